@@ -5,6 +5,7 @@ use sea_orm::DatabaseConnection;
 use tauri::{AppHandle, Wry};
 
 use crate::core::Feature;
+use crate::infrastructure::injection::InjectionRegistry;
 use crate::infrastructure::tray::TrayManager;
 
 /// 应用全局状态
@@ -18,6 +19,9 @@ pub struct AppState {
     // 系统托盘管理器
     tray_manager: TrayManager,
 
+    // 内容窗口注入脚本注册表
+    injection_registry: InjectionRegistry,
+
     // 当前主题缓存 (用于同步访问，如托盘菜单)
     current_theme: Mutex<String>,
 }
@@ -38,6 +42,7 @@ impl AppState {
             db,
             features: feature_map,
             tray_manager: TrayManager::new(),
+            injection_registry: InjectionRegistry::new(),
             current_theme: Mutex::new("system".to_string()),
         }
     }
@@ -57,6 +62,11 @@ impl AppState {
         &self.tray_manager
     }
 
+    /// 获取注入脚本注册表
+    pub fn injection_registry(&self) -> &InjectionRegistry {
+        &self.injection_registry
+    }
+
     /// 获取当前主题
     pub fn get_theme(&self) -> String {
         self.current_theme.lock().unwrap().clone()
@@ -82,6 +92,11 @@ impl AppState {
         self.tray_manager.set_registry(registry);
     }
 
+    /// 设置注入脚本注册表
+    pub fn set_injection_registry(&mut self, registry: InjectionRegistry) {
+        self.injection_registry = registry;
+    }
+
     /// 后初始化阶段（在 app.manage() 之后调用）
     ///
     /// 此时 AppState 已经被 Tauri 托管，可以通过 app.try_state() 访问。
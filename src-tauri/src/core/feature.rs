@@ -5,11 +5,12 @@ use async_trait::async_trait;
 
 use crate::core::AppState;
 use crate::infrastructure::database::DatabaseRegistry;
+use crate::infrastructure::injection::InjectionRegistry;
 
 /// Feature trait - 所有业务功能模块必须实现此 trait
 ///
 /// 每个 Feature 负责：
-/// 1. 向基础设施注册自己的组件（数据库实体、托盘菜单等）
+/// 1. 向基础设施注册自己的组件（数据库实体、注入脚本、托盘菜单等）
 /// 2. 提供自己的 Tauri Commands
 /// 3. 实现初始化逻辑
 #[async_trait]
@@ -21,11 +22,19 @@ pub trait Feature: Send + Sync {
 
     /// 注册数据库 Migrations
     ///
-    /// 使用 registry.register_migration() 注册迁移函数
+    /// 使用 registry.register_migrations() 按顺序注册本 Feature 的迁移，
+    /// 每个迁移只会执行一次；已执行的迁移不要修改，变更表结构时新增迁移
     fn register_database(&self, _registry: &mut DatabaseRegistry) {
         // 默认实现：不注册任何数据库组件
     }
 
+    /// 注册注入到内容窗口的脚本模块
+    ///
+    /// 使用 registry.register_scripts() 注册，构建内容窗口时按 order 组装
+    fn register_scripts(&self, _registry: &mut InjectionRegistry) {
+        // 默认实现：不注册任何脚本
+    }
+
     /// 返回此 Feature 的所有 Tauri Command 名称
     ///
     /// 这些名称用于文档和调试，实际 Commands 需要在 lib.rs 中手动注册
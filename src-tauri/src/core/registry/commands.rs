@@ -1,9 +1,51 @@
-use tauri::ipc::Invoke;
+use std::time::Instant;
+
+use serde_json::Value;
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::Manager;
+
+use crate::infrastructure::ipc::IpcGuard;
 
 /// 获取所有命令的处理器
 ///
-/// 封装 Tauri 的 generate_handler! 宏，统一管理所有命令注册
+/// 封装 Tauri 的 generate_handler! 宏，统一管理所有命令注册；
+/// 命令执行前先经过 [`IpcGuard`]（作为 Tauri state 管理）检查，内容窗口只能调用上报类命令
 pub fn get_handler() -> impl Fn(Invoke<tauri::Wry>) -> bool + Send + Sync + 'static {
+    let handler = commands();
+    move |invoke: Invoke<tauri::Wry>| {
+        let checked = {
+            let message = &invoke.message;
+            let webview = message.webview_ref();
+            let args = match message.payload() {
+                InvokeBody::Json(args) => args,
+                _ => &Value::Null,
+            };
+            match webview.try_state::<IpcGuard>() {
+                Some(guard) => guard.check(
+                    webview.label(),
+                    webview.url().ok().as_ref(),
+                    message.command(),
+                    args,
+                    Instant::now(),
+                ),
+                None => Err("IPC guard is not ready".to_string()),
+            }
+        };
+        if let Err(e) = checked {
+            println!(
+                "[IPC] Rejected {} from {}: {}",
+                invoke.message.command(),
+                invoke.message.webview_ref().label(),
+                e
+            );
+            invoke.resolver.reject(e);
+            return true;
+        }
+        handler(invoke)
+    }
+}
+
+fn commands() -> impl Fn(Invoke<tauri::Wry>) -> bool + Send + Sync + 'static {
     tauri::generate_handler![
         // Settings Feature Commands
         crate::features::settings::api::commands::get_theme_preference,
@@ -14,18 +56,76 @@ pub fn get_handler() -> impl Fn(Invoke<tauri::Wry>) -> bool + Send + Sync + 'sta
         crate::features::tab::api::commands::tabs_update,
         crate::features::tab::api::commands::tabs_activate,
         crate::features::tab::api::commands::tabs_close,
+        crate::features::tab::api::commands::tabs_reopen_closed,
+        crate::features::tab::api::commands::tabs_list_closed,
+        crate::features::tab::api::commands::tabs_get_suspension_policy,
+        crate::features::tab::api::commands::tabs_set_suspension_policy,
+        crate::features::tab::api::commands::tabs_import,
         crate::features::tab::api::commands::tabs_reorder,
         crate::features::tab::api::commands::tabs_activate_next,
         crate::features::tab::api::commands::tabs_activate_previous,
         crate::features::tab::api::commands::tabs_close_active,
         crate::features::tab::api::commands::tabs_reload,
+        crate::features::tab::api::commands::tabs_reset_content_size,
+        crate::features::tab::api::commands::tabs_detach,
+        crate::features::tab::api::commands::tabs_attach,
+        crate::features::tab::api::commands::tabs_go_back,
+        crate::features::tab::api::commands::tabs_go_forward,
+        crate::features::tab::api::commands::tabs_history,
         crate::features::tab::api::commands::tabs_report_navigation,
         crate::features::tab::api::commands::tabs_report_title,
+        crate::features::tab::api::commands::tabs_report_favicon,
         crate::features::tab::api::commands::tabs_get_current_url,
         crate::features::tab::api::commands::tabs_request_url,
         crate::features::tab::api::commands::tabs_respond_url,
+        crate::features::tab::api::commands::tabs_open_link,
+        crate::features::tab::api::commands::tabs_open_external,
+        crate::features::tab::api::group_commands::tab_groups_list,
+        crate::features::tab::api::group_commands::tab_groups_create,
+        crate::features::tab::api::group_commands::tab_groups_update,
+        crate::features::tab::api::group_commands::tab_groups_activate,
+        crate::features::tab::api::group_commands::tab_groups_delete,
+        crate::features::tab::api::group_commands::tab_groups_reorder,
+        crate::features::tab::api::split_commands::tab_splits_list,
+        crate::features::tab::api::split_commands::tab_splits_create,
+        crate::features::tab::api::split_commands::tab_splits_update,
+        crate::features::tab::api::split_commands::tab_splits_delete,
+        // Data Transfer Feature Commands
+        crate::features::data_transfer::api::commands::data_export,
+        crate::features::data_transfer::api::commands::data_import,
+        // Backup Feature Commands
+        crate::features::backup::api::commands::db_list_backups,
+        crate::features::backup::api::commands::db_restore_backup,
+        // Site Script Feature Commands
+        crate::features::site_script::api::commands::site_scripts_list,
+        crate::features::site_script::api::commands::site_scripts_create,
+        crate::features::site_script::api::commands::site_scripts_update,
+        crate::features::site_script::api::commands::site_scripts_delete,
+        crate::features::site_script::api::commands::site_scripts_import,
+        // Link Rule Feature Commands
+        crate::features::link_rule::api::commands::link_rules_list,
+        crate::features::link_rule::api::commands::link_rules_create,
+        crate::features::link_rule::api::commands::link_rules_update,
+        crate::features::link_rule::api::commands::link_rules_delete,
         // Window Feature Commands
         crate::features::window::api::commands::set_content_window_pinned,
+        crate::features::window::api::commands::window_get_content_pinned,
         crate::features::window::api::commands::resize_main_window,
+        crate::features::window::api::commands::shortcuts_list,
+        crate::features::window::api::commands::shortcuts_set,
+        crate::features::window::api::commands::shortcuts_reset,
+        crate::features::window::api::commands::window_get_dock_settings,
+        crate::features::window::api::commands::window_set_dock_settings,
+        crate::features::window::api::commands::window_get_content_layout,
+        crate::features::window::api::commands::window_set_content_layout,
+        crate::features::window::api::commands::window_get_summon_on_cursor_monitor,
+        crate::features::window::api::commands::window_set_summon_on_cursor_monitor,
+        crate::features::window::api::commands::window_set_modal_open,
+        crate::features::window::api::commands::window_get_focus_timings,
+        crate::features::window::api::commands::window_set_focus_timings,
+        crate::features::window::api::commands::window_get_force_dark,
+        crate::features::window::api::commands::window_set_force_dark,
+        crate::features::window::api::commands::window_script_modules_list,
+        crate::features::window::api::commands::window_script_modules_set_enabled,
     ]
 }
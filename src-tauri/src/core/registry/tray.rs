@@ -7,17 +7,26 @@ pub fn build_tray_registry() -> TrayRegistry {
     let mut registry = TrayRegistry::new();
 
     // 从各个模块导入托盘菜单项
+    use crate::features::backup::api::tray as backup_tray;
+    use crate::features::data_transfer::api::tray as data_tray;
+    use crate::features::tab::api::tray as tab_tray;
     use crate::features::window::api::tray as window_tray;
     use crate::features::settings::api::tray as settings_tray;
     use crate::infrastructure::tray::quit_app_item;
 
     // 手动布局菜单结构
     registry.add_item(window_tray::toggle_window_item());
+    registry.add_item(window_tray::pin_content_item());
+    registry.add_submenu(tab_tray::recently_closed_submenu());
     registry.add_separator();
     registry.add_item(settings_tray::theme_light_item());
     registry.add_item(settings_tray::theme_dark_item());
     registry.add_item(settings_tray::theme_system_item());
     registry.add_separator();
+    registry.add_item(data_tray::export_data_item());
+    registry.add_submenu(data_tray::import_data_submenu());
+    registry.add_item(backup_tray::restore_latest_backup_item());
+    registry.add_separator();
     registry.add_item(quit_app_item());
 
     registry
@@ -0,0 +1,31 @@
+use tauri::State;
+
+use crate::core::AppState;
+use crate::infrastructure::database::backup::{self, BackupInfo};
+
+/// 按时间倒序列出数据库备份
+#[tauri::command]
+pub async fn db_list_backups() -> Result<Vec<BackupInfo>, String> {
+    backup::list_backups().map_err(|e| e.to_string())
+}
+
+/// 从备份恢复数据库，应用会重启以重新打开数据库
+#[tauri::command]
+pub async fn db_restore_backup(
+    app_state: State<'_, AppState>,
+    file_name: String,
+) -> Result<(), String> {
+    restore_backup(&app_state, &file_name).await
+}
+
+/// 恢复备份（供 command 与托盘菜单共用）
+///
+/// 同时为当前数据库做一次备份，恢复后仍可回到恢复前的状态
+pub(crate) async fn restore_backup(app_state: &AppState, file_name: &str) -> Result<(), String> {
+    backup::prepare_restore(app_state.db(), file_name)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    println!("[BackupFeature] Restoring {}, restarting", file_name);
+    app_state.app_handle().restart();
+}
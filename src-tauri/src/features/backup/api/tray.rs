@@ -0,0 +1,39 @@
+use tauri::{AppHandle, Manager};
+
+use crate::core::AppState;
+use crate::features::backup::api::commands::restore_backup;
+use crate::infrastructure::database::backup;
+use crate::infrastructure::tray::TrayMenuItem;
+
+/// 恢复最近一次的数据库备份（会重启应用）
+pub fn restore_latest_backup_item() -> TrayMenuItem {
+    TrayMenuItem::always_visible(
+        "db_restore_latest",
+        "恢复最近的备份",
+        |app: &AppHandle| {
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let Some(state) = app_handle.try_state::<AppState>() else {
+                    return;
+                };
+                let latest = match backup::list_backups() {
+                    Ok(backups) => backups.into_iter().next(),
+                    Err(e) => {
+                        println!("[BackupFeature] Failed to list backups: {}", e);
+                        return;
+                    }
+                };
+                if let Some(latest) = latest {
+                    if let Err(e) = restore_backup(&state, &latest.file_name).await {
+                        println!("[BackupFeature] Restore failed: {}", e);
+                    }
+                }
+            });
+        },
+    )
+    .with_enabled(|_app: &AppHandle| {
+        backup::list_backups()
+            .map(|backups| !backups.is_empty())
+            .unwrap_or(false)
+    })
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tauri::Manager;
+
+use crate::core::{AppState, Feature};
+use crate::infrastructure::database::backup;
+
+/// 检查是否需要每日备份的间隔
+const BACKUP_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Backup Feature - 定时备份数据库，提供备份列表与恢复
+pub struct BackupFeature;
+
+impl BackupFeature {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl Feature for BackupFeature {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "backup"
+    }
+
+    async fn initialize(&self, app_state: &AppState) -> Result<()> {
+        // 每日备份：定期检查距离最近一次备份是否已超过一天
+        let app_handle = app_state.app_handle();
+        tauri::async_runtime::spawn(async move {
+            loop {
+                if backup::is_backup_due(backup::DAILY_BACKUP_INTERVAL).unwrap_or(false) {
+                    if let Some(state) = app_handle.try_state::<AppState>() {
+                        if let Err(e) = backup::create_backup(state.db(), "daily").await {
+                            println!("[BackupFeature] Daily backup failed: {}", e);
+                        }
+                    }
+                }
+                tokio::time::sleep(BACKUP_CHECK_INTERVAL).await;
+            }
+        });
+
+        Ok(())
+    }
+
+    fn command_names(&self) -> Vec<&'static str> {
+        vec!["db_list_backups", "db_restore_backup"]
+    }
+}
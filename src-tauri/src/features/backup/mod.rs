@@ -0,0 +1,10 @@
+// Backup Feature - 数据库自动备份与恢复
+//
+// 架构：
+// - api/: API 接口层（commands, tray）
+// - 备份的实现位于 infrastructure::database::backup
+
+pub mod api;
+pub mod feature;
+
+pub use feature::BackupFeature;
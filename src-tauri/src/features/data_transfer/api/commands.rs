@@ -0,0 +1,108 @@
+use std::path::Path;
+
+use serde_json::json;
+use tauri::State;
+
+use crate::core::AppState;
+use crate::features::data_transfer::api::tray::refresh_exports;
+use crate::features::data_transfer::core::models::{
+    DataExportResult, DataImportResult, ImportMode,
+};
+use crate::features::data_transfer::core::service::DataTransferService;
+use crate::features::settings::core::service::SettingService;
+use crate::features::tab::api::commands::enforce_suspension;
+use crate::features::tab::api::group_commands::present_group_active_tab;
+use crate::features::tab::api::tray::refresh_recently_closed;
+use crate::features::window::feature::load_settings as load_window_settings;
+use crate::features::window::manager as window_manager;
+use crate::features::window::{appearance, geometry, split};
+use crate::infrastructure::ipc::emit_to_app;
+
+/// 导出所有分组、标签和设置，未指定路径时写入应用数据目录下的 exports
+#[tauri::command]
+pub async fn data_export(
+    app_state: State<'_, AppState>,
+    path: Option<String>,
+) -> Result<DataExportResult, String> {
+    export_data(&app_state, path.as_deref().map(Path::new)).await
+}
+
+/// 从 bundle 文件导入，默认为合并模式
+#[tauri::command]
+pub async fn data_import(
+    app_state: State<'_, AppState>,
+    path: String,
+    mode: Option<ImportMode>,
+) -> Result<DataImportResult, String> {
+    import_data(&app_state, Path::new(&path), mode.unwrap_or_default()).await
+}
+
+/// 导出数据（供 command 与托盘菜单共用）
+pub(crate) async fn export_data(
+    app_state: &AppState,
+    path: Option<&Path>,
+) -> Result<DataExportResult, String> {
+    let result = DataTransferService::export(app_state.db(), path)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    refresh_exports(app_state);
+    Ok(result)
+}
+
+/// 导入数据并刷新运行时状态（供 command 与托盘菜单共用）
+pub(crate) async fn import_data(
+    app_state: &AppState,
+    path: &Path,
+    mode: ImportMode,
+) -> Result<DataImportResult, String> {
+    let bundle = DataTransferService::read_bundle(path)
+        .await
+        .map_err(|e| e.to_string())?;
+    let result = DataTransferService::import(app_state.db(), bundle, mode)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let app = app_state.app_handle();
+
+    if mode == ImportMode::Replace {
+        // 原有标签和分屏组合已全部删除，销毁其 webview
+        split::set_active_split(None);
+        for live in window_manager::live_content_webviews() {
+            window_manager::close_content_webview(&app, &live.tab_id);
+        }
+        window_manager::close_detached_windows(&app);
+        window_manager::clear_active_content();
+    }
+
+    // 设置可能被替换，刷新主题缓存
+    if let Ok(theme) = SettingService::get_or_default(app_state.db(), "ui.theme", "system").await {
+        if theme != app_state.get_theme() {
+            app_state.set_theme(theme.clone());
+            let _ = emit_to_app(&app, "theme-changed", theme);
+        }
+    }
+    // 快捷键、自动隐藏、布局、停靠等设置同样可能被替换或新增
+    load_window_settings(app_state).await;
+    appearance::apply(&app);
+    if let Ok(dock) = geometry::load_dock_settings(app_state).await {
+        window_manager::apply_dock_settings(&app, dock);
+    }
+
+    let _ = emit_to_app(
+        &app,
+        "tab-groups-changed",
+        json!({ "action": "imported", "data": { "result": result } }),
+    );
+    let _ = emit_to_app(
+        &app,
+        "tabs-changed",
+        json!({ "action": "imported", "data": { "result": result } }),
+    );
+    present_group_active_tab(app_state).await?;
+    // 按导入的挂起策略回收后台标签
+    enforce_suspension(app_state).await;
+    refresh_recently_closed(app_state).await;
+
+    Ok(result)
+}
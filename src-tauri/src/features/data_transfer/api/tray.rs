@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_opener::OpenerExt;
+
+use crate::core::AppState;
+use crate::features::data_transfer::api::commands::{export_data, import_data};
+use crate::features::data_transfer::core::models::ImportMode;
+use crate::features::data_transfer::core::service::DataTransferService;
+use crate::features::data_transfer::DataTransferFeature;
+use crate::infrastructure::tray::{TrayMenuItem, TraySubmenu};
+
+/// 托盘中最多列出的导出文件数
+const EXPORTS_MENU_LIMIT: usize = 10;
+
+/// 导出数据到导出目录，并在文件管理器中显示
+pub fn export_data_item() -> TrayMenuItem {
+    TrayMenuItem::always_visible("data_export", "导出数据", |app: &AppHandle| {
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Some(state) = app_handle.try_state::<AppState>() {
+                match export_data(&state, None).await {
+                    Ok(result) => {
+                        let _ = app_handle.opener().reveal_item_in_dir(&result.path);
+                    }
+                    Err(e) => println!("[DataTransferFeature] Export failed: {}", e),
+                }
+            }
+        });
+    })
+}
+
+/// 从导出目录中的备份文件导入（合并模式）
+pub fn import_data_submenu() -> TraySubmenu {
+    TraySubmenu::new(
+        "data_import",
+        "导入数据",
+        "（无导出文件）",
+        |app: &AppHandle| {
+            let Some(state) = app.try_state::<AppState>() else {
+                return vec![];
+            };
+            let Some(feature) = state
+                .get_feature("data_transfer")
+                .and_then(|f| f.as_any().downcast_ref::<DataTransferFeature>())
+            else {
+                return vec![];
+            };
+
+            feature.exports().into_iter().map(import_item).collect()
+        },
+    )
+}
+
+fn import_item(path: PathBuf) -> TrayMenuItem {
+    let label = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    TrayMenuItem::always_visible(
+        format!("data_import:{}", label),
+        label,
+        move |app: &AppHandle| {
+            let app_handle = app.clone();
+            let path = path.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Some(state) = app_handle.try_state::<AppState>() {
+                    if let Err(e) = import_data(&state, &path, ImportMode::Merge).await {
+                        println!("[DataTransferFeature] Import failed: {}", e);
+                    }
+                }
+            });
+        },
+    )
+}
+
+/// 重新扫描导出目录并重建托盘菜单
+pub fn refresh_exports(app_state: &AppState) {
+    let exports = match DataTransferService::list_exports() {
+        Ok(files) => files.into_iter().take(EXPORTS_MENU_LIMIT).collect(),
+        Err(e) => {
+            println!("[DataTransferFeature] Failed to list exports: {}", e);
+            return;
+        }
+    };
+
+    if let Some(feature) = app_state
+        .get_feature("data_transfer")
+        .and_then(|f| f.as_any().downcast_ref::<DataTransferFeature>())
+    {
+        feature.set_exports(exports);
+    }
+
+    let _ = app_state
+        .tray_manager()
+        .update_tray_menu(&app_state.app_handle());
+}
@@ -0,0 +1,147 @@
+// 备份文件（bundle）格式
+//
+// bundle 是一个 JSON 文件，包含所有分组、标签和设置，可在不同设备或成员之间共享。
+// 手写的初始标签集只需要填写 `format`、`version` 和各标签的 `url`，其余字段均有默认值。
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::settings::data::entity as setting_entity;
+use crate::features::tab::data::{entity as tab_entity, group_entity};
+
+/// bundle 文件标识
+pub const BUNDLE_FORMAT: &str = "nob-bundle";
+/// 当前 bundle 版本，导入时拒绝更高版本
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// 备份文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataBundle {
+    /// 固定为 `nob-bundle`
+    pub format: String,
+    /// bundle 版本，见 [`BUNDLE_VERSION`]
+    pub version: u32,
+    /// 导出时间（RFC 3339）
+    #[serde(default)]
+    pub exported_at: Option<String>,
+    #[serde(default)]
+    pub groups: Vec<BundleGroup>,
+    #[serde(default)]
+    pub tabs: Vec<BundleTab>,
+    #[serde(default)]
+    pub settings: Vec<BundleSetting>,
+}
+
+/// 标签分组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleGroup {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub is_active: bool,
+    #[serde(default)]
+    pub sort_order: i32,
+}
+
+/// 标签页
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleTab {
+    /// 省略时导入时生成
+    #[serde(default)]
+    pub id: Option<String>,
+    /// 所属分组的 id，省略或分组不存在时导入到当前分组
+    #[serde(default)]
+    pub group_id: Option<String>,
+    /// 省略时使用 url
+    #[serde(default)]
+    pub title: Option<String>,
+    pub url: String,
+    #[serde(default)]
+    pub initial_url: Option<String>,
+    #[serde(default)]
+    pub favicon_url: Option<String>,
+    #[serde(default)]
+    pub is_pinned: bool,
+    #[serde(default)]
+    pub is_active: bool,
+    /// 省略时按在文件中的顺序排列
+    #[serde(default)]
+    pub sort_order: Option<i32>,
+    /// 内容窗口尺寸，省略时使用默认尺寸
+    #[serde(default)]
+    pub content_width: Option<f64>,
+    #[serde(default)]
+    pub content_height: Option<f64>,
+}
+
+/// 设置项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleSetting {
+    pub key: String,
+    pub value: String,
+}
+
+impl From<group_entity::Model> for BundleGroup {
+    fn from(model: group_entity::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            is_active: model.is_active,
+            sort_order: model.sort_order,
+        }
+    }
+}
+
+impl From<tab_entity::Model> for BundleTab {
+    fn from(model: tab_entity::Model) -> Self {
+        Self {
+            id: Some(model.id),
+            group_id: Some(model.group_id),
+            title: Some(model.title),
+            url: model.url,
+            initial_url: Some(model.initial_url),
+            favicon_url: model.favicon_url,
+            is_pinned: model.is_pinned,
+            is_active: model.is_active,
+            sort_order: Some(model.sort_order),
+            content_width: model.content_width,
+            content_height: model.content_height,
+        }
+    }
+}
+
+impl From<setting_entity::Model> for BundleSetting {
+    fn from(model: setting_entity::Model) -> Self {
+        Self {
+            key: model.key,
+            value: model.value,
+        }
+    }
+}
+
+/// 导入方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+    /// 合并：保留现有数据，跳过已存在的标签和设置
+    #[default]
+    Merge,
+    /// 替换：清空现有分组、标签（含历史和最近关闭）和设置后导入
+    Replace,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataExportResult {
+    pub path: String,
+    pub groups: usize,
+    pub tabs: usize,
+    pub settings: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DataImportResult {
+    pub groups_created: u32,
+    pub tabs_created: u32,
+    /// 合并模式下已存在的标签
+    pub tabs_skipped: u32,
+    pub settings_written: u32,
+}
@@ -0,0 +1,390 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use url::Url;
+use uuid::Uuid;
+
+use crate::features::data_transfer::core::models::{
+    BundleGroup, DataBundle, DataExportResult, DataImportResult, ImportMode, BUNDLE_FORMAT,
+    BUNDLE_VERSION,
+};
+use crate::features::settings::data::entity::{self as setting_entity, Entity as SettingEntity};
+use crate::features::tab::core::group_service::TabGroupService;
+use crate::features::tab::core::history_service::TabHistoryService;
+use crate::features::tab::data::closed_entity::Entity as ClosedTabEntity;
+use crate::features::tab::data::entity::{self as tab_entity, Entity as TabEntity};
+use crate::features::tab::data::group_entity::{self, Entity as TabGroupEntity, DEFAULT_GROUP_ID};
+use crate::features::tab::data::history_entity::Entity as TabHistoryEntity;
+use crate::features::tab::data::split_entity::Entity as TabSplitEntity;
+use crate::infrastructure::database::app_data_dir;
+
+/// 导出文件所在目录（相对应用数据目录）
+const EXPORTS_DIR: &str = "exports";
+
+/// 数据导入导出服务
+pub struct DataTransferService;
+
+impl DataTransferService {
+    /// 收集所有分组、标签和设置
+    pub async fn build_bundle(db: &DatabaseConnection) -> Result<DataBundle> {
+        let groups = TabGroupEntity::find()
+            .order_by_asc(group_entity::Column::SortOrder)
+            .all(db)
+            .await?;
+        let tabs = TabEntity::find()
+            .order_by_asc(tab_entity::Column::GroupId)
+            .order_by_asc(tab_entity::Column::SortOrder)
+            .all(db)
+            .await?;
+        let settings = SettingEntity::find()
+            .order_by_asc(setting_entity::Column::Key)
+            .all(db)
+            .await?;
+
+        Ok(DataBundle {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            exported_at: Some(Utc::now().to_rfc3339()),
+            groups: groups.into_iter().map(Into::into).collect(),
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            settings: settings.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// 导出到指定文件，未指定时写入导出目录
+    pub async fn export(db: &DatabaseConnection, path: Option<&Path>) -> Result<DataExportResult> {
+        let bundle = Self::build_bundle(db).await?;
+        let content = serde_json::to_string_pretty(&bundle)?;
+
+        // 文件读写放到阻塞线程池，不占用异步运行时
+        let path = path.map(Path::to_path_buf);
+        let path = tauri::async_runtime::spawn_blocking(move || -> Result<PathBuf> {
+            let path = match path {
+                Some(path) => path,
+                None => Self::exports_dir()?
+                    .join(format!("nob-{}.json", Utc::now().format("%Y%m%d-%H%M%S"))),
+            };
+            fs::write(&path, content)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            Ok(path)
+        })
+        .await??;
+
+        Ok(DataExportResult {
+            path: path.to_string_lossy().to_string(),
+            groups: bundle.groups.len(),
+            tabs: bundle.tabs.len(),
+            settings: bundle.settings.len(),
+        })
+    }
+
+    /// 读取并校验 bundle 文件
+    pub async fn read_bundle(path: &Path) -> Result<DataBundle> {
+        let file = path.to_path_buf();
+        let content = tauri::async_runtime::spawn_blocking(move || {
+            fs::read_to_string(&file).with_context(|| format!("failed to read {}", file.display()))
+        })
+        .await??;
+        let bundle: DataBundle = serde_json::from_str(&content)
+            .with_context(|| format!("invalid bundle file: {}", path.display()))?;
+
+        if bundle.format != BUNDLE_FORMAT {
+            return Err(anyhow!("not a NoB bundle (format: {})", bundle.format));
+        }
+        if bundle.version == 0 || bundle.version > BUNDLE_VERSION {
+            return Err(anyhow!(
+                "unsupported bundle version {} (supported: 1..={})",
+                bundle.version,
+                BUNDLE_VERSION
+            ));
+        }
+
+        Ok(bundle)
+    }
+
+    /// 在一个事务中导入 bundle
+    pub async fn import(
+        db: &DatabaseConnection,
+        bundle: DataBundle,
+        mode: ImportMode,
+    ) -> Result<DataImportResult> {
+        let txn = db.begin().await?;
+        let now = Utc::now();
+        let mut result = DataImportResult::default();
+
+        if mode == ImportMode::Replace {
+            TabSplitEntity::delete_many().exec(&txn).await?;
+            TabHistoryEntity::delete_many().exec(&txn).await?;
+            ClosedTabEntity::delete_many().exec(&txn).await?;
+            TabEntity::delete_many().exec(&txn).await?;
+            TabGroupEntity::delete_many().exec(&txn).await?;
+            SettingEntity::delete_many().exec(&txn).await?;
+        }
+
+        // bundle 中的分组 id -> 数据库中的分组 id
+        let group_ids = Self::import_groups(&txn, &bundle.groups, mode, &mut result).await?;
+        let current_group = TabGroupService::current_id(&txn).await?;
+
+        let mut known_ids: HashSet<String> = HashSet::new();
+        let mut known_urls: HashSet<(String, String)> = HashSet::new();
+        if mode == ImportMode::Merge {
+            for tab in TabEntity::find().all(&txn).await? {
+                known_ids.insert(tab.id);
+                known_urls.insert((tab.group_id, tab.url));
+            }
+        }
+
+        let mut next_orders: HashMap<String, i32> = HashMap::new();
+        let mut active_groups: HashSet<String> = HashSet::new();
+
+        for tab in bundle.tabs {
+            // 与 tabs_open_link 一致，只导入 http/https 标签
+            let Some(url) = web_url(&tab.url) else {
+                result.tabs_skipped += 1;
+                continue;
+            };
+            let initial_url = tab
+                .initial_url
+                .as_deref()
+                .and_then(web_url)
+                .unwrap_or_else(|| url.clone());
+
+            let group_id = tab
+                .group_id
+                .as_ref()
+                .and_then(|id| group_ids.get(id))
+                .cloned()
+                .unwrap_or_else(|| current_group.clone());
+            let id = tab.id.unwrap_or_else(|| Uuid::new_v4().to_string());
+
+            if !known_ids.insert(id.clone()) || !known_urls.insert((group_id.clone(), url.clone()))
+            {
+                result.tabs_skipped += 1;
+                continue;
+            }
+
+            // 合并时追加到分组末尾，保持 bundle 中的相对顺序
+            let sort_order = match (mode, tab.sort_order) {
+                (ImportMode::Replace, Some(order)) => order,
+                _ => Self::next_sort_order(&txn, &mut next_orders, &group_id).await?,
+            };
+            // 合并时不改变现有的激活标签；替换时每个分组最多保留一个激活标签
+            let is_active = mode == ImportMode::Replace
+                && tab.is_active
+                && active_groups.insert(group_id.clone());
+
+            let created = tab_entity::ActiveModel {
+                id: Set(id),
+                group_id: Set(group_id),
+                title: Set(tab.title.unwrap_or_else(|| url.clone())),
+                url: Set(url),
+                initial_url: Set(initial_url),
+                favicon_url: Set(tab.favicon_url),
+                is_pinned: Set(tab.is_pinned),
+                is_active: Set(is_active),
+                sort_order: Set(sort_order),
+                content_width: Set(tab.content_width),
+                content_height: Set(tab.content_height),
+                last_opened_at: Set(now),
+                created_at: Set(now),
+                updated_at: Set(now),
+            }
+            .insert(&txn)
+            .await?;
+
+            TabHistoryService::record(&txn, &created.id, &created.url).await?;
+            result.tabs_created += 1;
+        }
+
+        let mut known_keys: HashSet<String> = SettingEntity::find()
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|s| s.key)
+            .collect();
+
+        for setting in bundle.settings {
+            // 合并时保留本机已有的设置
+            if !known_keys.insert(setting.key.clone()) {
+                continue;
+            }
+
+            setting_entity::ActiveModel {
+                key: Set(setting.key),
+                value: Set(setting.value),
+                created_at: Set(now),
+                updated_at: Set(now),
+            }
+            .insert(&txn)
+            .await?;
+            result.settings_written += 1;
+        }
+
+        txn.commit().await?;
+        Ok(result)
+    }
+
+    /// 导出目录，不存在时创建
+    pub fn exports_dir() -> Result<PathBuf> {
+        let dir = app_data_dir()?.join(EXPORTS_DIR);
+        fs::create_dir_all(&dir).context("failed to create exports directory")?;
+        Ok(dir)
+    }
+
+    /// 列出导出目录中的 bundle 文件（最新的在前）
+    pub fn list_exports() -> Result<Vec<PathBuf>> {
+        let mut files: Vec<PathBuf> = fs::read_dir(Self::exports_dir()?)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+
+        // 文件名带时间戳，按名称倒序即为时间倒序
+        files.sort();
+        files.reverse();
+        Ok(files)
+    }
+
+    async fn import_groups<C>(
+        conn: &C,
+        groups: &[BundleGroup],
+        mode: ImportMode,
+        result: &mut DataImportResult,
+    ) -> Result<HashMap<String, String>>
+    where
+        C: ConnectionTrait,
+    {
+        let now = Utc::now();
+        let existing = TabGroupEntity::find().all(conn).await?;
+        let mut next_order = existing.iter().map(|g| g.sort_order + 1).max().unwrap_or(0);
+        let mut has_active = existing.iter().any(|g| g.is_active);
+        let mut group_ids = HashMap::new();
+
+        for group in groups {
+            // 合并时按 id、再按名称匹配已有分组
+            if let Some(found) = existing
+                .iter()
+                .find(|g| g.id == group.id)
+                .or_else(|| existing.iter().find(|g| g.name == group.name))
+            {
+                group_ids.insert(group.id.clone(), found.id.clone());
+                continue;
+            }
+            if group_ids.contains_key(&group.id) {
+                continue;
+            }
+
+            let is_active = mode == ImportMode::Replace && group.is_active && !has_active;
+            has_active |= is_active;
+            let sort_order = match mode {
+                ImportMode::Replace => group.sort_order,
+                ImportMode::Merge => {
+                    next_order += 1;
+                    next_order - 1
+                }
+            };
+
+            group_entity::ActiveModel {
+                id: Set(group.id.clone()),
+                name: Set(group.name.clone()),
+                is_active: Set(is_active),
+                sort_order: Set(sort_order),
+                created_at: Set(now),
+                updated_at: Set(now),
+            }
+            .insert(conn)
+            .await?;
+
+            group_ids.insert(group.id.clone(), group.id.clone());
+            result.groups_created += 1;
+        }
+
+        // 替换后至少保留一个分组，并保证有且仅有一个激活分组
+        let first = TabGroupEntity::find()
+            .order_by_asc(group_entity::Column::SortOrder)
+            .one(conn)
+            .await?;
+        match first {
+            None => {
+                group_entity::ActiveModel {
+                    id: Set(DEFAULT_GROUP_ID.to_string()),
+                    name: Set("默认".to_string()),
+                    is_active: Set(true),
+                    sort_order: Set(0),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                }
+                .insert(conn)
+                .await?;
+            }
+            Some(first) if !has_active => {
+                TabGroupService::switch_to(conn, &first.id).await?;
+            }
+            Some(_) => {}
+        }
+
+        Ok(group_ids)
+    }
+
+    async fn next_sort_order<C>(
+        conn: &C,
+        next_orders: &mut HashMap<String, i32>,
+        group_id: &str,
+    ) -> Result<i32>
+    where
+        C: ConnectionTrait,
+    {
+        let next = match next_orders.get(group_id) {
+            Some(next) => *next,
+            None => TabEntity::find()
+                .filter(tab_entity::Column::GroupId.eq(group_id))
+                .order_by_desc(tab_entity::Column::SortOrder)
+                .one(conn)
+                .await?
+                .map(|t| t.sort_order + 1)
+                .unwrap_or(0),
+        };
+
+        next_orders.insert(group_id.to_string(), next + 1);
+        Ok(next)
+    }
+}
+
+/// 规范化 http/https 地址，其他协议（javascript:、file: 等）返回 None
+fn web_url(url: &str) -> Option<String> {
+    Url::parse(url)
+        .ok()
+        .filter(|parsed| matches!(parsed.scheme(), "http" | "https"))
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_web_urls_are_imported() {
+        assert_eq!(
+            web_url("https://example.com").as_deref(),
+            Some("https://example.com/")
+        );
+        assert_eq!(
+            web_url("http://example.com/a?b=1").as_deref(),
+            Some("http://example.com/a?b=1")
+        );
+        for url in [
+            "javascript:alert(1)",
+            "file:///etc/passwd",
+            "data:text/html,<p>x</p>",
+            "not a url",
+        ] {
+            assert_eq!(web_url(url), None, "{}", url);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::core::{AppState, Feature};
+
+use super::api::tray::refresh_exports;
+
+/// Data Transfer Feature - 所有标签与设置的导入导出
+pub struct DataTransferFeature {
+    // 导出目录中的文件缓存（用于同步构建托盘菜单）
+    exports: Mutex<Vec<PathBuf>>,
+}
+
+impl DataTransferFeature {
+    pub fn new() -> Self {
+        Self {
+            exports: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 获取导出文件缓存
+    pub fn exports(&self) -> Vec<PathBuf> {
+        self.exports.lock().unwrap().clone()
+    }
+
+    /// 更新导出文件缓存
+    pub fn set_exports(&self, exports: Vec<PathBuf>) {
+        *self.exports.lock().unwrap() = exports;
+    }
+}
+
+#[async_trait]
+impl Feature for DataTransferFeature {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "data_transfer"
+    }
+
+    async fn initialize(&self, app_state: &AppState) -> Result<()> {
+        refresh_exports(app_state);
+        Ok(())
+    }
+
+    fn command_names(&self) -> Vec<&'static str> {
+        vec!["data_export", "data_import"]
+    }
+}
@@ -0,0 +1,11 @@
+// Data Transfer Feature - 数据导入导出
+//
+// 分层架构：
+// - api/: API 接口层（commands, tray）
+// - core/: 核心业务层（bundle 格式定义, service）
+
+pub mod api;
+pub mod core;
+pub mod feature;
+
+pub use feature::DataTransferFeature;
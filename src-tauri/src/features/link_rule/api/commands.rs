@@ -0,0 +1,62 @@
+use tauri::State;
+
+use crate::core::AppState;
+use crate::features::link_rule::core::engine;
+use crate::features::link_rule::core::models::{
+    CreateLinkRulePayload, LinkRule, UpdateLinkRulePayload,
+};
+use crate::features::link_rule::core::service::LinkRuleService;
+
+/// 修改后刷新规则缓存，导航钩子立即使用新规则，页面中的规则在下次加载时更新
+async fn refresh(app_state: &AppState) {
+    if let Err(e) = engine::reload(app_state.db()).await {
+        println!("[LinkRule] Failed to reload rules: {}", e);
+    }
+}
+
+#[tauri::command]
+pub async fn link_rules_list(app_state: State<'_, AppState>) -> Result<Vec<LinkRule>, String> {
+    LinkRuleService::list(app_state.db())
+        .await
+        .map(|rules| rules.into_iter().map(LinkRule::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn link_rules_create(
+    app_state: State<'_, AppState>,
+    payload: CreateLinkRulePayload,
+) -> Result<LinkRule, String> {
+    let rule = LinkRuleService::create(app_state.db(), payload)
+        .await
+        .map(LinkRule::from)
+        .map_err(|e| e.to_string())?;
+    refresh(&app_state).await;
+    Ok(rule)
+}
+
+#[tauri::command]
+pub async fn link_rules_update(
+    app_state: State<'_, AppState>,
+    payload: UpdateLinkRulePayload,
+) -> Result<Option<LinkRule>, String> {
+    let rule = LinkRuleService::update(app_state.db(), payload)
+        .await
+        .map(|res| res.map(LinkRule::from))
+        .map_err(|e| e.to_string())?;
+    refresh(&app_state).await;
+    Ok(rule)
+}
+
+#[tauri::command]
+pub async fn link_rules_delete(
+    app_state: State<'_, AppState>,
+    id: String,
+) -> Result<Option<LinkRule>, String> {
+    let rule = LinkRuleService::delete(app_state.db(), &id)
+        .await
+        .map(|res| res.map(LinkRule::from))
+        .map_err(|e| e.to_string())?;
+    refresh(&app_state).await;
+    Ok(rule)
+}
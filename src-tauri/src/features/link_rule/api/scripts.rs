@@ -0,0 +1,12 @@
+use crate::features::link_rule::core::engine;
+use crate::infrastructure::injection::ScriptModule;
+
+/// Link Rule Feature 注入的脚本模块
+pub fn script_modules() -> Vec<ScriptModule> {
+    vec![
+        ScriptModule::dynamic("link_rule.engine", "链接处理规则", 5, |_| {
+            vec![engine::script()]
+        })
+        .reapply_on_load(),
+    ]
+}
@@ -0,0 +1,585 @@
+use std::sync::Mutex;
+
+use anyhow::Result;
+use sea_orm::DatabaseConnection;
+use serde_json::json;
+use tauri::{AppHandle, Manager, Wry};
+use tauri_plugin_opener::OpenerExt;
+use url::{Position, Url};
+
+use crate::core::AppState;
+use crate::features::link_rule::core::models::{LinkAction, LinkRelation, LinkRule, RuleScope};
+use crate::features::link_rule::core::pattern;
+use crate::features::link_rule::core::service::LinkRuleService;
+use crate::features::tab::api::commands as tab_commands;
+use crate::features::tab::core::models::CreateTabPayload;
+use crate::infrastructure::web::{site, SECOND_LEVEL_LABELS};
+
+/// 已启用的规则（按匹配顺序，导航钩子中同步读取）
+static RULES: Mutex<Vec<LinkRule>> = Mutex::new(Vec::new());
+
+/// 各内容窗口当前的页面（只在主框架开始加载时更新，作为导航的来源页面）
+static PAGES: Mutex<Vec<(String, Url)>> = Mutex::new(Vec::new());
+
+/// 应用发起、尚未开始的导航（地址栏、刷新、前进后退），不经过规则
+static EXPECTED: Mutex<Vec<(String, Url)>> = Mutex::new(Vec::new());
+
+/// 参与规则匹配的协议，其他协议（about:、data:、blob: 等）不处理
+const HANDLED_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// 页面中的规则引擎：`nob.linkAction(url)` 返回处理方式，`nob.followLink(url, newWindow)` 按规则打开
+///
+/// 规则在每次页面加载后按当前设置更新，函数只定义一次
+const ENGINE_SCRIPT: &str = r##"
+(() => {
+  const nob = window.__NOB__;
+  if (!nob) return;
+  nob.linkRules = __RULES__;
+  if (nob.followLink) return;
+
+  const SCHEMES = ["http:", "https:", "mailto:", "tel:"];
+  const SECOND_LEVEL = __SECOND_LEVEL__;
+  const site = (host) => {
+    if (/^[\d.]+$/.test(host) || host.startsWith("[")) return host;
+    const labels = host.split(".");
+    const n = labels.length >= 3
+      && SECOND_LEVEL.includes(labels[labels.length - 2])
+      && labels[labels.length - 1].length === 2 ? 3 : 2;
+    return labels.slice(-n).join(".");
+  };
+  const test = (patterns, href) => patterns.some((pattern) => {
+    try {
+      return new RegExp(pattern).test(href);
+    } catch {
+      return false;
+    }
+  });
+  const isWeb = (u) => u.protocol === "http:" || u.protocol === "https:";
+
+  // 返回处理方式；不参与规则匹配的链接返回 null
+  nob.linkAction = (href) => {
+    if (!href) return null;
+    let target;
+    try {
+      target = new URL(href, location.href);
+    } catch {
+      return null;
+    }
+    if (!SCHEMES.includes(target.protocol)) return null;
+    const source = new URL(location.href);
+    const targetHref = target.href.split("#")[0];
+    const sourceHref = source.href.split("#")[0];
+    for (const rule of nob.linkRules) {
+      if (rule.source.length && !test(rule.source, sourceHref)) continue;
+      if (rule.target.length && !test(rule.target, targetHref)) continue;
+      if (rule.relation !== "any") {
+        if (!isWeb(source) || !isWeb(target)) continue;
+        const sameSite = site(target.hostname) === site(source.hostname);
+        if (rule.relation === "same_origin" && target.origin !== source.origin) continue;
+        if (rule.relation === "same_site" && !sameSite) continue;
+        if (rule.relation === "cross_site" && sameSite) continue;
+      }
+      return rule.action;
+    }
+    return "stay";
+  };
+
+  // 按规则打开链接，返回 true 表示已处理（调用方应阻止默认行为）
+  // newWindow：链接原本要在新窗口中打开（target=_blank、window.open）
+  nob.followLink = (href, newWindow) => {
+    const action = nob.linkAction(href);
+    if (!action) return !!newWindow;
+    const url = new URL(href, location.href).href;
+    switch (action) {
+      case "external":
+        nob.openExternal(url);
+        return true;
+      case "new_tab":
+        nob.invoke("tabs_open_link", { url }).catch((e) =>
+          console.warn("[NoB] open link in new tab failed", e)
+        );
+        return true;
+      case "block":
+        console.log("[NoB] link blocked by rule", url);
+        return true;
+      default:
+        if (!newWindow) return false;
+        location.assign(url);
+        return true;
+    }
+  };
+})();
+"##;
+
+/// 从数据库重新读取已启用的规则（启动时和修改后调用）
+pub async fn reload(db: &DatabaseConnection) -> Result<()> {
+    let rules = LinkRuleService::list_enabled(db).await?;
+    *RULES.lock().unwrap() = rules;
+    Ok(())
+}
+
+/// 按当前规则决定链接的处理方式
+///
+/// `source` 为空表示没有来源页面（窗口的第一次导航），此时只有不限制来源和关系的规则能匹配
+pub fn decide(source: Option<&Url>, target: &Url, scope: RuleScope) -> LinkAction {
+    evaluate(&RULES.lock().unwrap(), source, target, scope)
+}
+
+/// 第一条匹配的规则决定处理方式，没有匹配的规则时留在当前标签
+fn evaluate(
+    rules: &[LinkRule],
+    source: Option<&Url>,
+    target: &Url,
+    scope: RuleScope,
+) -> LinkAction {
+    if !HANDLED_SCHEMES.contains(&target.scheme()) {
+        return LinkAction::Stay;
+    }
+    let target_href = &target[..Position::AfterQuery];
+    let source_href = source.map(|source| &source[..Position::AfterQuery]);
+
+    rules
+        .iter()
+        .filter(|rule| rule.scope.covers(scope))
+        .find(|rule| {
+            if !rule.source_patterns.is_empty() {
+                let Some(source_href) = source_href else {
+                    return false;
+                };
+                if !any_match(&rule.source_patterns, source_href) {
+                    return false;
+                }
+            }
+            if !rule.target_patterns.is_empty() && !any_match(&rule.target_patterns, target_href) {
+                return false;
+            }
+            match (rule.relation, source) {
+                (LinkRelation::Any, _) => true,
+                (_, None) => false,
+                (relation, Some(source)) => relation_matches(relation, source, target),
+            }
+        })
+        .map(|rule| rule.action)
+        .unwrap_or_default()
+}
+
+fn any_match(patterns: &[String], href: &str) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| pattern::matches(pattern, href))
+}
+
+fn relation_matches(relation: LinkRelation, source: &Url, target: &Url) -> bool {
+    let is_web = |url: &Url| matches!(url.scheme(), "http" | "https");
+    if !is_web(source) || !is_web(target) {
+        return false;
+    }
+    let same_site = site(source) == site(target);
+    match relation {
+        LinkRelation::Any => true,
+        LinkRelation::SameOrigin => source.origin() == target.origin(),
+        LinkRelation::SameSite => same_site,
+        LinkRelation::CrossSite => !same_site,
+    }
+}
+
+/// 注入脚本：规则引擎 + 作用于页面链接的规则
+pub fn script() -> String {
+    let rules: Vec<_> = RULES
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|rule| rule.scope.covers(RuleScope::Links))
+        .map(|rule| {
+            json!({
+                "source": regexes(&rule.source_patterns),
+                "target": regexes(&rule.target_patterns),
+                "relation": rule.relation,
+                "action": rule.action,
+            })
+        })
+        .collect();
+    ENGINE_SCRIPT
+        .replace("__RULES__", &json!(rules).to_string())
+        .replace("__SECOND_LEVEL__", &json!(SECOND_LEVEL_LABELS).to_string())
+}
+
+/// 无法转换的规则用永不匹配的正则代替，避免退化为匹配任意 URL
+fn regexes(patterns: &[String]) -> Vec<String> {
+    patterns
+        .iter()
+        .map(|pattern| pattern::to_regex(pattern).unwrap_or_else(|| "(?!)".to_string()))
+        .collect()
+}
+
+/// 记录内容窗口开始加载的页面（主框架），之后的导航以它为来源
+pub fn record_page(label: &str, url: &Url) {
+    let mut pages = PAGES.lock().unwrap();
+    pages.retain(|(page_label, _)| page_label != label);
+    pages.push((label.to_string(), url.clone()));
+}
+
+/// 标记内容窗口即将由应用导航到 url，导航钩子放行这一次导航
+///
+/// 调用方已经更新了标签记录，被规则拦截会让记录与页面不一致
+pub fn expect_navigation(label: &str, url: &Url) {
+    let mut expected = EXPECTED.lock().unwrap();
+    expected.retain(|(expected_label, _)| expected_label != label);
+    expected.push((label.to_string(), url.clone()));
+}
+
+/// 取出窗口对 url 的导航标记，没有时返回 false
+fn take_expected(label: &str, url: &Url) -> bool {
+    let mut expected = EXPECTED.lock().unwrap();
+    match expected.iter().position(|(l, u)| l == label && u == url) {
+        Some(index) => {
+            expected.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
+/// 内容窗口销毁时清除它的页面和导航标记
+pub fn forget_window(label: &str) {
+    PAGES
+        .lock()
+        .unwrap()
+        .retain(|(page_label, _)| page_label != label);
+    EXPECTED
+        .lock()
+        .unwrap()
+        .retain(|(expected_label, _)| expected_label != label);
+}
+
+/// 内容窗口的导航钩子：按作用于导航的规则处理，返回是否继续导航
+///
+/// 窗口的第一次导航（打开标签时的页面）和应用发起的导航总是允许
+pub fn navigation_handler(
+    app: &AppHandle<Wry>,
+    label: &str,
+) -> impl Fn(&Url) -> bool + Send + 'static {
+    // 重建的窗口不沿用之前的页面
+    forget_window(label);
+
+    let app = app.clone();
+    let label = label.to_string();
+    move |url: &Url| {
+        if take_expected(&label, url) {
+            return true;
+        }
+        let source = PAGES
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(page_label, _)| *page_label == label)
+            .map(|(_, page)| page.clone());
+        let Some(source) = source else {
+            return true;
+        };
+
+        match decide(Some(&source), url, RuleScope::Navigation) {
+            LinkAction::Stay => true,
+            LinkAction::Block => {
+                println!("[LinkRule] Blocked navigation to {}", url);
+                false
+            }
+            LinkAction::External => {
+                if let Err(e) = app.opener().open_url(url.as_str(), None::<&str>) {
+                    println!("[LinkRule] Failed to open {} externally: {}", url, e);
+                }
+                false
+            }
+            LinkAction::NewTab => {
+                open_in_new_tab(&app, url.to_string());
+                false
+            }
+        }
+    }
+}
+
+/// 在新的标签中打开链接
+pub fn open_in_new_tab(app: &AppHandle<Wry>, url: String) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let Some(state) = app.try_state::<AppState>() else {
+            return;
+        };
+        let payload = CreateTabPayload {
+            url,
+            title: None,
+            favicon_url: None,
+            is_pinned: None,
+            activate: Some(true),
+        };
+        if let Err(e) = tab_commands::tabs_create(state, payload).await {
+            println!("[LinkRule] Failed to open link in new tab: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(
+        sources: &[&str],
+        targets: &[&str],
+        relation: LinkRelation,
+        scope: RuleScope,
+        action: LinkAction,
+    ) -> LinkRule {
+        LinkRule {
+            id: String::new(),
+            name: String::new(),
+            source_patterns: sources.iter().map(|p| p.to_string()).collect(),
+            target_patterns: targets.iter().map(|p| p.to_string()).collect(),
+            relation,
+            scope,
+            action,
+            priority: 0,
+            enabled: true,
+            builtin: false,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn url(value: &str) -> Url {
+        Url::parse(value).unwrap()
+    }
+
+    /// 与内置规则相同的顺序：登录页、同站点留在标签内，跨站链接用系统浏览器打开
+    fn defaults() -> Vec<LinkRule> {
+        vec![
+            rule(
+                &[],
+                &["*://accounts.google.com/*"],
+                LinkRelation::Any,
+                RuleScope::All,
+                LinkAction::Stay,
+            ),
+            rule(
+                &["*://accounts.google.com/*"],
+                &[],
+                LinkRelation::Any,
+                RuleScope::All,
+                LinkAction::Stay,
+            ),
+            rule(&[], &[], LinkRelation::SameSite, RuleScope::All, LinkAction::Stay),
+            rule(
+                &[],
+                &["mailto:*"],
+                LinkRelation::Any,
+                RuleScope::All,
+                LinkAction::External,
+            ),
+            rule(
+                &[],
+                &[],
+                LinkRelation::CrossSite,
+                RuleScope::Links,
+                LinkAction::External,
+            ),
+        ]
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let rules = vec![
+            rule(
+                &[],
+                &["https://example.com/*"],
+                LinkRelation::Any,
+                RuleScope::All,
+                LinkAction::Block,
+            ),
+            rule(&[], &[], LinkRelation::Any, RuleScope::All, LinkAction::NewTab),
+        ];
+        let source = url("https://other.test/");
+
+        assert_eq!(
+            evaluate(&rules, Some(&source), &url("https://example.com/a"), RuleScope::Links),
+            LinkAction::Block
+        );
+        assert_eq!(
+            evaluate(&rules, Some(&source), &url("https://example.org/a"), RuleScope::Links),
+            LinkAction::NewTab
+        );
+        assert_eq!(
+            evaluate(&[], Some(&source), &url("https://example.org/a"), RuleScope::Links),
+            LinkAction::Stay
+        );
+    }
+
+    #[test]
+    fn rules_are_filtered_by_scope() {
+        let rules = defaults();
+        let source = url("https://app.example.com/");
+        let target = url("https://elsewhere.test/");
+
+        assert_eq!(
+            evaluate(&rules, Some(&source), &target, RuleScope::Links),
+            LinkAction::External
+        );
+        // 跨站规则只作用于链接，重定向等导航留在当前标签
+        assert_eq!(
+            evaluate(&rules, Some(&source), &target, RuleScope::Navigation),
+            LinkAction::Stay
+        );
+
+        let navigation_only = vec![rule(
+            &[],
+            &[],
+            LinkRelation::Any,
+            RuleScope::Navigation,
+            LinkAction::Block,
+        )];
+        assert_eq!(
+            evaluate(&navigation_only, Some(&source), &target, RuleScope::Links),
+            LinkAction::Stay
+        );
+        assert_eq!(
+            evaluate(&navigation_only, Some(&source), &target, RuleScope::Navigation),
+            LinkAction::Block
+        );
+    }
+
+    #[test]
+    fn source_less_navigation_only_matches_unrestricted_rules() {
+        let target = url("https://example.com/");
+        let with_source = vec![rule(
+            &["*://*/*"],
+            &[],
+            LinkRelation::Any,
+            RuleScope::All,
+            LinkAction::Block,
+        )];
+        let with_relation = vec![rule(
+            &[],
+            &[],
+            LinkRelation::CrossSite,
+            RuleScope::All,
+            LinkAction::Block,
+        )];
+        let unrestricted = vec![rule(
+            &[],
+            &["https://example.com/*"],
+            LinkRelation::Any,
+            RuleScope::All,
+            LinkAction::NewTab,
+        )];
+
+        assert_eq!(
+            evaluate(&with_source, None, &target, RuleScope::Navigation),
+            LinkAction::Stay
+        );
+        assert_eq!(
+            evaluate(&with_relation, None, &target, RuleScope::Navigation),
+            LinkAction::Stay
+        );
+        assert_eq!(
+            evaluate(&unrestricted, None, &target, RuleScope::Navigation),
+            LinkAction::NewTab
+        );
+    }
+
+    #[test]
+    fn default_rules() {
+        let rules = defaults();
+        let docs = url("https://docs.x.com/guide");
+        let decide = |source: &Url, target: &str| {
+            evaluate(&rules, Some(source), &url(target), RuleScope::Links)
+        };
+
+        // 同产品的子域名留在当前标签
+        assert_eq!(decide(&docs, "https://app.x.com/"), LinkAction::Stay);
+        // 单点登录跳转过去和跳转回来都留在当前标签
+        assert_eq!(
+            decide(&docs, "https://accounts.google.com/o/oauth2"),
+            LinkAction::Stay
+        );
+        assert_eq!(
+            decide(&url("https://accounts.google.com/o/oauth2"), "https://app.x.com/callback"),
+            LinkAction::Stay
+        );
+        assert_eq!(decide(&docs, "mailto:someone@x.com"), LinkAction::External);
+        assert_eq!(decide(&docs, "https://y.com/"), LinkAction::External);
+    }
+
+    #[test]
+    fn unhandled_schemes_and_fragments() {
+        let rules = vec![rule(
+            &[],
+            &["https://example.com/page"],
+            LinkRelation::Any,
+            RuleScope::All,
+            LinkAction::Block,
+        )];
+        let source = url("https://example.com/");
+
+        assert_eq!(
+            evaluate(&rules, Some(&source), &url("https://example.com/page#top"), RuleScope::Links),
+            LinkAction::Block
+        );
+        let block_all = vec![rule(&[], &[], LinkRelation::Any, RuleScope::All, LinkAction::Block)];
+        for target in ["about:blank", "data:text/plain,x", "javascript:void(0)"] {
+            assert_eq!(
+                evaluate(&block_all, Some(&source), &url(target), RuleScope::Links),
+                LinkAction::Stay
+            );
+        }
+    }
+
+    #[test]
+    fn relation_matching() {
+        let source = url("https://app.example.com/");
+        let same_origin = url("https://app.example.com:443/other");
+        let other_port = url("https://app.example.com:8443/");
+        let same_site = url("https://docs.example.com/");
+        let cross_site = url("https://example.org/");
+        let co_uk = url("https://shop.example.co.uk/");
+        let other_co_uk = url("https://other.co.uk/");
+
+        assert!(relation_matches(LinkRelation::SameOrigin, &source, &same_origin));
+        assert!(!relation_matches(LinkRelation::SameOrigin, &source, &other_port));
+        assert!(!relation_matches(LinkRelation::SameOrigin, &source, &same_site));
+
+        assert!(relation_matches(LinkRelation::SameSite, &source, &other_port));
+        assert!(relation_matches(LinkRelation::SameSite, &source, &same_site));
+        assert!(!relation_matches(LinkRelation::SameSite, &source, &cross_site));
+        assert!(relation_matches(LinkRelation::CrossSite, &source, &cross_site));
+
+        assert!(relation_matches(
+            LinkRelation::SameSite,
+            &url("https://example.co.uk/"),
+            &co_uk
+        ));
+        assert!(relation_matches(LinkRelation::CrossSite, &co_uk, &other_co_uk));
+
+        // 非网页链接不参与关系匹配
+        let mail = url("mailto:someone@example.com");
+        assert!(!relation_matches(LinkRelation::CrossSite, &source, &mail));
+        assert!(!relation_matches(LinkRelation::SameSite, &source, &mail));
+    }
+
+    #[test]
+    fn app_navigations_are_allowed_once() {
+        let label = "content-expected-test";
+        let target = url("https://example.com/next");
+        assert!(!take_expected(label, &target));
+
+        expect_navigation(label, &target);
+        assert!(!take_expected("content-other", &target));
+        assert!(!take_expected(label, &url("https://example.com/other")));
+        assert!(take_expected(label, &target));
+        assert!(!take_expected(label, &target));
+
+        // 新的导航替换未开始的导航，窗口销毁时清除
+        expect_navigation(label, &url("https://example.com/a"));
+        expect_navigation(label, &target);
+        assert!(!take_expected(label, &url("https://example.com/a")));
+        forget_window(label);
+        assert!(!take_expected(label, &target));
+    }
+}
@@ -0,0 +1,5 @@
+// 核心业务层
+pub mod engine;
+pub mod models;
+pub mod pattern;
+pub mod service;
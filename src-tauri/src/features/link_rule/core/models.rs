@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::link_rule::data::entity;
+
+/// 链接的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkAction {
+    /// 在当前标签中打开
+    #[default]
+    Stay,
+    /// 在新的 NoB 标签中打开
+    NewTab,
+    /// 用系统默认浏览器打开
+    External,
+    /// 阻止打开
+    Block,
+}
+
+impl LinkAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Stay => "stay",
+            Self::NewTab => "new_tab",
+            Self::External => "external",
+            Self::Block => "block",
+        }
+    }
+
+    /// 从数据库中的值解析，未知值按 stay 处理
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "new_tab" => Self::NewTab,
+            "external" => Self::External,
+            "block" => Self::Block,
+            _ => Self::Stay,
+        }
+    }
+}
+
+/// 目标链接与来源页面的关系
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkRelation {
+    #[default]
+    Any,
+    /// 协议、域名和端口都相同
+    SameOrigin,
+    /// 注册域名相同（如 a.example.com 与 b.example.com）
+    SameSite,
+    CrossSite,
+}
+
+impl LinkRelation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::SameOrigin => "same_origin",
+            Self::SameSite => "same_site",
+            Self::CrossSite => "cross_site",
+        }
+    }
+
+    /// 从数据库中的值解析，未知值按 any 处理
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "same_origin" => Self::SameOrigin,
+            "same_site" => Self::SameSite,
+            "cross_site" => Self::CrossSite,
+            _ => Self::Any,
+        }
+    }
+}
+
+/// 规则的作用范围
+///
+/// 注入脚本只处理页面中的链接点击和 window.open；Rust 侧的导航钩子还会收到
+/// 重定向、表单提交和 iframe 中的导航，且无法区分是否为 iframe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleScope {
+    #[default]
+    All,
+    /// 仅页面中的链接（注入脚本）
+    Links,
+    /// 仅导航（Rust 导航钩子）
+    Navigation,
+}
+
+impl RuleScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Links => "links",
+            Self::Navigation => "navigation",
+        }
+    }
+
+    /// 从数据库中的值解析，未知值按 all 处理
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "links" => Self::Links,
+            "navigation" => Self::Navigation,
+            _ => Self::All,
+        }
+    }
+
+    /// 规则是否作用于 `scope`
+    pub fn covers(&self, scope: RuleScope) -> bool {
+        *self == Self::All || *self == scope
+    }
+}
+
+/// 链接处理规则（用于 API 响应）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkRule {
+    pub id: String,
+    pub name: String,
+    /// 来源页面匹配规则，为空时匹配任意页面
+    pub source_patterns: Vec<String>,
+    /// 目标链接匹配规则，为空时匹配任意链接
+    pub target_patterns: Vec<String>,
+    pub relation: LinkRelation,
+    pub scope: RuleScope,
+    pub action: LinkAction,
+    pub priority: i32,
+    pub enabled: bool,
+    pub builtin: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<entity::Model> for LinkRule {
+    fn from(model: entity::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            source_patterns: serde_json::from_str(&model.source_patterns).unwrap_or_default(),
+            target_patterns: serde_json::from_str(&model.target_patterns).unwrap_or_default(),
+            relation: LinkRelation::parse(&model.relation),
+            scope: RuleScope::parse(&model.scope),
+            action: LinkAction::parse(&model.action),
+            priority: model.priority,
+            enabled: model.enabled,
+            builtin: model.builtin,
+            created_at: model.created_at.to_rfc3339(),
+            updated_at: model.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateLinkRulePayload {
+    pub name: String,
+    pub source_patterns: Option<Vec<String>>,
+    pub target_patterns: Option<Vec<String>>,
+    /// 默认 any
+    pub relation: Option<LinkRelation>,
+    /// 默认 all
+    pub scope: Option<RuleScope>,
+    pub action: LinkAction,
+    /// 默认排在所有规则之后
+    pub priority: Option<i32>,
+    /// 默认启用
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateLinkRulePayload {
+    pub id: String,
+    pub name: Option<String>,
+    pub source_patterns: Option<Vec<String>>,
+    pub target_patterns: Option<Vec<String>>,
+    pub relation: Option<LinkRelation>,
+    pub scope: Option<RuleScope>,
+    pub action: Option<LinkAction>,
+    pub priority: Option<i32>,
+    pub enabled: Option<bool>,
+}
@@ -0,0 +1,232 @@
+use anyhow::{anyhow, Result};
+
+use crate::infrastructure::web::{pattern_to_regex, split_port};
+
+/// 检查链接规则中的匹配规则
+///
+/// 写法与站点脚本相同（见 [`pattern_to_regex`]），但不支持 `/regex/`：
+/// 规则同时在 Rust 导航钩子中匹配，两侧的结果需要一致
+pub fn validate(pattern: &str) -> Result<()> {
+    let pattern = pattern.trim();
+    if is_regex(pattern) {
+        return Err(anyhow!(
+            "regular expression '{}' is not supported in link rules",
+            pattern
+        ));
+    }
+    pattern_to_regex(pattern).map(|_| ())
+}
+
+/// 注入脚本中使用的正则表达式源码
+pub fn to_regex(pattern: &str) -> Option<String> {
+    validate(pattern).ok()?;
+    pattern_to_regex(pattern).ok()
+}
+
+/// 匹配不含 fragment 的 URL，结果与 [`to_regex`] 生成的正则一致
+pub fn matches(pattern: &str, href: &str) -> bool {
+    let pattern = pattern.trim();
+    if pattern.is_empty() || is_regex(pattern) {
+        return false;
+    }
+    if pattern == "<all_urls>" {
+        return href.starts_with("http://") || href.starts_with("https://");
+    }
+
+    let Some((scheme, rest)) = pattern.split_once("://") else {
+        return glob_match(pattern, href);
+    };
+    if !matches!(scheme, "*" | "http" | "https") {
+        return glob_match(pattern, href);
+    }
+    let Some((host, path)) = rest.find('/').map(|index| rest.split_at(index)) else {
+        return false;
+    };
+    let Some((host, port)) = split_port(host) else {
+        return false;
+    };
+
+    let Some((href_scheme, href_rest)) = href.split_once("://") else {
+        return false;
+    };
+    let scheme_ok = match scheme {
+        "*" => href_scheme == "http" || href_scheme == "https",
+        _ => href_scheme == scheme,
+    };
+    if !scheme_ok {
+        return false;
+    }
+
+    let (authority, href_path) = match href_rest.find('/') {
+        Some(index) => href_rest.split_at(index),
+        None => (href_rest, ""),
+    };
+    let Some((href_host, href_port)) = split_port(authority) else {
+        return false;
+    };
+    if port.is_some() && port != href_port {
+        return false;
+    }
+    let host_ok = if host == "*" {
+        !href_host.is_empty()
+    } else if let Some(domain) = host.strip_prefix("*.") {
+        href_host == domain
+            || href_host
+                .strip_suffix(domain)
+                .is_some_and(|prefix| prefix.len() > 1 && prefix.ends_with('.'))
+    } else {
+        href_host == host
+    };
+
+    host_ok && glob_match(path, href_path)
+}
+
+fn is_regex(pattern: &str) -> bool {
+    pattern.len() > 2 && pattern.starts_with('/') && pattern.ends_with('/')
+}
+
+/// 通配符匹配：`*` 匹配任意字符（包括空），其余字符按字面匹配
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::features::link_rule::data::migration::SIGN_IN_PATTERNS;
+
+    const URLS: &[&str] = &[
+        "https://accounts.google.com/signin/v2",
+        "http://accounts.google.com/",
+        "https://github.com/login",
+        "https://github.com/login/oauth/authorize?client_id=1",
+        "https://github.com/sessions/two-factor",
+        "https://github.com/YigesMx/nob",
+        "https://acme.okta.com/app/x",
+        "https://okta.com/",
+        "https://notokta.com/",
+        "https://example.com/",
+        "https://example.com:8443/app/x?y=1",
+        "http://example.com:8080/",
+        "https://docs.example.com/guide",
+        "https://a.b.example.com/",
+        "https://example.com.evil.test/",
+        "https://example.co.uk/",
+        "https://shop.example.co.uk/cart",
+        "https://co.uk/",
+        "ftp://example.com/file",
+        "mailto:someone@example.com",
+        "tel:+123456",
+    ];
+
+    fn extra_patterns() -> Vec<&'static str> {
+        vec![
+            "<all_urls>",
+            "mailto:*",
+            "tel:*",
+            "*://*/*",
+            "https://example.com/*",
+            "https://example.com/app/*",
+            "http://example.com/",
+            "*://*.example.com/*",
+            "*://*.example.co.uk/*",
+            "https://*.co.uk/*",
+            "*example.com*",
+            "https://example.com:8443/*",
+            "*://*:8080/*",
+        ]
+    }
+
+    #[test]
+    fn matches_agrees_with_generated_regex() {
+        let patterns = SIGN_IN_PATTERNS.iter().copied().chain(extra_patterns());
+        for pattern in patterns {
+            let regex = Regex::new(&to_regex(pattern).unwrap()).unwrap();
+            for url in URLS {
+                assert_eq!(
+                    matches(pattern, url),
+                    regex.is_match(url),
+                    "pattern {} on {}",
+                    pattern,
+                    url
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn built_in_sign_in_patterns() {
+        let signs_in = |url| SIGN_IN_PATTERNS.iter().any(|pattern| matches(pattern, url));
+        assert!(signs_in("https://accounts.google.com/signin/v2"));
+        assert!(signs_in("https://github.com/login/oauth/authorize?client_id=1"));
+        assert!(signs_in("https://acme.okta.com/app/x"));
+        assert!(!signs_in("https://github.com/YigesMx/nob"));
+        assert!(!signs_in("https://notokta.com/"));
+    }
+
+    #[test]
+    fn ports() {
+        assert!(matches("https://example.com/*", "https://example.com:8443/a"));
+        assert!(matches("https://example.com:8443/*", "https://example.com:8443/a"));
+        assert!(!matches("https://example.com:8443/*", "https://example.com/a"));
+        assert!(!matches("https://example.com:8443/*", "https://example.com:9443/a"));
+        assert!(matches("*://*:8080/*", "http://example.com:8080/"));
+        assert!(!matches("*://*:8080/*", "http://example.com/"));
+    }
+
+    #[test]
+    fn wildcard_subdomains() {
+        assert!(matches("*://*.example.com/*", "https://example.com/"));
+        assert!(matches("*://*.example.com/*", "https://a.b.example.com/"));
+        assert!(!matches("*://*.example.com/*", "https://badexample.com/"));
+        assert!(!matches("*://*.example.com/*", "https://example.com.evil.test/"));
+        assert!(matches("*://*.example.co.uk/*", "https://shop.example.co.uk/cart"));
+        assert!(!matches("*://*.example.co.uk/*", "https://co.uk/"));
+    }
+
+    #[test]
+    fn regex_patterns_are_rejected() {
+        assert!(validate("/example/").is_err());
+        assert!(to_regex("/example/").is_none());
+        assert!(!matches("/example/", "https://example.com/"));
+    }
+
+    #[test]
+    fn invalid_patterns_never_match() {
+        assert!(validate("").is_err());
+        assert!(validate("https://example.com").is_err());
+        assert!(!matches("", "https://example.com/"));
+        assert!(!matches("https://example.com", "https://example.com/"));
+    }
+
+    #[test]
+    fn glob_match_backtracks() {
+        assert!(glob_match("*a*b", "xxaxxab"));
+        assert!(glob_match("a**", "a"));
+        assert!(!glob_match("a*b", "acbd"));
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "a"));
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use crate::features::link_rule::core::models::{
+    CreateLinkRulePayload, LinkRule, UpdateLinkRulePayload,
+};
+use crate::features::link_rule::core::pattern;
+use crate::features::link_rule::data::entity::{self, Entity as LinkRuleEntity, Model};
+
+/// 链接处理规则服务
+pub struct LinkRuleService;
+
+impl LinkRuleService {
+    /// 所有规则（按匹配顺序）
+    pub async fn list(db: &DatabaseConnection) -> Result<Vec<Model>> {
+        let rules = LinkRuleEntity::find()
+            .order_by_asc(entity::Column::Priority)
+            .order_by_asc(entity::Column::CreatedAt)
+            .all(db)
+            .await?;
+        Ok(rules)
+    }
+
+    /// 已启用的规则（按匹配顺序）
+    pub async fn list_enabled(db: &DatabaseConnection) -> Result<Vec<LinkRule>> {
+        let rules = LinkRuleEntity::find()
+            .filter(entity::Column::Enabled.eq(true))
+            .order_by_asc(entity::Column::Priority)
+            .order_by_asc(entity::Column::CreatedAt)
+            .all(db)
+            .await?;
+        Ok(rules.into_iter().map(LinkRule::from).collect())
+    }
+
+    pub async fn create(db: &DatabaseConnection, payload: CreateLinkRulePayload) -> Result<Model> {
+        let name = normalize_name(&payload.name)?;
+        let sources = normalize_patterns(payload.source_patterns.unwrap_or_default())?;
+        let targets = normalize_patterns(payload.target_patterns.unwrap_or_default())?;
+        let priority = match payload.priority {
+            Some(priority) => priority,
+            None => Self::next_priority(db).await?,
+        };
+
+        let now = Utc::now();
+        let model = entity::ActiveModel {
+            id: Set(Uuid::new_v4().to_string()),
+            name: Set(name),
+            source_patterns: Set(serde_json::to_string(&sources)?),
+            target_patterns: Set(serde_json::to_string(&targets)?),
+            relation: Set(payload.relation.unwrap_or_default().as_str().to_string()),
+            scope: Set(payload.scope.unwrap_or_default().as_str().to_string()),
+            action: Set(payload.action.as_str().to_string()),
+            priority: Set(priority),
+            enabled: Set(payload.enabled.unwrap_or(true)),
+            builtin: Set(false),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(db)
+        .await?;
+        Ok(model)
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        payload: UpdateLinkRulePayload,
+    ) -> Result<Option<Model>> {
+        let Some(rule) = LinkRuleEntity::find_by_id(payload.id.clone())
+            .one(db)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let mut active_model: entity::ActiveModel = rule.into();
+        if let Some(name) = payload.name {
+            active_model.name = Set(normalize_name(&name)?);
+        }
+        if let Some(sources) = payload.source_patterns {
+            active_model.source_patterns =
+                Set(serde_json::to_string(&normalize_patterns(sources)?)?);
+        }
+        if let Some(targets) = payload.target_patterns {
+            active_model.target_patterns =
+                Set(serde_json::to_string(&normalize_patterns(targets)?)?);
+        }
+        if let Some(relation) = payload.relation {
+            active_model.relation = Set(relation.as_str().to_string());
+        }
+        if let Some(scope) = payload.scope {
+            active_model.scope = Set(scope.as_str().to_string());
+        }
+        if let Some(action) = payload.action {
+            active_model.action = Set(action.as_str().to_string());
+        }
+        if let Some(priority) = payload.priority {
+            active_model.priority = Set(priority);
+        }
+        if let Some(enabled) = payload.enabled {
+            active_model.enabled = Set(enabled);
+        }
+        active_model.updated_at = Set(Utc::now());
+
+        Ok(Some(active_model.update(db).await?))
+    }
+
+    /// 删除规则，返回被删除的规则（内置规则也可以删除，不需要时也可以停用）
+    pub async fn delete(db: &DatabaseConnection, id: &str) -> Result<Option<Model>> {
+        let Some(rule) = LinkRuleEntity::find_by_id(id.to_string()).one(db).await? else {
+            return Ok(None);
+        };
+        LinkRuleEntity::delete_by_id(id.to_string())
+            .exec(db)
+            .await?;
+        Ok(Some(rule))
+    }
+
+    /// 新规则默认排在最后一条用户规则之后，但在兜底的内置规则之前
+    async fn next_priority(db: &DatabaseConnection) -> Result<i32> {
+        let last = LinkRuleEntity::find()
+            .filter(entity::Column::Builtin.eq(false))
+            .order_by_desc(entity::Column::Priority)
+            .one(db)
+            .await?;
+        Ok(last.map(|rule| rule.priority + 10).unwrap_or(100))
+    }
+}
+
+fn normalize_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(anyhow!("rule name is required"));
+    }
+    Ok(name.to_string())
+}
+
+/// 去掉空白规则，并检查每条规则的写法（空列表表示匹配任意 URL）
+fn normalize_patterns(patterns: Vec<String>) -> Result<Vec<String>> {
+    let patterns: Vec<String> = patterns
+        .into_iter()
+        .map(|pattern| pattern.trim().to_string())
+        .filter(|pattern| !pattern.is_empty())
+        .collect();
+    for pattern in &patterns {
+        pattern::validate(pattern)?;
+    }
+    Ok(patterns)
+}
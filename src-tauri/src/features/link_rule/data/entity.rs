@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+
+/// 链接处理规则：按来源页面和目标链接决定如何打开
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "link_rules")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub name: String,
+    /// 来源页面匹配规则（JSON 字符串数组），为空时匹配任意页面
+    pub source_patterns: String,
+    /// 目标链接匹配规则（JSON 字符串数组），为空时匹配任意链接
+    pub target_patterns: String,
+    /// "any" / "same_origin" / "same_site" / "cross_site"
+    pub relation: String,
+    /// "all" / "links" / "navigation"
+    pub scope: String,
+    /// "stay" / "new_tab" / "external" / "block"
+    pub action: String,
+    /// 小的优先匹配
+    pub priority: i32,
+    pub enabled: bool,
+    /// 内置规则
+    pub builtin: bool,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unreachable!("link_rules has no relations")
+    }
+}
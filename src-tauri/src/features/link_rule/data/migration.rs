@@ -0,0 +1,140 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, EntityTrait, PaginatorTrait, Schema, Set};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigrationTrait;
+use uuid::Uuid;
+
+use super::entity;
+
+/// 登录与单点登录页面：跳转过去和跳转回来都留在当前标签
+pub(crate) const SIGN_IN_PATTERNS: &[&str] = &[
+    "*://accounts.google.com/*",
+    "*://login.microsoftonline.com/*",
+    "*://login.live.com/*",
+    "*://appleid.apple.com/*",
+    "*://github.com/login*",
+    "*://github.com/sessions/*",
+    "*://*.okta.com/*",
+    "*://*.auth0.com/*",
+    "*://*.onelogin.com/*",
+];
+
+/// 内置规则
+struct BuiltinRule {
+    name: &'static str,
+    sources: &'static [&'static str],
+    targets: &'static [&'static str],
+    relation: &'static str,
+    scope: &'static str,
+    action: &'static str,
+    priority: i32,
+}
+
+const BUILTIN_RULES: &[BuiltinRule] = &[
+    BuiltinRule {
+        name: "登录与单点登录",
+        sources: &[],
+        targets: SIGN_IN_PATTERNS,
+        relation: "any",
+        scope: "all",
+        action: "stay",
+        priority: 10,
+    },
+    BuiltinRule {
+        name: "从登录页返回",
+        sources: SIGN_IN_PATTERNS,
+        targets: &[],
+        relation: "any",
+        scope: "all",
+        action: "stay",
+        priority: 20,
+    },
+    BuiltinRule {
+        name: "同站点链接",
+        sources: &[],
+        targets: &[],
+        relation: "same_site",
+        scope: "all",
+        action: "stay",
+        priority: 30,
+    },
+    BuiltinRule {
+        name: "邮件与电话",
+        sources: &[],
+        targets: &["mailto:*", "tel:*"],
+        relation: "any",
+        scope: "all",
+        action: "external",
+        priority: 40,
+    },
+    // 仅作用于页面中的链接：重定向和 iframe 中的跨站导航不受影响
+    BuiltinRule {
+        name: "其他站点",
+        sources: &[],
+        targets: &[],
+        relation: "cross_site",
+        scope: "links",
+        action: "external",
+        priority: 1000,
+    },
+];
+
+#[derive(Debug, Clone, Copy)]
+pub struct LinkRuleMigration;
+
+impl MigrationName for LinkRuleMigration {
+    fn name(&self) -> &str {
+        "m20240101_000011_create_link_rules_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for LinkRuleMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_rules = schema.create_table_from_entity(entity::Entity);
+        create_rules.if_not_exists();
+
+        db.execute(backend.build(&create_rules))
+            .await
+            .map_err(|e| DbErr::Custom(format!("failed to create link_rules table: {}", e)))?;
+
+        // 写入内置规则
+        if entity::Entity::find().count(db).await? == 0 {
+            let now = Utc::now();
+            for rule in BUILTIN_RULES {
+                entity::ActiveModel {
+                    id: Set(Uuid::new_v4().to_string()),
+                    name: Set(rule.name.to_string()),
+                    source_patterns: Set(to_json(rule.sources)),
+                    target_patterns: Set(to_json(rule.targets)),
+                    relation: Set(rule.relation.to_string()),
+                    scope: Set(rule.scope.to_string()),
+                    action: Set(rule.action.to_string()),
+                    priority: Set(rule.priority),
+                    enabled: Set(true),
+                    builtin: Set(true),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                }
+                .insert(db)
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(entity::Entity).to_owned())
+            .await
+    }
+}
+
+fn to_json(patterns: &[&str]) -> String {
+    serde_json::to_string(patterns).unwrap_or_else(|_| "[]".to_string())
+}
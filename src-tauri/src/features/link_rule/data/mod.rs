@@ -0,0 +1,3 @@
+// 数据访问层
+pub mod entity;
+pub mod migration;
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::core::{AppState, Feature};
+use crate::infrastructure::database::{DatabaseRegistry, RegisteredMigration};
+use crate::infrastructure::injection::InjectionRegistry;
+
+use super::api::scripts;
+use super::core::engine;
+use super::data::migration;
+
+/// Link Rule Feature - 链接处理规则
+///
+/// 按规则决定页面中的链接和导航在当前标签、新标签还是系统浏览器中打开，或者阻止
+pub struct LinkRuleFeature;
+
+impl LinkRuleFeature {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl Feature for LinkRuleFeature {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "link_rule"
+    }
+
+    fn register_database(&self, registry: &mut DatabaseRegistry) {
+        registry.register_migrations(
+            self.name(),
+            vec![RegisteredMigration::new(migration::LinkRuleMigration, 1)],
+        );
+    }
+
+    fn register_scripts(&self, registry: &mut InjectionRegistry) {
+        registry.register_scripts(self.name(), scripts::script_modules());
+    }
+
+    fn command_names(&self) -> Vec<&'static str> {
+        vec![
+            "link_rules_list",
+            "link_rules_create",
+            "link_rules_update",
+            "link_rules_delete",
+        ]
+    }
+
+    async fn initialize(&self, app_state: &AppState) -> Result<()> {
+        engine::reload(app_state.db()).await?;
+        println!("[LinkRuleFeature] Initialized");
+        Ok(())
+    }
+}
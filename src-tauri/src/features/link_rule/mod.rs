@@ -0,0 +1,13 @@
+// Link Rule Feature - 链接处理规则
+//
+// 分层架构：
+// - api/: API 接口层（commands, scripts）
+// - core/: 核心业务层（service, models, pattern, engine）
+// - data/: 数据访问层（entity, migration）
+
+pub mod api;
+pub mod core;
+pub mod data;
+pub mod feature;
+
+pub use feature::LinkRuleFeature;
@@ -1,3 +1,7 @@
+pub mod backup;
+pub mod data_transfer;
+pub mod link_rule;
 pub mod settings;
+pub mod site_script;
 pub mod tab;
 pub mod window;
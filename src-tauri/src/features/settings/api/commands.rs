@@ -2,6 +2,7 @@ use tauri::State;
 
 use crate::core::AppState;
 use crate::features::settings::core::service::SettingService;
+use crate::features::window::appearance;
 
 #[derive(Debug, serde::Serialize)]
 pub struct ThemePreference {
@@ -48,6 +49,7 @@ pub async fn set_theme_preference(
         Ok(_) => {
             state.set_theme(normalized.clone());
             let _ = state.tray_manager().update_tray_menu(&state.app_handle());
+            appearance::apply(&state.app_handle());
             Ok(ThemePreference { theme: normalized })
         }
         Err(err) => {
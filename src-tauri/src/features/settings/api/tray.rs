@@ -1,7 +1,9 @@
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Manager};
 
 use crate::core::AppState;
 use crate::features::settings::core::service::SettingService;
+use crate::features::window::appearance;
+use crate::infrastructure::ipc::emit_to_app;
 use crate::infrastructure::tray::TrayMenuItem;
 
 pub fn theme_light_item() -> TrayMenuItem {
@@ -12,7 +14,8 @@ pub fn theme_light_item() -> TrayMenuItem {
                 let _ = SettingService::set(state.db(), "ui.theme", "light").await;
                 state.set_theme("light".to_string());
                 let _ = state.tray_manager().update_tray_menu(&app_handle);
-                let _ = app_handle.emit("theme-changed", "light");
+                appearance::apply(&app_handle);
+                let _ = emit_to_app(&app_handle, "theme-changed", "light");
             }
         });
     })
@@ -33,7 +36,8 @@ pub fn theme_dark_item() -> TrayMenuItem {
                 let _ = SettingService::set(state.db(), "ui.theme", "dark").await;
                 state.set_theme("dark".to_string());
                 let _ = state.tray_manager().update_tray_menu(&app_handle);
-                let _ = app_handle.emit("theme-changed", "dark");
+                appearance::apply(&app_handle);
+                let _ = emit_to_app(&app_handle, "theme-changed", "dark");
             }
         });
     })
@@ -54,7 +58,8 @@ pub fn theme_system_item() -> TrayMenuItem {
                 let _ = SettingService::set(state.db(), "ui.theme", "system").await;
                 state.set_theme("system".to_string());
                 let _ = state.tray_manager().update_tray_menu(&app_handle);
-                let _ = app_handle.emit("theme-changed", "system");
+                appearance::apply(&app_handle);
+                let _ = emit_to_app(&app_handle, "theme-changed", "system");
             }
         });
     })
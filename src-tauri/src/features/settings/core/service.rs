@@ -57,6 +57,19 @@ impl SettingService {
         Ok(model.into())
     }
 
+    /// 删除指定的设置项
+    pub async fn delete_keys(db: &DatabaseConnection, keys: Vec<String>) -> Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        SettingEntity::delete_many()
+            .filter(entity::Column::Key.is_in(keys))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
     /// 列出所有设置
     #[allow(dead_code)]
     pub async fn list(db: &DatabaseConnection) -> Result<Vec<entity::Model>> {
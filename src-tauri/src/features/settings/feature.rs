@@ -2,10 +2,9 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use sea_orm_migration::MigrationTrait;
 
 use crate::core::{AppState, Feature};
-use crate::infrastructure::database::DatabaseRegistry;
+use crate::infrastructure::database::{DatabaseRegistry, RegisteredMigration};
 
 use super::data::migration;
 
@@ -29,10 +28,10 @@ impl Feature for SettingsFeature {
 
     fn register_database(&self, registry: &mut DatabaseRegistry) {
         // 注册 Settings 数据表迁移
-        registry.register_migration("settings_migration", |manager| {
-            let migration = migration::SettingsMigration;
-            Box::pin(async move { migration.up(manager).await })
-        });
+        registry.register_migrations(
+            self.name(),
+            vec![RegisteredMigration::new(migration::SettingsMigration, 1)],
+        );
     }
 
     fn command_names(&self) -> Vec<&'static str> {
@@ -0,0 +1,76 @@
+use tauri::State;
+
+use crate::core::AppState;
+use crate::features::site_script::core::injection;
+use crate::features::site_script::core::models::{
+    CreateSiteScriptPayload, SiteScript, UpdateSiteScriptPayload,
+};
+use crate::features::site_script::core::service::SiteScriptService;
+
+/// 修改后刷新注入缓存，新打开或重新加载的页面使用新的脚本
+async fn refresh(app_state: &AppState) {
+    if let Err(e) = injection::reload(app_state.db()).await {
+        println!("[SiteScript] Failed to reload scripts: {}", e);
+    }
+}
+
+#[tauri::command]
+pub async fn site_scripts_list(app_state: State<'_, AppState>) -> Result<Vec<SiteScript>, String> {
+    SiteScriptService::list(app_state.db())
+        .await
+        .map(|scripts| scripts.into_iter().map(SiteScript::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn site_scripts_create(
+    app_state: State<'_, AppState>,
+    payload: CreateSiteScriptPayload,
+) -> Result<SiteScript, String> {
+    let script = SiteScriptService::create(app_state.db(), payload)
+        .await
+        .map(SiteScript::from)
+        .map_err(|e| e.to_string())?;
+    refresh(&app_state).await;
+    Ok(script)
+}
+
+#[tauri::command]
+pub async fn site_scripts_update(
+    app_state: State<'_, AppState>,
+    payload: UpdateSiteScriptPayload,
+) -> Result<Option<SiteScript>, String> {
+    let script = SiteScriptService::update(app_state.db(), payload)
+        .await
+        .map(|res| res.map(SiteScript::from))
+        .map_err(|e| e.to_string())?;
+    refresh(&app_state).await;
+    Ok(script)
+}
+
+#[tauri::command]
+pub async fn site_scripts_delete(
+    app_state: State<'_, AppState>,
+    id: String,
+) -> Result<Option<SiteScript>, String> {
+    let script = SiteScriptService::delete(app_state.db(), &id)
+        .await
+        .map(|res| res.map(SiteScript::from))
+        .map_err(|e| e.to_string())?;
+    refresh(&app_state).await;
+    Ok(script)
+}
+
+/// 导入带 userscript（`==UserScript==`）或 userstyle（`==UserStyle==`）头部的脚本
+#[tauri::command]
+pub async fn site_scripts_import(
+    app_state: State<'_, AppState>,
+    source: String,
+) -> Result<SiteScript, String> {
+    let script = SiteScriptService::import(app_state.db(), source)
+        .await
+        .map(SiteScript::from)
+        .map_err(|e| e.to_string())?;
+    refresh(&app_state).await;
+    Ok(script)
+}
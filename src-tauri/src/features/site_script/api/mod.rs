@@ -0,0 +1,3 @@
+// API 接口层
+pub mod commands;
+pub mod scripts;
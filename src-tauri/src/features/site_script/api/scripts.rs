@@ -0,0 +1,12 @@
+use crate::features::site_script::core::injection;
+use crate::infrastructure::injection::ScriptModule;
+
+/// Site Script Feature 注入的脚本模块
+pub fn script_modules() -> Vec<ScriptModule> {
+    vec![
+        ScriptModule::dynamic("site_script.user_scripts", "站点脚本", 100, |_| {
+            injection::scripts()
+        })
+        .reapply_on_load(),
+    ]
+}
@@ -0,0 +1,124 @@
+use std::sync::Mutex;
+
+use anyhow::Result;
+use sea_orm::DatabaseConnection;
+use serde_json::json;
+
+use crate::features::site_script::core::models::{ScriptKind, SiteScript};
+use crate::features::site_script::core::service::SiteScriptService;
+use crate::infrastructure::web::pattern_to_regex;
+
+/// 已启用的脚本（构建内容窗口时生成初始化脚本，不必每次查询数据库）
+static ENABLED: Mutex<Vec<SiteScript>> = Mutex::new(Vec::new());
+
+/// 页面中的运行时：按 URL 匹配、按时机执行，同一版本的脚本在一个页面中只执行一次
+const RUNTIME_SCRIPT: &str = r##"
+(() => {
+  if (window.__nobSiteScripts) return;
+  const ran = new Set();
+  const matches = (meta) => {
+    const href = location.href.split("#")[0];
+    return meta.patterns.some((pattern) => {
+      try {
+        return new RegExp(pattern).test(href);
+      } catch {
+        return false;
+      }
+    });
+  };
+  const whenReady = (runAt, fn) => {
+    if (runAt === "document_start") return fn();
+    if (runAt === "document_idle") {
+      if (document.readyState === "complete") return fn();
+      return window.addEventListener("load", fn, { once: true });
+    }
+    if (document.readyState !== "loading") return fn();
+    document.addEventListener("DOMContentLoaded", fn, { once: true });
+  };
+  const injectStyle = (id, css) => {
+    const parent = document.head || document.documentElement;
+    if (!parent) {
+      document.addEventListener("DOMContentLoaded", () => injectStyle(id, css), { once: true });
+      return;
+    }
+    let style = document.querySelector(`style[data-nob-site-script="${id}"]`);
+    if (!style) {
+      style = document.createElement("style");
+      style.dataset.nobSiteScript = id;
+      parent.appendChild(style);
+    }
+    style.textContent = css;
+  };
+  window.__nobSiteScripts = {
+    run(meta, body) {
+      if (!matches(meta) || ran.has(meta.key)) return;
+      ran.add(meta.key);
+      whenReady(meta.run_at, () => {
+        if (meta.kind === "css") return injectStyle(meta.id, body);
+        try {
+          body();
+        } catch (e) {
+          console.error("[NoB] Site script failed:", meta.name, e);
+        }
+      });
+    },
+    // 移除已删除、停用或不再匹配的脚本注入的样式
+    prune(metas) {
+      const keep = new Set(metas.filter(matches).map((meta) => meta.id));
+      document.querySelectorAll("style[data-nob-site-script]").forEach((el) => {
+        if (!keep.has(el.dataset.nobSiteScript)) el.remove();
+      });
+    },
+  };
+})();
+"##;
+
+/// 从数据库重新读取已启用的脚本（启动时和修改后调用）
+pub async fn reload(db: &DatabaseConnection) -> Result<()> {
+    let scripts = SiteScriptService::list_enabled(db).await?;
+    *ENABLED.lock().unwrap() = scripts;
+    Ok(())
+}
+
+/// 内容窗口的初始化脚本：运行时 + 每个脚本单独一段
+///
+/// 每个脚本单独作为一段初始化脚本，某个脚本有语法错误时不影响其他脚本
+pub fn scripts() -> Vec<String> {
+    let enabled = ENABLED.lock().unwrap().clone();
+    let metas: Vec<_> = enabled.iter().map(meta).collect();
+
+    let mut scripts = Vec::with_capacity(enabled.len() + 2);
+    scripts.push(RUNTIME_SCRIPT.to_string());
+    for (script, meta) in enabled.iter().zip(&metas) {
+        let body = match script.kind {
+            ScriptKind::Css => json!(script.code).to_string(),
+            ScriptKind::Js => format!("function () {{\n{}\n}}", script.code),
+        };
+        scripts.push(format!(
+            "window.__nobSiteScripts && window.__nobSiteScripts.run({}, {});",
+            meta, body
+        ));
+    }
+    scripts.push(format!(
+        "window.__nobSiteScripts && window.__nobSiteScripts.prune({});",
+        json!(metas)
+    ));
+    scripts
+}
+
+/// 注入运行时使用的脚本信息，key 随内容更新变化
+fn meta(script: &SiteScript) -> serde_json::Value {
+    let patterns: Vec<String> = script
+        .matches
+        .iter()
+        .filter_map(|pattern| pattern_to_regex(pattern).ok())
+        .collect();
+    json!({
+        "id": script.id,
+        "key": format!("{}:{}", script.id, script.updated_at),
+        "name": script.name,
+        "kind": script.kind,
+        "run_at": script.run_at,
+        "patterns": patterns,
+    })
+}
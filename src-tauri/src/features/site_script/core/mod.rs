@@ -0,0 +1,5 @@
+// 核心业务层
+pub mod injection;
+pub mod models;
+pub mod service;
+pub mod userscript;
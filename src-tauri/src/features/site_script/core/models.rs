@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::site_script::data::entity;
+
+/// 脚本类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptKind {
+    #[default]
+    Css,
+    Js,
+}
+
+impl ScriptKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Css => "css",
+            Self::Js => "js",
+        }
+    }
+
+    /// 从数据库中的值解析，未知值按 CSS 处理
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "js" => Self::Js,
+            _ => Self::Css,
+        }
+    }
+}
+
+/// 注入时机（与 userscript 的 @run-at 对应）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RunAt {
+    /// 页面开始加载时（DOM 尚未构建）
+    #[serde(rename = "document_start")]
+    Start,
+    /// DOMContentLoaded 之后
+    #[default]
+    #[serde(rename = "document_end")]
+    End,
+    /// 页面加载完成（load）之后
+    #[serde(rename = "document_idle")]
+    Idle,
+}
+
+impl RunAt {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Start => "document_start",
+            Self::End => "document_end",
+            Self::Idle => "document_idle",
+        }
+    }
+
+    /// 从数据库中的值解析，未知值按 document_end 处理
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "document_start" => Self::Start,
+            "document_idle" => Self::Idle,
+            _ => Self::End,
+        }
+    }
+}
+
+/// 站点脚本（用于 API 响应）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteScript {
+    pub id: String,
+    pub name: String,
+    pub kind: ScriptKind,
+    /// URL 匹配规则，任一匹配即注入
+    pub matches: Vec<String>,
+    pub code: String,
+    pub enabled: bool,
+    pub run_at: RunAt,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<entity::Model> for SiteScript {
+    fn from(model: entity::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            kind: ScriptKind::parse(&model.kind),
+            matches: serde_json::from_str(&model.matches).unwrap_or_default(),
+            code: model.code,
+            enabled: model.enabled,
+            run_at: RunAt::parse(&model.run_at),
+            created_at: model.created_at.to_rfc3339(),
+            updated_at: model.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateSiteScriptPayload {
+    pub name: String,
+    pub kind: ScriptKind,
+    pub matches: Vec<String>,
+    pub code: String,
+    /// 默认启用
+    pub enabled: Option<bool>,
+    /// 默认 document_end
+    pub run_at: Option<RunAt>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateSiteScriptPayload {
+    pub id: String,
+    pub name: Option<String>,
+    pub kind: Option<ScriptKind>,
+    pub matches: Option<Vec<String>>,
+    pub code: Option<String>,
+    pub enabled: Option<bool>,
+    pub run_at: Option<RunAt>,
+}
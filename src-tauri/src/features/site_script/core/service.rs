@@ -0,0 +1,224 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use crate::features::site_script::core::models::{
+    CreateSiteScriptPayload, SiteScript, UpdateSiteScriptPayload,
+};
+use crate::features::site_script::core::userscript;
+use crate::features::site_script::data::entity::{self, Entity as SiteScriptEntity, Model};
+use crate::infrastructure::web::pattern_to_regex;
+
+/// 站点脚本服务
+pub struct SiteScriptService;
+
+impl SiteScriptService {
+    pub async fn list(db: &DatabaseConnection) -> Result<Vec<Model>> {
+        let scripts = SiteScriptEntity::find()
+            .order_by_asc(entity::Column::CreatedAt)
+            .all(db)
+            .await?;
+        Ok(scripts)
+    }
+
+    /// 已启用的脚本（按创建顺序注入）
+    pub async fn list_enabled(db: &DatabaseConnection) -> Result<Vec<SiteScript>> {
+        let scripts = SiteScriptEntity::find()
+            .filter(entity::Column::Enabled.eq(true))
+            .order_by_asc(entity::Column::CreatedAt)
+            .all(db)
+            .await?;
+        Ok(scripts.into_iter().map(SiteScript::from).collect())
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        payload: CreateSiteScriptPayload,
+    ) -> Result<Model> {
+        let name = normalize_name(&payload.name)?;
+        let matches = normalize_matches(payload.matches)?;
+
+        let now = Utc::now();
+        let model = entity::ActiveModel {
+            id: Set(Uuid::new_v4().to_string()),
+            name: Set(name),
+            kind: Set(payload.kind.as_str().to_string()),
+            matches: Set(serde_json::to_string(&matches)?),
+            code: Set(payload.code),
+            enabled: Set(payload.enabled.unwrap_or(true)),
+            run_at: Set(payload.run_at.unwrap_or_default().as_str().to_string()),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(db)
+        .await?;
+        Ok(model)
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        payload: UpdateSiteScriptPayload,
+    ) -> Result<Option<Model>> {
+        let Some(script) = SiteScriptEntity::find_by_id(payload.id.clone())
+            .one(db)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let mut active_model: entity::ActiveModel = script.into();
+        if let Some(name) = payload.name {
+            active_model.name = Set(normalize_name(&name)?);
+        }
+        if let Some(kind) = payload.kind {
+            active_model.kind = Set(kind.as_str().to_string());
+        }
+        if let Some(matches) = payload.matches {
+            active_model.matches = Set(serde_json::to_string(&normalize_matches(matches)?)?);
+        }
+        if let Some(code) = payload.code {
+            active_model.code = Set(code);
+        }
+        if let Some(enabled) = payload.enabled {
+            active_model.enabled = Set(enabled);
+        }
+        if let Some(run_at) = payload.run_at {
+            active_model.run_at = Set(run_at.as_str().to_string());
+        }
+        active_model.updated_at = Set(Utc::now());
+
+        Ok(Some(active_model.update(db).await?))
+    }
+
+    /// 删除脚本，返回被删除的脚本
+    pub async fn delete(db: &DatabaseConnection, id: &str) -> Result<Option<Model>> {
+        let Some(script) = SiteScriptEntity::find_by_id(id.to_string()).one(db).await? else {
+            return Ok(None);
+        };
+        SiteScriptEntity::delete_by_id(id.to_string())
+            .exec(db)
+            .await?;
+        Ok(Some(script))
+    }
+
+    /// 从带 userscript / userstyle 头部的源码导入（整段源码作为脚本内容）
+    pub async fn import(db: &DatabaseConnection, source: String) -> Result<Model> {
+        let parsed = userscript::parse(&source)?;
+        Self::create(
+            db,
+            CreateSiteScriptPayload {
+                name: parsed.name,
+                kind: parsed.kind,
+                matches: parsed.matches,
+                code: source,
+                enabled: Some(true),
+                run_at: Some(parsed.run_at),
+            },
+        )
+        .await
+    }
+}
+
+fn normalize_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(anyhow!("script name is required"));
+    }
+    Ok(name.to_string())
+}
+
+/// 去掉空白规则，并检查每条规则都能转换为正则（`/…/` 写法由用户直接给出）
+fn normalize_matches(matches: Vec<String>) -> Result<Vec<String>> {
+    let matches: Vec<String> = matches
+        .into_iter()
+        .map(|pattern| pattern.trim().to_string())
+        .filter(|pattern| !pattern.is_empty())
+        .collect();
+    if matches.is_empty() {
+        return Err(anyhow!("at least one match pattern is required"));
+    }
+    for pattern in &matches {
+        let source = pattern_to_regex(pattern)?;
+        check_js_regex(&source)
+            .map_err(|e| anyhow!("invalid regex in match pattern '{}': {}", pattern, e))?;
+    }
+    Ok(matches)
+}
+
+/// 规则在页面中由 JS 的 `RegExp` 编译，可以使用环视、反向引用等 Rust regex 不支持的写法，
+/// 因此这里只检查括号是否配对、结尾是否有多余的 `\`；其他错误在页面中会使该规则不匹配
+fn check_js_regex(source: &str) -> Result<()> {
+    let mut groups = 0usize;
+    let mut in_class = false;
+    let mut chars = source.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if chars.next().is_none() {
+                    return Err(anyhow!("trailing backslash"));
+                }
+            }
+            ']' if in_class => in_class = false,
+            _ if in_class => {}
+            '[' => in_class = true,
+            '(' => groups += 1,
+            ')' => {
+                groups = groups
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow!("unmatched ')'"))?;
+            }
+            _ => {}
+        }
+    }
+    if in_class {
+        return Err(anyhow!("unterminated character class"));
+    }
+    if groups > 0 {
+        return Err(anyhow!("unclosed group"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|pattern| pattern.to_string()).collect()
+    }
+
+    #[test]
+    fn js_only_regex_features_are_accepted() {
+        let matches = patterns(&[
+            "/^https:\\/\\/(?!admin\\.)[^/]+\\.example\\.com\\//",
+            "/(?<=\\.)example\\.com/",
+            "/^https:\\/\\/(\\w+)\\.\\1\\.com/",
+            "/[(]literal[)]/",
+            "https://example.com/*",
+        ]);
+        assert_eq!(normalize_matches(matches.clone()).unwrap(), matches);
+    }
+
+    #[test]
+    fn unbalanced_regex_is_rejected() {
+        for pattern in ["/(example/", "/example)/", "/[abc/", "/example\\/"] {
+            assert!(
+                normalize_matches(patterns(&[pattern])).is_err(),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn blank_patterns_are_dropped() {
+        assert_eq!(
+            normalize_matches(patterns(&["  ", " https://example.com/* "])).unwrap(),
+            patterns(&["https://example.com/*"])
+        );
+        assert!(normalize_matches(patterns(&[" "])).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+use anyhow::{anyhow, Result};
+
+use super::models::{RunAt, ScriptKind};
+
+/// 从 userscript / userstyle 头部解析出的信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedScript {
+    pub name: String,
+    pub kind: ScriptKind,
+    pub matches: Vec<String>,
+    pub run_at: RunAt,
+}
+
+/// 解析常见的 userscript 头部
+///
+/// JS 使用 `// ==UserScript== ... // ==/UserScript==`，
+/// CSS 使用 `/* ==UserStyle== ... ==/UserStyle== */`。
+/// 读取 @name、@match、@include 和 @run-at，其他字段忽略。
+/// 站点脚本不支持排除规则，带 @exclude 的脚本会被拒绝，避免在本应排除的页面上运行
+pub fn parse(source: &str) -> Result<ParsedScript> {
+    let (kind, start, end) = if source.contains("==UserScript==") {
+        (ScriptKind::Js, "==UserScript==", "==/UserScript==")
+    } else if source.contains("==UserStyle==") {
+        (ScriptKind::Css, "==UserStyle==", "==/UserStyle==")
+    } else {
+        return Err(anyhow!("missing ==UserScript== or ==UserStyle== header"));
+    };
+
+    let mut name = None;
+    let mut matches = Vec::new();
+    let mut run_at = RunAt::default();
+    let mut in_header = false;
+    let mut closed = false;
+
+    for line in source.lines() {
+        let line = line
+            .trim()
+            .trim_start_matches("//")
+            .trim_start_matches("/*")
+            .trim_end_matches("*/")
+            .trim();
+        if line.starts_with(start) {
+            in_header = true;
+            continue;
+        }
+        if line.starts_with(end) {
+            closed = true;
+            break;
+        }
+        if !in_header {
+            continue;
+        }
+
+        let Some(entry) = line.strip_prefix('@') else {
+            continue;
+        };
+        let (key, value) = match entry.split_once(char::is_whitespace) {
+            Some((key, value)) => (key, value.trim()),
+            None => (entry, ""),
+        };
+        match key {
+            "name" if name.is_none() && !value.is_empty() => name = Some(value.to_string()),
+            "match" | "include" if !value.is_empty() => matches.push(value.to_string()),
+            "exclude" | "exclude-match" if !value.is_empty() => {
+                return Err(anyhow!(
+                    "@{} is not supported, narrow the @match patterns instead",
+                    key
+                ));
+            }
+            "run-at" => run_at = parse_run_at(value),
+            _ => {}
+        }
+    }
+
+    if !closed {
+        return Err(anyhow!("userscript header is not closed"));
+    }
+    if matches.is_empty() {
+        return Err(anyhow!("userscript header has no @match or @include"));
+    }
+
+    Ok(ParsedScript {
+        name: name.unwrap_or_else(|| "Untitled".to_string()),
+        kind,
+        matches,
+        run_at,
+    })
+}
+
+/// @run-at 的取值，document-body 按 document-end 处理
+fn parse_run_at(value: &str) -> RunAt {
+    match value {
+        "document-start" => RunAt::Start,
+        "document-idle" => RunAt::Idle,
+        _ => RunAt::End,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_userscript_header() {
+        let source = "\
+// ==UserScript==
+// @name        Quiet dashboard
+// @namespace   example
+// @match       https://dash.example.com/*
+// @run-at      document-start
+// ==/UserScript==
+document.body.classList.add('quiet');
+";
+        let parsed = parse(source).unwrap();
+        assert_eq!(parsed.name, "Quiet dashboard");
+        assert_eq!(parsed.kind, ScriptKind::Js);
+        assert_eq!(parsed.matches, vec!["https://dash.example.com/*"]);
+        assert_eq!(parsed.run_at, RunAt::Start);
+    }
+
+    #[test]
+    fn parses_userstyle_header() {
+        let source = "\
+/* ==UserStyle==
+@name   Hide banner
+@match  *://*.example.com/*
+==/UserStyle== */
+.banner { display: none; }
+";
+        let parsed = parse(source).unwrap();
+        assert_eq!(parsed.name, "Hide banner");
+        assert_eq!(parsed.kind, ScriptKind::Css);
+        assert_eq!(parsed.matches, vec!["*://*.example.com/*"]);
+        assert_eq!(parsed.run_at, RunAt::End);
+    }
+
+    #[test]
+    fn missing_header_is_rejected() {
+        assert!(parse("document.title = 'x';").is_err());
+        assert!(parse("// @match https://example.com/*\nalert(1);").is_err());
+    }
+
+    #[test]
+    fn unclosed_header_is_rejected() {
+        let source = "// ==UserScript==\n// @match https://example.com/*\n";
+        assert!(parse(source).is_err());
+    }
+
+    #[test]
+    fn header_without_match_is_rejected() {
+        let source = "// ==UserScript==\n// @name Empty\n// ==/UserScript==\n";
+        assert!(parse(source).is_err());
+    }
+
+    #[test]
+    fn repeated_match_keeps_every_pattern_in_order() {
+        let source = "\
+// ==UserScript==
+// @match https://a.example.com/*
+// @match https://b.example.com/*
+// @match
+// @match https://c.example.com/*
+// ==/UserScript==
+";
+        assert_eq!(
+            parse(source).unwrap().matches,
+            vec![
+                "https://a.example.com/*",
+                "https://b.example.com/*",
+                "https://c.example.com/*",
+            ]
+        );
+    }
+
+    #[test]
+    fn include_is_a_match() {
+        let source = "\
+// ==UserScript==
+// @include  /^https://app\\.example\\.com/
+// @match    https://docs.example.com/*
+// ==/UserScript==
+";
+        assert_eq!(
+            parse(source).unwrap().matches,
+            vec!["/^https://app\\.example\\.com/", "https://docs.example.com/*"]
+        );
+    }
+
+    #[test]
+    fn exclude_is_rejected() {
+        for key in ["exclude", "exclude-match"] {
+            let source = format!(
+                "// ==UserScript==\n// @match https://app.example.com/*\n// @{} https://app.example.com/admin/*\n// ==/UserScript==\n",
+                key
+            );
+            let error = parse(&source).unwrap_err().to_string();
+            assert!(error.contains(key), "{}", error);
+        }
+    }
+
+    #[test]
+    fn first_name_wins_and_defaults_to_untitled() {
+        let source = "\
+// ==UserScript==
+// @name First
+// @name Second
+// @match https://example.com/*
+// ==/UserScript==
+";
+        assert_eq!(parse(source).unwrap().name, "First");
+
+        let source = "// ==UserScript==\n// @match https://example.com/*\n// ==/UserScript==\n";
+        assert_eq!(parse(source).unwrap().name, "Untitled");
+    }
+
+    #[test]
+    fn entries_outside_header_are_ignored() {
+        let source = "\
+// @match https://before.example.com/*
+// ==UserScript==
+// @match https://example.com/*
+// ==/UserScript==
+// @match https://after.example.com/*
+";
+        assert_eq!(parse(source).unwrap().matches, vec!["https://example.com/*"]);
+    }
+
+    #[test]
+    fn run_at_values() {
+        assert_eq!(parse_run_at("document-start"), RunAt::Start);
+        assert_eq!(parse_run_at("document-end"), RunAt::End);
+        assert_eq!(parse_run_at("document-body"), RunAt::End);
+        assert_eq!(parse_run_at("document-idle"), RunAt::Idle);
+        assert_eq!(parse_run_at("unknown"), RunAt::End);
+    }
+}
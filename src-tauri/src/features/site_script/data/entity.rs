@@ -0,0 +1,32 @@
+use sea_orm::entity::prelude::*;
+
+/// 站点脚本：在匹配的页面中注入的用户 CSS 或 JS
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "site_scripts")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub name: String,
+    /// "css" 或 "js"
+    pub kind: String,
+    /// URL 匹配规则（JSON 字符串数组），任一匹配即注入
+    pub matches: String,
+    #[sea_orm(column_type = "Text")]
+    pub code: String,
+    pub enabled: bool,
+    /// "document_start" / "document_end" / "document_idle"
+    pub run_at: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unreachable!("site_scripts has no relations")
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::core::{AppState, Feature};
+use crate::infrastructure::database::{DatabaseRegistry, RegisteredMigration};
+use crate::infrastructure::injection::InjectionRegistry;
+
+use super::api::scripts;
+use super::core::injection;
+use super::data::migration;
+
+/// Site Script Feature - 站点脚本
+///
+/// 在匹配的页面中注入用户自定义的 CSS/JS
+pub struct SiteScriptFeature;
+
+impl SiteScriptFeature {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl Feature for SiteScriptFeature {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "site_script"
+    }
+
+    fn register_database(&self, registry: &mut DatabaseRegistry) {
+        registry.register_migrations(
+            self.name(),
+            vec![RegisteredMigration::new(migration::SiteScriptMigration, 1)],
+        );
+    }
+
+    fn register_scripts(&self, registry: &mut InjectionRegistry) {
+        registry.register_scripts(self.name(), scripts::script_modules());
+    }
+
+    fn command_names(&self) -> Vec<&'static str> {
+        vec![
+            "site_scripts_list",
+            "site_scripts_create",
+            "site_scripts_update",
+            "site_scripts_delete",
+            "site_scripts_import",
+        ]
+    }
+
+    async fn initialize(&self, app_state: &AppState) -> Result<()> {
+        injection::reload(app_state.db()).await?;
+        println!("[SiteScriptFeature] Initialized");
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+// Site Script Feature - 站点脚本（用户自定义 CSS/JS）
+//
+// 分层架构：
+// - api/: API 接口层（commands）
+// - core/: 核心业务层（service, models, userscript, injection）
+// - data/: 数据访问层（entity, migration）
+
+pub mod api;
+pub mod core;
+pub mod data;
+pub mod feature;
+
+pub use feature::SiteScriptFeature;
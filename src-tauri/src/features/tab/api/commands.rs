@@ -1,19 +1,35 @@
-use tauri::State;
+use std::path::Path;
+use std::time::Instant;
+
+use tauri::{State, WebviewWindow};
 
 use crate::core::AppState;
+use crate::features::tab::api::content_size;
+use crate::features::tab::api::tray::refresh_recently_closed;
+use crate::features::tab::core::closed_service::ClosedTabService;
+use crate::features::tab::core::favicon_service::FaviconService;
+use crate::features::tab::core::group_service::TabGroupService;
+use crate::features::tab::core::history_service::TabHistoryService;
+use crate::features::tab::core::importer::read_bookmarks;
 use crate::features::tab::core::models::{
-    CreateTabPayload, ReorderTabsPayload, Tab, UpdateTabPayload,
+    ClosedTab, CreateTabPayload, FaviconCandidate, ImportTabsPayload, ImportTabsResult,
+    ReorderTabsPayload, SplitOrientation, Tab, TabGroup, TabHistoryEntry, UpdateTabPayload,
 };
 use crate::features::tab::core::service::TabService;
+use crate::features::tab::core::split_service::TabSplitService;
+use crate::features::tab::core::suspension::SuspensionPolicy;
+use crate::features::tab::data::entity;
+use crate::features::window::geometry;
 use crate::features::window::manager as window_manager;
+use crate::features::window::split::{self, SplitPane, SplitView};
+use crate::infrastructure::ipc::{emit_to_app, IpcGuard};
 use serde_json::json;
-use tauri::Emitter;
 
 #[tauri::command]
 pub async fn tabs_list(app_state: State<'_, AppState>) -> Result<Vec<Tab>, String> {
     TabService::list(app_state.db())
         .await
-        .map(|tabs| tabs.into_iter().map(Tab::from).collect())
+        .map(|tabs| tabs.into_iter().map(tab_view).collect())
         .map_err(|e| e.to_string())
 }
 
@@ -22,13 +38,13 @@ pub async fn tabs_create(
     app_state: State<'_, AppState>,
     payload: CreateTabPayload,
 ) -> Result<Tab, String> {
-    let tab = TabService::create(app_state.db(), payload)
+    let mut tab = TabService::create(app_state.db(), payload)
         .await
-        .map(Tab::from)
+        .map(tab_view)
         .map_err(|e| e.to_string())?;
 
-    // 打开或导航内容窗口
-    let _ = window_manager::present_content_window(&app_state.app_handle(), Some(&tab.url), false);
+    // 为新标签创建内容窗口
+    present_tab(&app_state, &mut tab, false).await;
     let _ = emit_tab_event(&app_state, "created", json!({ "tab": tab.clone() }));
 
     Ok(tab)
@@ -41,7 +57,7 @@ pub async fn tabs_update(
 ) -> Result<Option<Tab>, String> {
     let updated = TabService::update(app_state.db(), payload)
         .await
-        .map(|res| res.map(Tab::from))
+        .map(|res| res.map(tab_view))
         .map_err(|e| e.to_string())?;
 
     if let Some(ref tab) = updated {
@@ -53,38 +69,15 @@ pub async fn tabs_update(
 
 #[tauri::command]
 pub async fn tabs_activate(app_state: State<'_, AppState>, id: String) -> Result<Option<Tab>, String> {
-    // 获取当前缓存的 URL，用于判断是否需要导航
-    let current_cached_url = window_manager::get_current_url();
-
-    let tab = TabService::activate(app_state.db(), &id)
+    let mut tab = TabService::activate(app_state.db(), &id)
         .await
-        .map(|res| res.map(Tab::from))
+        .map(|res| res.map(tab_view))
         .map_err(|e| e.to_string())?;
 
-    if let Some(ref tab) = tab {
-        // 如果 URL 相同，传入 None 以避免刷新；否则传入 Some(url) 进行导航
-        let should_navigate = match current_cached_url {
-            Some(ref cached) => cached != &tab.url,
-            None => true,
-        };
-        let url_arg = if should_navigate { Some(tab.url.as_str()) } else { None };
-
-        let _ = window_manager::present_content_window(&app_state.app_handle(), url_arg, false);
+    if let Some(ref mut tab) = tab {
+        // 每个 tab 有独立的 webview，切换时只显示对应窗口，不重新导航
+        present_tab(&app_state, tab, false).await;
         let _ = emit_tab_event(&app_state, "activated", json!({ "tab": tab }));
-    } else {
-        // 如果 activate 返回 None，可能是因为已经是 active 状态
-        // 这种情况下，我们仍然需要确保窗口显示（解决再次点击无法打开的问题）
-        if let Ok(Some(current_tab)) = TabService::get(app_state.db(), &id).await {
-             let tab = Tab::from(current_tab);
-             
-             let should_navigate = match current_cached_url {
-                Some(ref cached) => cached != &tab.url,
-                None => true,
-            };
-            let url_arg = if should_navigate { Some(tab.url.as_str()) } else { None };
-
-             let _ = window_manager::present_content_window(&app_state.app_handle(), url_arg, false);
-        }
     }
 
     Ok(tab)
@@ -92,25 +85,98 @@ pub async fn tabs_activate(app_state: State<'_, AppState>, id: String) -> Result
 
 #[tauri::command]
 pub async fn tabs_close(app_state: State<'_, AppState>, id: String) -> Result<Option<Tab>, String> {
-    let activated = TabService::close(app_state.db(), &id)
+    let (activated, expired) = TabService::close(app_state.db(), &id)
         .await
-        .map(|res| res.map(Tab::from))
         .map_err(|e| e.to_string())?;
+    let mut activated = activated.map(tab_view);
+    forget_detached_geometry(&app_state, &expired).await;
 
+    on_tab_closed(&app_state, &id, activated.as_mut()).await;
     let _ = emit_tab_event(&app_state, "closed", json!({ "id": id }));
+    refresh_recently_closed(&app_state).await;
 
     if let Some(ref tab) = activated {
-        let _ = window_manager::present_content_window(&app_state.app_handle(), Some(&tab.url), false);
         let _ = emit_tab_event(&app_state, "activated", json!({ "tab": tab }));
-    } else {
-        // 如果没有激活的标签页，隐藏内容窗口
-        window_manager::hide_content_window(&app_state.app_handle());
-        window_manager::clear_current_url();
     }
 
     Ok(activated)
 }
 
+/// 重新打开最近关闭的标签页，未指定 id 时为最近关闭的一个
+#[tauri::command]
+pub async fn tabs_reopen_closed(
+    app_state: State<'_, AppState>,
+    id: Option<String>,
+) -> Result<Option<Tab>, String> {
+    reopen_closed(&app_state, id).await
+}
+
+#[tauri::command]
+pub async fn tabs_list_closed(
+    app_state: State<'_, AppState>,
+    limit: Option<u64>,
+) -> Result<Vec<ClosedTab>, String> {
+    ClosedTabService::list(app_state.db(), limit.unwrap_or(20))
+        .await
+        .map(|closed| closed.into_iter().map(ClosedTab::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn tabs_get_suspension_policy(
+    app_state: State<'_, AppState>,
+) -> Result<SuspensionPolicy, String> {
+    SuspensionPolicy::load(app_state.db())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 更新后台标签挂起策略，并立即按新策略回收
+#[tauri::command]
+pub async fn tabs_set_suspension_policy(
+    app_state: State<'_, AppState>,
+    policy: SuspensionPolicy,
+) -> Result<SuspensionPolicy, String> {
+    let saved = policy
+        .save(app_state.db())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    enforce_suspension(&app_state).await;
+
+    Ok(saved)
+}
+
+/// 从本地书签文件（Netscape HTML / Chrome JSON / Firefox places.sqlite）导入标签
+#[tauri::command]
+pub async fn tabs_import(
+    app_state: State<'_, AppState>,
+    payload: ImportTabsPayload,
+) -> Result<ImportTabsResult, String> {
+    let bookmarks = read_bookmarks(Path::new(&payload.path), payload.format)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let result = TabService::import(
+        app_state.db(),
+        bookmarks,
+        payload.folders_as_groups.unwrap_or(true),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    if result.groups_created > 0 {
+        let _ = emit_to_app(
+            &app_state.app_handle(),
+            "tab-groups-changed",
+            json!({ "action": "imported", "data": { "count": result.groups_created } }),
+        );
+    }
+    let _ = emit_tab_event(&app_state, "imported", json!({ "result": result }));
+
+    Ok(result)
+}
+
 #[tauri::command]
 pub async fn tabs_reorder(
     app_state: State<'_, AppState>,
@@ -130,13 +196,13 @@ pub async fn tabs_reorder(
 
 #[tauri::command]
 pub async fn tabs_activate_next(app_state: State<'_, AppState>) -> Result<Option<Tab>, String> {
-    let tab = TabService::activate_next(app_state.db())
+    let mut tab = TabService::activate_next(app_state.db())
         .await
-        .map(|res| res.map(Tab::from))
+        .map(|res| res.map(tab_view))
         .map_err(|e| e.to_string())?;
 
-    if let Some(ref tab) = tab {
-        let _ = window_manager::present_content_window(&app_state.app_handle(), Some(&tab.url), false);
+    if let Some(ref mut tab) = tab {
+        present_tab(&app_state, tab, false).await;
         let _ = emit_tab_event(&app_state, "activated", json!({ "tab": tab }));
     }
 
@@ -145,13 +211,13 @@ pub async fn tabs_activate_next(app_state: State<'_, AppState>) -> Result<Option
 
 #[tauri::command]
 pub async fn tabs_activate_previous(app_state: State<'_, AppState>) -> Result<Option<Tab>, String> {
-    let tab = TabService::activate_previous(app_state.db())
+    let mut tab = TabService::activate_previous(app_state.db())
         .await
-        .map(|res| res.map(Tab::from))
+        .map(|res| res.map(tab_view))
         .map_err(|e| e.to_string())?;
 
-    if let Some(ref tab) = tab {
-        let _ = window_manager::present_content_window(&app_state.app_handle(), Some(&tab.url), false);
+    if let Some(ref mut tab) = tab {
+        present_tab(&app_state, tab, false).await;
         let _ = emit_tab_event(&app_state, "activated", json!({ "tab": tab }));
     }
 
@@ -160,20 +226,25 @@ pub async fn tabs_activate_previous(app_state: State<'_, AppState>) -> Result<Op
 
 #[tauri::command]
 pub async fn tabs_close_active(app_state: State<'_, AppState>) -> Result<Option<Tab>, String> {
-    let activated = TabService::close_active(app_state.db())
+    let Some(active) = TabService::get_active(app_state.db())
+        .await
+        .map_err(|e| e.to_string())?
+    else {
+        return Ok(None);
+    };
+
+    let (activated, expired) = TabService::close(app_state.db(), &active.id)
         .await
-        .map(|res| res.map(Tab::from))
         .map_err(|e| e.to_string())?;
+    let mut activated = activated.map(tab_view);
+    forget_detached_geometry(&app_state, &expired).await;
 
-    let _ = emit_tab_event(&app_state, "closed", json!({ "active": true }));
+    on_tab_closed(&app_state, &active.id, activated.as_mut()).await;
+    let _ = emit_tab_event(&app_state, "closed", json!({ "id": active.id, "active": true }));
+    refresh_recently_closed(&app_state).await;
 
     if let Some(ref tab) = activated {
-        let _ = window_manager::present_content_window(&app_state.app_handle(), Some(&tab.url), false);
         let _ = emit_tab_event(&app_state, "activated", json!({ "tab": tab }));
-    } else {
-        // 如果没有激活的标签页，隐藏内容窗口
-        window_manager::hide_content_window(&app_state.app_handle());
-        window_manager::clear_current_url();
     }
 
     Ok(activated)
@@ -182,70 +253,213 @@ pub async fn tabs_close_active(app_state: State<'_, AppState>) -> Result<Option<
 #[tauri::command]
 pub async fn tabs_reload(app_state: State<'_, AppState>, id: String) -> Result<(), String> {
     if let Ok(Some(current_tab)) = TabService::get(app_state.db(), &id).await {
-        let tab = Tab::from(current_tab);
-        // 强制导航到当前 URL，忽略缓存检查
-        let _ = window_manager::present_content_window(&app_state.app_handle(), Some(&tab.url), false);
+        let tab = tab_view(current_tab);
+        // 强制导航到当前 URL
+        let _ = window_manager::present_content_window(&app_state.app_handle(), &tab.id, &tab.url, true, false);
     }
     Ok(())
 }
 
+/// 清除 tab 记录的内容窗口尺寸，恢复为默认尺寸
+#[tauri::command]
+pub async fn tabs_reset_content_size(
+    app_state: State<'_, AppState>,
+    id: String,
+) -> Result<Option<Tab>, String> {
+    content_size::discard_pending(&id);
+    let updated = TabService::update_content_size(app_state.db(), &id, None)
+        .await
+        .map(|res| res.map(tab_view))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(ref tab) = updated {
+        geometry::remember_tab_size(&tab.id, None);
+        window_manager::resize_content_window(&app_state.app_handle(), &tab.id);
+        let _ = emit_tab_event(&app_state, "updated", json!({ "tab": tab }));
+    }
+
+    Ok(updated)
+}
+
+/// 将标签分离到独立窗口（按上次记录的位置和尺寸打开）
+#[tauri::command]
+pub async fn tabs_detach(app_state: State<'_, AppState>, id: String) -> Result<Option<Tab>, String> {
+    let Some(model) = TabService::get(app_state.db(), &id)
+        .await
+        .map_err(|e| e.to_string())?
+    else {
+        return Ok(None);
+    };
+
+    let saved = geometry::load_detached_geometry(&app_state, &model.id)
+        .await
+        .map_err(|e| e.to_string())?;
+    window_manager::detach_content_window(
+        &app_state.app_handle(),
+        &model.id,
+        &model.url,
+        &model.title,
+        saved,
+    )?;
+
+    let tab = tab_view(model);
+    let _ = emit_tab_event(&app_state, "detached", json!({ "tab": tab }));
+    Ok(Some(tab))
+}
+
+/// 关闭标签的独立窗口，放回内容栏并激活
+#[tauri::command]
+pub async fn tabs_attach(app_state: State<'_, AppState>, id: String) -> Result<Option<Tab>, String> {
+    window_manager::attach_content_window(&app_state.app_handle(), &id);
+    let _ = emit_tab_event(&app_state, "attached", json!({ "id": id }));
+    tabs_activate(app_state, id).await
+}
+
+#[tauri::command]
+pub async fn tabs_go_back(app_state: State<'_, AppState>) -> Result<Option<Tab>, String> {
+    navigate_history(&app_state, false).await
+}
+
+#[tauri::command]
+pub async fn tabs_go_forward(app_state: State<'_, AppState>) -> Result<Option<Tab>, String> {
+    navigate_history(&app_state, true).await
+}
+
+/// 获取标签页的导航历史，未指定 id 时返回当前激活标签的历史
 #[tauri::command]
-pub async fn tabs_report_navigation(app_state: State<'_, AppState>, url: String) -> Result<(), String> {
+pub async fn tabs_history(
+    app_state: State<'_, AppState>,
+    id: Option<String>,
+) -> Result<Vec<TabHistoryEntry>, String> {
+    let tab_id = match id {
+        Some(id) => id,
+        None => match TabService::get_active(app_state.db())
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            Some(tab) => tab.id,
+            None => return Ok(vec![]),
+        },
+    };
+
+    TabHistoryService::list(app_state.db(), &tab_id)
+        .await
+        .map(|entries| entries.into_iter().map(TabHistoryEntry::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+/// 内容窗口上报导航（记录到发起上报的 webview 所属的 Tab）
+#[tauri::command]
+pub async fn tabs_report_navigation(
+    app_state: State<'_, AppState>,
+    webview_window: WebviewWindow,
+    url: String,
+) -> Result<(), String> {
     println!("[NoB] tabs_report_navigation called with: {}", url);
-    // 更新数据库中当前激活 Tab 的 URL
-    let updated = TabService::update_active_url(app_state.db(), url.clone())
+    let Some(tab_id) = reporting_tab_id(&app_state, webview_window.label()).await else {
+        return Ok(());
+    };
+
+    let updated = TabService::update_url(app_state.db(), &tab_id, url.clone())
         .await
-        .map(|res| res.map(Tab::from))
+        .map(|res| res.map(tab_view))
         .map_err(|e| e.to_string())?;
 
     if let Some(tab) = updated {
-        // 更新缓存
-        window_manager::set_current_url(url);
+        // 更新激活 tab 记录的 URL（webview 被回收后按此 URL 重建）
+        split::update_pane_url(&tab.id, &url);
+        window_manager::update_active_content_url(&tab.id, url);
         // 通知前端更新 UI
         let _ = emit_tab_event(&app_state, "updated", json!({ "tab": tab }));
     }
     Ok(())
 }
 
+/// 内容窗口上报标题（记录到发起上报的 webview 所属的 Tab）
 #[tauri::command]
-pub async fn tabs_report_title(app_state: State<'_, AppState>, title: String) -> Result<(), String> {
-    // 更新数据库中当前激活 Tab 的 Title
-    let updated = TabService::update_active_title(app_state.db(), title.clone())
+pub async fn tabs_report_title(
+    app_state: State<'_, AppState>,
+    webview_window: WebviewWindow,
+    title: String,
+) -> Result<(), String> {
+    let Some(tab_id) = reporting_tab_id(&app_state, webview_window.label()).await else {
+        return Ok(());
+    };
+
+    let updated = TabService::update_title(app_state.db(), &tab_id, title)
         .await
-        .map(|res| res.map(Tab::from))
+        .map(|res| res.map(tab_view))
         .map_err(|e| e.to_string())?;
 
     if let Some(tab) = updated {
+        if tab.detached {
+            window_manager::set_detached_title(&app_state.app_handle(), &tab.id, &tab.title);
+        }
         // 通知前端更新 UI
         let _ = emit_tab_event(&app_state, "updated", json!({ "tab": tab }));
     }
     Ok(())
 }
 
+/// 内容窗口上报候选图标：选出最合适的一个，下载缓存后记录到 Tab
+#[tauri::command]
+pub async fn tabs_report_favicon(
+    app_state: State<'_, AppState>,
+    webview_window: WebviewWindow,
+    icons: Vec<FaviconCandidate>,
+) -> Result<(), String> {
+    let Some(tab_id) = reporting_tab_id(&app_state, webview_window.label()).await else {
+        return Ok(());
+    };
+    let Some(icon) = FaviconService::pick(&icons) else {
+        return Ok(());
+    };
+
+    FaviconService::fetch(app_state.db(), &icon.url)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let updated = TabService::update_favicon(app_state.db(), &tab_id, &icon.url)
+        .await
+        .map(|res| res.map(tab_view))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(tab) = updated {
+        let _ = emit_tab_event(&app_state, "updated", json!({ "tab": tab }));
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn tabs_get_current_url(app_state: State<'_, AppState>) -> Result<String, String> {
-    use tauri::Manager;
     println!("[NoB] tabs_get_current_url called");
-    // 优先尝试从 content window 直接获取
-    if let Some(window) = app_state.app_handle().get_webview_window("content") {
+    // 优先尝试从激活 tab 的内容窗口直接获取
+    if let Some(window) = window_manager::active_content_window(&app_state.app_handle()) {
         if let Ok(url) = window.url() {
             println!("[NoB] Got URL from window.url(): {}", url);
             return Ok(url.to_string());
         }
     }
-    // 降级使用缓存
-    let cached = window_manager::get_current_url();
-    println!("[NoB] Got URL from cache: {:?}", cached);
-    cached.ok_or_else(|| "No active URL".to_string())
+    // 降级使用记录的 URL
+    let recorded = window_manager::active_content_url();
+    println!("[NoB] Got URL from active content: {:?}", recorded);
+    recorded.ok_or_else(|| "No active URL".to_string())
 }
 
 #[tauri::command]
-pub async fn tabs_request_url(app_state: State<'_, AppState>) -> Result<(), String> {
-    use tauri::Manager;
+pub async fn tabs_request_url(
+    app_state: State<'_, AppState>,
+    guard: State<'_, IpcGuard>,
+) -> Result<(), String> {
     println!("[NoB] tabs_request_url called");
-    if let Some(window) = app_state.app_handle().get_webview_window("content") {
-        println!("[NoB] Emitting 'get-url' to content window");
-        window.emit("get-url", ()).map_err(|e| e.to_string())?;
+    if let Some(window) = window_manager::active_content_window(&app_state.app_handle()) {
+        println!("[NoB] Requesting URL from {}", window.label());
+        // 只接受这个窗口对本次请求的回复
+        guard.expect_reply(window.label(), "tabs_respond_url", Instant::now());
+        // 远程页面不能监听事件，直接调用注入脚本中的回复函数
+        window
+            .eval("window.__NOB__?.respondUrl?.()")
+            .map_err(|e| e.to_string())?;
         Ok(())
     } else {
         println!("[NoB] Content window not found");
@@ -255,20 +469,222 @@ pub async fn tabs_request_url(app_state: State<'_, AppState>) -> Result<(), Stri
 
 #[tauri::command]
 pub async fn tabs_respond_url(app_state: State<'_, AppState>, url: String) -> Result<(), String> {
-    use tauri::Manager;
     println!("[NoB] tabs_respond_url called with: {}", url);
-    if let Some(window) = app_state.app_handle().get_webview_window("main") {
-        println!("[NoB] Emitting 'return-url' to main window");
-        window.emit("return-url", url).map_err(|e| e.to_string())?;
-        Ok(())
-    } else {
-        println!("[NoB] Main window not found");
-        Err("Main window not found".to_string())
+    println!("[NoB] Emitting 'return-url' to main window");
+    emit_to_app(&app_state.app_handle(), "return-url", url).map_err(|e| e.to_string())
+}
+
+/// 内容页面按链接规则在新标签中打开链接（只接受 http/https）
+#[tauri::command]
+pub async fn tabs_open_link(app_state: State<'_, AppState>, url: String) -> Result<Tab, String> {
+    let parsed = url::Url::parse(&url).map_err(|e| format!("Invalid URL: {}", e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!("unsupported link: {}", url));
     }
+    tabs_create(
+        app_state,
+        CreateTabPayload {
+            url: parsed.to_string(),
+            title: None,
+            favicon_url: None,
+            is_pinned: None,
+            activate: Some(true),
+        },
+    )
+    .await
 }
 
-fn emit_tab_event(app_state: &AppState, action: &str, payload: serde_json::Value) -> tauri::Result<()> {
+/// 内容页面用系统默认浏览器打开链接（只接受 http/https/mailto/tel）
+#[tauri::command]
+pub async fn tabs_open_external(app_state: State<'_, AppState>, url: String) -> Result<(), String> {
+    use tauri_plugin_opener::OpenerExt;
+    let parsed = url::Url::parse(&url).map_err(|e| format!("Invalid URL: {}", e))?;
+    if !matches!(parsed.scheme(), "http" | "https" | "mailto" | "tel") {
+        return Err(format!("unsupported link: {}", url));
+    }
     app_state
         .app_handle()
-        .emit("tabs-changed", json!({ "action": action, "data": payload }))
+        .opener()
+        .open_url(parsed.as_str(), None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+/// 重新打开关闭的标签页并在内容窗口中展示（供 command 与托盘菜单共用）
+pub(crate) async fn reopen_closed(
+    app_state: &AppState,
+    id: Option<String>,
+) -> Result<Option<Tab>, String> {
+    let previous_group = TabGroupService::current_id(app_state.db())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut tab = TabService::reopen_closed(app_state.db(), id.as_deref())
+        .await
+        .map(|res| res.map(tab_view))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(ref mut tab) = tab {
+        // 标签被放回了其它分组时，通知前端分组已切换
+        if tab.group_id != previous_group {
+            if let Ok(Some(group)) = TabGroupService::get_active(app_state.db()).await {
+                let _ = emit_to_app(
+                    &app_state.app_handle(),
+                    "tab-groups-changed",
+                    json!({ "action": "activated", "data": { "group": TabGroup::from(group) } }),
+                );
+            }
+        }
+
+        present_tab(app_state, tab, false).await;
+        let _ = emit_tab_event(app_state, "reopened", json!({ "tab": tab }));
+        let _ = emit_tab_event(app_state, "activated", json!({ "tab": tab }));
+    }
+
+    refresh_recently_closed(app_state).await;
+
+    Ok(tab)
+}
+
+async fn navigate_history(app_state: &AppState, forward: bool) -> Result<Option<Tab>, String> {
+    let mut tab = TabService::navigate_history(app_state.db(), forward)
+        .await
+        .map(|res| res.map(tab_view))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(ref mut tab) = tab {
+        present_tab(app_state, tab, true).await;
+        let _ = emit_tab_event(app_state, "updated", json!({ "tab": tab }));
+    }
+
+    Ok(tab)
+}
+
+/// 销毁被关闭标签的 webview，并展示新激活的标签
+async fn on_tab_closed(app_state: &AppState, closed_id: &str, activated: Option<&mut Tab>) {
+    let app = app_state.app_handle();
+    let was_active = window_manager::active_content_tab_id().as_deref() == Some(closed_id);
+    let was_split_pane = split::is_split_pane(closed_id);
+    if was_split_pane {
+        split::set_active_split(None);
+    }
+
+    window_manager::close_content_webview(&app, closed_id);
+
+    if let Some(tab) = activated {
+        present_tab(app_state, tab, false).await;
+    } else if was_split_pane && !was_active {
+        // 关闭的是分屏的另一个窗格，激活的标签恢复单独显示
+        let _ = window_manager::present_active_content_window(&app, false);
+    } else if was_active {
+        // 如果没有激活的标签页，隐藏内容窗口
+        window_manager::hide_content_window(&app);
+        window_manager::clear_active_content();
+    }
+}
+
+/// 删除已彻底移除的标签（删除分组或淘汰出最近关闭列表）记录的分离窗口位置
+pub(crate) async fn forget_detached_geometry(app_state: &AppState, tab_ids: &[String]) {
+    if let Err(e) = geometry::forget_detached_geometry(app_state, tab_ids).await {
+        println!("[NoB] Failed to delete detached geometry of removed tabs: {}", e);
+    }
+}
+
+/// 在内容窗口中展示标签，随后按挂起策略回收后台 webview
+pub(crate) async fn present_tab(app_state: &AppState, tab: &mut Tab, navigate: bool) {
+    geometry::remember_tab_size(&tab.id, tab.content_size());
+    split::set_active_split(split_view_for(app_state, tab).await);
+    let _ = window_manager::present_content_window(&app_state.app_handle(), &tab.id, &tab.url, navigate, false);
+    tab.detached = window_manager::is_detached(&tab.id);
+    tab.suspended = !window_manager::is_content_live(&tab.id) && !tab.detached;
+    enforce_suspension(app_state).await;
+}
+
+/// 标签所属的分屏组合，两个标签都在内容栏中（未分离）时才分屏显示
+async fn split_view_for(app_state: &AppState, tab: &Tab) -> Option<SplitView> {
+    let split = TabSplitService::find_for_tab(app_state.db(), &tab.id)
+        .await
+        .ok()
+        .flatten()?;
+    let partner_id = if split.primary_tab_id == tab.id {
+        &split.secondary_tab_id
+    } else {
+        &split.primary_tab_id
+    };
+    if tab.detached || window_manager::is_detached(partner_id) {
+        return None;
+    }
+    let partner = TabService::get(app_state.db(), partner_id)
+        .await
+        .ok()
+        .flatten()?;
+
+    let pane = |tab_id: &str, url: &str| SplitPane {
+        tab_id: tab_id.to_string(),
+        url: url.to_string(),
+    };
+    let (primary, secondary) = if split.primary_tab_id == tab.id {
+        (pane(&tab.id, &tab.url), pane(&partner.id, &partner.url))
+    } else {
+        (pane(&partner.id, &partner.url), pane(&tab.id, &tab.url))
+    };
+
+    Some(SplitView {
+        id: split.id,
+        primary,
+        secondary,
+        orientation: SplitOrientation::parse(&split.orientation),
+        ratio: split.ratio,
+    })
+}
+
+/// 挂起超出策略的后台标签（销毁其 webview），并通知前端
+pub(crate) async fn enforce_suspension(app_state: &AppState) {
+    let Ok(policy) = SuspensionPolicy::load(app_state.db()).await else {
+        return;
+    };
+
+    let live = window_manager::live_content_webviews();
+    let active = window_manager::active_content_tab_id();
+    let mut ids = policy.select(&live, active.as_deref(), Instant::now());
+    // 分屏中与激活标签并排显示的窗格同样可见，不挂起
+    ids.retain(|id| !split::is_split_pane(id));
+    if ids.is_empty() {
+        return;
+    }
+
+    let app = app_state.app_handle();
+    for id in &ids {
+        window_manager::close_content_webview(&app, id);
+    }
+    let _ = emit_tab_event(app_state, "suspended", json!({ "ids": ids }));
+}
+
+/// 解析上报来源对应的 Tab：内容窗口和分离窗口按 label 对应，其它来源回退到当前激活的 Tab
+async fn reporting_tab_id(app_state: &AppState, label: &str) -> Option<String> {
+    if let Some(tab_id) = window_manager::tab_id_from_label(label)
+        .or_else(|| window_manager::tab_id_from_detached_label(label))
+    {
+        return Some(tab_id.to_string());
+    }
+    TabService::get_active(app_state.db())
+        .await
+        .ok()
+        .flatten()
+        .map(|tab| tab.id)
+}
+
+/// 转换为 API 响应，并按内容窗口的运行状态填写分离和挂起标记
+pub(crate) fn tab_view(model: entity::Model) -> Tab {
+    let mut tab = Tab::from(model);
+    tab.detached = window_manager::is_detached(&tab.id);
+    tab.suspended = !window_manager::is_content_live(&tab.id) && !tab.detached;
+    tab
+}
+
+pub(crate) fn emit_tab_event(app_state: &AppState, action: &str, payload: serde_json::Value) -> tauri::Result<()> {
+    emit_to_app(
+        &app_state.app_handle(),
+        "tabs-changed",
+        json!({ "action": action, "data": payload }),
+    )
 }
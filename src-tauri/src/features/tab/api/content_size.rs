@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use tauri::{AppHandle, LogicalSize, Manager, Wry};
+
+use crate::core::AppState;
+use crate::features::tab::core::service::TabService;
+use crate::infrastructure::debounce::DebouncedWrites;
+
+/// 调整停止后多久写入数据库
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// 调整后尚未写入数据库的标签尺寸
+static PENDING: DebouncedWrites<String, LogicalSize<f64>> = DebouncedWrites::new(SAVE_DELAY);
+
+/// 记录用户调整后的标签内容窗口尺寸，防抖写入数据库（注册为窗口尺寸变化的回调）
+pub(crate) fn remember_content_size(app: &AppHandle<Wry>, tab_id: &str, size: LogicalSize<f64>) {
+    PENDING.set(tab_id.to_string(), size);
+
+    let app_handle = app.clone();
+    PENDING.schedule(async move { save(&app_handle).await });
+}
+
+/// 丢弃标签尚未写入的尺寸（恢复默认尺寸时调用）
+pub(crate) fn discard_pending(tab_id: &str) {
+    PENDING.discard(tab_id);
+}
+
+/// 写入待保存的尺寸
+async fn save(app: &AppHandle<Wry>) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let db = state.db();
+
+    let failed = PENDING
+        .flush(|tab_id, size| async move {
+            TabService::update_content_size(db, &tab_id, Some((size.width, size.height)))
+                .await
+                .map(|_| ())
+        })
+        .await;
+    for (tab_id, e) in failed {
+        println!("[NoB] Failed to save content size of tab {}: {}", tab_id, e);
+    }
+}
@@ -0,0 +1,150 @@
+use serde_json::json;
+use tauri::State;
+
+use crate::core::AppState;
+use crate::features::tab::api::commands::{
+    emit_tab_event, forget_detached_geometry, present_tab, tab_view,
+};
+use crate::features::tab::core::group_service::TabGroupService;
+use crate::features::tab::core::models::{
+    CreateTabGroupPayload, ReorderTabGroupsPayload, TabGroup, UpdateTabGroupPayload,
+};
+use crate::features::tab::core::service::TabService;
+use crate::features::window::manager as window_manager;
+use crate::infrastructure::ipc::emit_to_app;
+
+#[tauri::command]
+pub async fn tab_groups_list(app_state: State<'_, AppState>) -> Result<Vec<TabGroup>, String> {
+    TabGroupService::list(app_state.db())
+        .await
+        .map(|groups| groups.into_iter().map(TabGroup::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn tab_groups_create(
+    app_state: State<'_, AppState>,
+    payload: CreateTabGroupPayload,
+) -> Result<TabGroup, String> {
+    let group = TabGroupService::create(app_state.db(), payload)
+        .await
+        .map(TabGroup::from)
+        .map_err(|e| e.to_string())?;
+
+    let _ = emit_group_event(&app_state, "created", json!({ "group": group.clone() }));
+
+    if group.is_active {
+        present_group_active_tab(&app_state).await?;
+    }
+
+    Ok(group)
+}
+
+#[tauri::command]
+pub async fn tab_groups_update(
+    app_state: State<'_, AppState>,
+    payload: UpdateTabGroupPayload,
+) -> Result<Option<TabGroup>, String> {
+    let updated = TabGroupService::update(app_state.db(), payload)
+        .await
+        .map(|res| res.map(TabGroup::from))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(ref group) = updated {
+        let _ = emit_group_event(&app_state, "updated", json!({ "group": group }));
+    }
+
+    Ok(updated)
+}
+
+/// 切换分组，并在内容窗口中恢复该分组上次激活的标签
+#[tauri::command]
+pub async fn tab_groups_activate(
+    app_state: State<'_, AppState>,
+    id: String,
+) -> Result<Option<TabGroup>, String> {
+    let group = TabGroupService::activate(app_state.db(), &id)
+        .await
+        .map(|res| res.map(TabGroup::from))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(ref group) = group {
+        let _ = emit_group_event(&app_state, "activated", json!({ "group": group }));
+        present_group_active_tab(&app_state).await?;
+    }
+
+    Ok(group)
+}
+
+#[tauri::command]
+pub async fn tab_groups_delete(
+    app_state: State<'_, AppState>,
+    id: String,
+) -> Result<Option<TabGroup>, String> {
+    let (activated, removed_tab_ids) = TabGroupService::delete(app_state.db(), &id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let activated = activated.map(TabGroup::from);
+
+    for tab_id in &removed_tab_ids {
+        window_manager::close_content_webview(&app_state.app_handle(), tab_id);
+    }
+    forget_detached_geometry(&app_state, &removed_tab_ids).await;
+
+    let _ = emit_group_event(&app_state, "deleted", json!({ "id": id }));
+
+    if let Some(ref group) = activated {
+        let _ = emit_group_event(&app_state, "activated", json!({ "group": group }));
+        present_group_active_tab(&app_state).await?;
+    }
+
+    Ok(activated)
+}
+
+#[tauri::command]
+pub async fn tab_groups_reorder(
+    app_state: State<'_, AppState>,
+    payload: ReorderTabGroupsPayload,
+) -> Result<(), String> {
+    TabGroupService::reorder(app_state.db(), payload)
+        .await
+        .map_err(|e| e.to_string())
+        .map(|_| {
+            let _ = emit_group_event(
+                &app_state,
+                "reordered",
+                json!({ "at": chrono::Utc::now().to_rfc3339() }),
+            );
+        })
+}
+
+/// 在内容窗口中展示当前分组的激活标签，分组为空时隐藏内容窗口
+pub(crate) async fn present_group_active_tab(app_state: &AppState) -> Result<(), String> {
+    let mut active = TabService::restore_active(app_state.db())
+        .await
+        .map(|res| res.map(tab_view))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(ref mut tab) = active {
+        present_tab(app_state, tab, false).await;
+        let _ = emit_tab_event(app_state, "activated", json!({ "tab": tab }));
+    } else {
+        window_manager::hide_content_window(&app_state.app_handle());
+        window_manager::clear_active_content();
+        let _ = emit_tab_event(app_state, "group_switched", json!({ "tab": null }));
+    }
+
+    Ok(())
+}
+
+fn emit_group_event(
+    app_state: &AppState,
+    action: &str,
+    payload: serde_json::Value,
+) -> tauri::Result<()> {
+    emit_to_app(
+        &app_state.app_handle(),
+        "tab-groups-changed",
+        json!({ "action": action, "data": payload }),
+    )
+}
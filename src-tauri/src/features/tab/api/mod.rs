@@ -1 +1,7 @@
 pub mod commands;
+pub mod content_size;
+pub mod group_commands;
+pub mod protocol;
+pub mod scripts;
+pub mod split_commands;
+pub mod tray;
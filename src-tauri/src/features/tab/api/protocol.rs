@@ -0,0 +1,52 @@
+use tauri::http::{header, Request, Response, StatusCode};
+use tauri::{Manager, UriSchemeContext, UriSchemeResponder, Wry};
+
+use crate::core::AppState;
+use crate::features::tab::core::favicon_service::FaviconService;
+
+/// 图标协议名，前端通过 `convertFileSrc(faviconUrl, "favicon")` 访问
+pub const FAVICON_SCHEME: &str = "favicon";
+
+/// 处理 `favicon://localhost/<编码后的图标 URL>` 请求
+///
+/// 只返回数据库中已缓存的图标（由内容页上报时下载），未缓存时返回 404
+pub fn handle_favicon_request(
+    ctx: UriSchemeContext<'_, Wry>,
+    request: Request<Vec<u8>>,
+    responder: UriSchemeResponder,
+) {
+    let app = ctx.app_handle().clone();
+    let path = request.uri().path().trim_start_matches('/').to_string();
+
+    tauri::async_runtime::spawn(async move {
+        let Ok(icon_url) = percent_encoding::percent_decode_str(&path).decode_utf8() else {
+            responder.respond(empty_response(StatusCode::BAD_REQUEST));
+            return;
+        };
+        let Some(app_state) = app.try_state::<AppState>() else {
+            responder.respond(empty_response(StatusCode::SERVICE_UNAVAILABLE));
+            return;
+        };
+
+        let response = match FaviconService::get(app_state.db(), &icon_url).await {
+            Ok(Some(favicon)) => Response::builder()
+                .status(StatusCode::OK)
+                .header(header::CONTENT_TYPE, favicon.mime_type)
+                .header(header::CACHE_CONTROL, "max-age=86400")
+                .body(favicon.data)
+                .unwrap_or_else(|_| empty_response(StatusCode::INTERNAL_SERVER_ERROR)),
+            Ok(None) => empty_response(StatusCode::NOT_FOUND),
+            Err(e) => {
+                println!("[NoB] favicon lookup failed for {}: {}", icon_url, e);
+                empty_response(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        };
+        responder.respond(response);
+    });
+}
+
+fn empty_response(status: StatusCode) -> Response<Vec<u8>> {
+    let mut response = Response::new(Vec::new());
+    *response.status_mut() = status;
+    response
+}
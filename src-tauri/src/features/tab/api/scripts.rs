@@ -0,0 +1,220 @@
+use crate::infrastructure::injection::ScriptModule;
+
+/// 上报导航（包括 pushState / replaceState / hash 变化）
+const NAVIGATION_REPORTER_SCRIPT: &str = r#"
+(() => {
+  const nob = window.__NOB__;
+  if (!nob) return;
+
+  nob.safeInit("NavigationReporter", () => {
+    const reportNavigation = () => {
+      nob.invoke("tabs_report_navigation", { url: window.location.href }).catch(e =>
+        console.warn("[NoB] report navigation failed", e)
+      );
+      // Also report title on navigation as a fallback
+      nob.invoke("tabs_report_title", { title: document.title || "Untitled" }).catch(e =>
+        console.warn("[NoB] report title failed", e)
+      );
+    };
+
+    window.addEventListener("popstate", reportNavigation);
+    window.addEventListener("hashchange", reportNavigation);
+
+    const originalPushState = history.pushState;
+    history.pushState = function(...args) {
+      const result = originalPushState.apply(this, args);
+      reportNavigation();
+      return result;
+    };
+
+    const originalReplaceState = history.replaceState;
+    history.replaceState = function(...args) {
+      const result = originalReplaceState.apply(this, args);
+      reportNavigation();
+      return result;
+    };
+
+    // Initial report
+    reportNavigation();
+  });
+})();
+"#;
+
+/// 上报页面标题
+const TITLE_REPORTER_SCRIPT: &str = r#"
+(() => {
+  const nob = window.__NOB__;
+  if (!nob) return;
+
+  nob.safeInit("TitleReporter", () => {
+    const reportTitle = () => {
+      nob.invoke("tabs_report_title", { title: document.title || "Untitled" }).catch(e =>
+        console.warn("[NoB] report title failed", e)
+      );
+    };
+
+    const startObserver = () => {
+      const target = document.querySelector('title') || document.head;
+      if (target) {
+        new MutationObserver(reportTitle).observe(target, {
+          subtree: true,
+          characterData: true,
+          childList: true
+        });
+        console.log("[NoB] Title observer started on", target);
+        // Report immediately when observer starts
+        reportTitle();
+      } else {
+        console.warn("[NoB] No title or head found to observe");
+      }
+    };
+
+    if (document.readyState === "loading") {
+      document.addEventListener("DOMContentLoaded", () => {
+        startObserver();
+        reportTitle();
+      });
+    } else {
+      startObserver();
+      reportTitle();
+    }
+
+    // Also report on window load to ensure final title is captured
+    window.addEventListener("load", reportTitle);
+  });
+})();
+"#;
+
+/// 上报网站图标（link 标签和 manifest）
+const FAVICON_REPORTER_SCRIPT: &str = r#"
+(() => {
+  const nob = window.__NOB__;
+  if (!nob) return;
+
+  nob.safeInit("FaviconReporter", () => {
+    const manifestIcons = new Map();
+
+    const loadManifestIcons = async (href) => {
+      if (manifestIcons.has(href)) return manifestIcons.get(href);
+      let icons = [];
+      try {
+        const res = await fetch(href, { credentials: "include" });
+        const manifest = await res.json();
+        icons = (manifest.icons || [])
+          .filter(icon => icon.src)
+          .map(icon => ({ url: new URL(icon.src, href).href, sizes: icon.sizes || null }));
+      } catch (e) {
+        console.warn("[NoB] load manifest icons failed", e);
+      }
+      manifestIcons.set(href, icons);
+      return icons;
+    };
+
+    const collectIcons = async () => {
+      const icons = [];
+      document
+        .querySelectorAll('link[rel~="icon"], link[rel="apple-touch-icon"]')
+        .forEach(link => {
+          if (link.href) icons.push({ url: link.href, sizes: link.getAttribute("sizes") });
+        });
+
+      const manifest = document.querySelector('link[rel="manifest"]');
+      if (manifest?.href) {
+        icons.push(...(await loadManifestIcons(manifest.href)));
+      }
+
+      if (icons.length === 0 && /^https?:$/.test(window.location.protocol)) {
+        icons.push({ url: new URL("/favicon.ico", window.location.href).href, sizes: null });
+      }
+      return icons;
+    };
+
+    let lastReported = "";
+    let timer = null;
+    const reportFavicon = () => {
+      clearTimeout(timer);
+      timer = setTimeout(async () => {
+        const icons = await collectIcons();
+        const key = JSON.stringify(icons);
+        if (icons.length === 0 || key === lastReported) return;
+        lastReported = key;
+        nob.invoke("tabs_report_favicon", { icons }).catch(e =>
+          console.warn("[NoB] report favicon failed", e)
+        );
+      }, 300);
+    };
+
+    const startObserver = () => {
+      if (document.head) {
+        new MutationObserver(reportFavicon).observe(document.head, {
+          childList: true,
+          subtree: true,
+          attributes: true,
+          attributeFilter: ["href", "rel", "sizes"]
+        });
+      }
+      reportFavicon();
+    };
+
+    if (document.readyState === "loading") {
+      document.addEventListener("DOMContentLoaded", startObserver);
+    } else {
+      startObserver();
+    }
+    window.addEventListener("load", reportFavicon);
+  });
+})();
+"#;
+
+/// 响应应用的 URL 请求，返回当前页面 URL
+///
+/// 远程页面不能监听事件，由 tabs_request_url 通过 eval 调用 `respondUrl`
+const URL_RESPONDER_SCRIPT: &str = r#"
+(() => {
+  const nob = window.__NOB__;
+  if (!nob) return;
+
+  nob.safeInit("UrlResponder", () => {
+    nob.respondUrl = () => {
+      console.log("[NoB] Received URL request, responding with:", window.location.href);
+      nob.invoke("tabs_respond_url", { url: window.location.href }).catch(e =>
+        console.warn("[NoB] respond url failed", e)
+      );
+    };
+  });
+})();
+"#;
+
+/// Tab Feature 注入的脚本模块
+///
+/// 导航上报和 URL 响应是标签记录 URL 的唯一来源，不能停用
+pub fn script_modules() -> Vec<ScriptModule> {
+    vec![
+        ScriptModule::new(
+            "tab.navigation_reporter",
+            "上报页面导航",
+            20,
+            NAVIGATION_REPORTER_SCRIPT,
+        )
+        .required(),
+        ScriptModule::new(
+            "tab.title_reporter",
+            "上报页面标题",
+            30,
+            TITLE_REPORTER_SCRIPT,
+        ),
+        ScriptModule::new(
+            "tab.favicon_reporter",
+            "上报网站图标",
+            40,
+            FAVICON_REPORTER_SCRIPT,
+        ),
+        ScriptModule::new(
+            "tab.url_responder",
+            "响应当前页面 URL 查询",
+            60,
+            URL_RESPONDER_SCRIPT,
+        )
+        .required(),
+    ]
+}
@@ -0,0 +1,128 @@
+use serde_json::json;
+use tauri::{AppHandle, Manager, State, Wry};
+
+use crate::core::AppState;
+use crate::features::tab::api::commands::{emit_tab_event, present_tab, tab_view};
+use crate::features::tab::core::models::{CreateTabSplitPayload, TabSplit, UpdateTabSplitPayload};
+use crate::features::tab::core::service::TabService;
+use crate::features::tab::core::split_service::TabSplitService;
+use crate::features::window::manager as window_manager;
+use crate::features::window::split::{self, SplitView};
+
+#[tauri::command]
+pub async fn tab_splits_list(app_state: State<'_, AppState>) -> Result<Vec<TabSplit>, String> {
+    TabSplitService::list(app_state.db())
+        .await
+        .map(|splits| splits.into_iter().map(TabSplit::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+/// 将两个标签组合为分屏，并激活 primary 在内容区展示整个组合
+#[tauri::command]
+pub async fn tab_splits_create(
+    app_state: State<'_, AppState>,
+    payload: CreateTabSplitPayload,
+) -> Result<TabSplit, String> {
+    let split = TabSplitService::create(
+        app_state.db(),
+        &payload.primary_tab_id,
+        &payload.secondary_tab_id,
+        payload.orientation,
+        payload.ratio,
+    )
+    .await
+    .map(TabSplit::from)
+    .map_err(|e| e.to_string())?;
+
+    let _ = emit_tab_event(&app_state, "split_created", json!({ "split": split }));
+
+    // 分离在独立窗口中的标签先放回内容栏
+    let app = app_state.app_handle();
+    for id in [&split.primary_tab_id, &split.secondary_tab_id] {
+        if window_manager::is_detached(id) {
+            window_manager::attach_content_window(&app, id);
+            let _ = emit_tab_event(&app_state, "attached", json!({ "id": id }));
+        }
+    }
+
+    let mut tab = TabService::activate(app_state.db(), &split.primary_tab_id)
+        .await
+        .map(|res| res.map(tab_view))
+        .map_err(|e| e.to_string())?;
+    if let Some(ref mut tab) = tab {
+        present_tab(&app_state, tab, false).await;
+        let _ = emit_tab_event(&app_state, "activated", json!({ "tab": tab }));
+    }
+
+    Ok(split)
+}
+
+/// 修改分屏方向或比例，正在展示的组合立即重新布局
+#[tauri::command]
+pub async fn tab_splits_update(
+    app_state: State<'_, AppState>,
+    payload: UpdateTabSplitPayload,
+) -> Result<Option<TabSplit>, String> {
+    let updated = TabSplitService::update(
+        app_state.db(),
+        &payload.id,
+        payload.orientation,
+        payload.ratio,
+    )
+    .await
+    .map(|res| res.map(TabSplit::from))
+    .map_err(|e| e.to_string())?;
+
+    if let Some(ref split) = updated {
+        if split::update_active_split(&split.id, split.orientation, split.ratio) {
+            window_manager::sync_content_window_position(&app_state.app_handle());
+        }
+        let _ = emit_tab_event(&app_state, "split_updated", json!({ "split": split }));
+    }
+
+    Ok(updated)
+}
+
+/// 解除分屏组合，激活的标签恢复单独显示
+#[tauri::command]
+pub async fn tab_splits_delete(
+    app_state: State<'_, AppState>,
+    id: String,
+) -> Result<Option<TabSplit>, String> {
+    let removed = TabSplitService::delete(app_state.db(), &id)
+        .await
+        .map(|res| res.map(TabSplit::from))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(ref split) = removed {
+        if split::active_split().is_some_and(|active| active.id == split.id) {
+            split::set_active_split(None);
+            let _ = window_manager::present_active_content_window(&app_state.app_handle(), false);
+        }
+        let _ = emit_tab_event(&app_state, "split_deleted", json!({ "id": split.id }));
+    }
+
+    Ok(removed)
+}
+
+/// 拖动分隔后保存新的比例，并通知前端（注册为分屏比例变化的回调）
+pub(crate) fn save_split_ratio(app: &AppHandle<Wry>, split: &SplitView) {
+    let app_handle = app.clone();
+    let (id, ratio) = (split.id.clone(), split.ratio);
+    tauri::async_runtime::spawn(async move {
+        let Some(state) = app_handle.try_state::<AppState>() else {
+            return;
+        };
+        match TabSplitService::update(state.db(), &id, None, Some(ratio)).await {
+            Ok(Some(model)) => {
+                let _ = emit_tab_event(
+                    &state,
+                    "split_updated",
+                    json!({ "split": TabSplit::from(model) }),
+                );
+            }
+            Ok(None) => {}
+            Err(e) => println!("[NoB] Failed to save split ratio: {}", e),
+        }
+    });
+}
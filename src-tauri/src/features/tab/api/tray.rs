@@ -0,0 +1,87 @@
+use tauri::{AppHandle, Manager};
+
+use crate::core::AppState;
+use crate::features::tab::api::commands::reopen_closed;
+use crate::features::tab::core::closed_service::ClosedTabService;
+use crate::features::tab::core::models::ClosedTab;
+use crate::features::tab::TabFeature;
+use crate::infrastructure::tray::{TrayMenuItem, TraySubmenu};
+
+/// 托盘中最多列出的最近关闭标签数
+const RECENTLY_CLOSED_MENU_LIMIT: u64 = 10;
+/// 托盘菜单项标题的最大字符数
+const MENU_LABEL_MAX_CHARS: usize = 40;
+
+/// 最近关闭的标签页子菜单，点击条目重新打开对应标签
+pub fn recently_closed_submenu() -> TraySubmenu {
+    TraySubmenu::new(
+        "recently_closed",
+        "最近关闭",
+        "（无）",
+        |app: &AppHandle| {
+            let Some(state) = app.try_state::<AppState>() else {
+                return vec![];
+            };
+            let Some(feature) = state
+                .get_feature("tab")
+                .and_then(|f| f.as_any().downcast_ref::<TabFeature>())
+            else {
+                return vec![];
+            };
+
+            feature
+                .recently_closed()
+                .into_iter()
+                .map(reopen_closed_item)
+                .collect()
+        },
+    )
+}
+
+fn reopen_closed_item(tab: ClosedTab) -> TrayMenuItem {
+    let id = tab.id.clone();
+    TrayMenuItem::always_visible(
+        format!("reopen_closed:{}", tab.id),
+        truncate_label(&tab.title),
+        move |app: &AppHandle| {
+            let app_handle = app.clone();
+            let id = id.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Some(state) = app_handle.try_state::<AppState>() {
+                    let _ = reopen_closed(&state, Some(id)).await;
+                }
+            });
+        },
+    )
+}
+
+/// 从数据库刷新最近关闭标签缓存并重建托盘菜单
+pub async fn refresh_recently_closed(app_state: &AppState) {
+    let closed = match ClosedTabService::list(app_state.db(), RECENTLY_CLOSED_MENU_LIMIT).await {
+        Ok(closed) => closed.into_iter().map(ClosedTab::from).collect(),
+        Err(e) => {
+            println!("[TabFeature] Failed to load recently closed tabs: {}", e);
+            return;
+        }
+    };
+
+    if let Some(feature) = app_state
+        .get_feature("tab")
+        .and_then(|f| f.as_any().downcast_ref::<TabFeature>())
+    {
+        feature.set_recently_closed(closed);
+    }
+
+    let _ = app_state
+        .tray_manager()
+        .update_tray_menu(&app_state.app_handle());
+}
+
+fn truncate_label(title: &str) -> String {
+    if title.chars().count() > MENU_LABEL_MAX_CHARS {
+        let truncated: String = title.chars().take(MENU_LABEL_MAX_CHARS).collect();
+        format!("{}…", truncated)
+    } else {
+        title.to_string()
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+
+use crate::features::tab::core::history_service::TabHistoryService;
+use crate::features::tab::data::closed_entity::{self, Entity as ClosedTabEntity, Model};
+use crate::features::tab::data::entity;
+
+/// 最近关闭列表最多保留的条数
+const MAX_CLOSED_TABS: usize = 50;
+
+/// 最近关闭的标签页服务
+pub struct ClosedTabService;
+
+impl ClosedTabService {
+    /// 按关闭时间倒序列出最近关闭的标签页
+    pub async fn list(db: &DatabaseConnection, limit: u64) -> Result<Vec<Model>> {
+        let closed = ClosedTabEntity::find()
+            .order_by_desc(closed_entity::Column::ClosedAt)
+            .limit(limit)
+            .all(db)
+            .await?;
+
+        Ok(closed)
+    }
+
+    /// 记录一个被关闭的标签页，超出上限时淘汰最早关闭的条目及其导航历史
+    ///
+    /// 返回被淘汰的标签 id
+    pub async fn push<C>(conn: &C, tab: &entity::Model) -> Result<Vec<String>>
+    where
+        C: ConnectionTrait,
+    {
+        // 同一个 id 只保留最近一次关闭
+        ClosedTabEntity::delete_by_id(tab.id.clone())
+            .exec(conn)
+            .await?;
+
+        closed_entity::ActiveModel {
+            id: Set(tab.id.clone()),
+            group_id: Set(tab.group_id.clone()),
+            title: Set(tab.title.clone()),
+            url: Set(tab.url.clone()),
+            initial_url: Set(tab.initial_url.clone()),
+            favicon_url: Set(tab.favicon_url.clone()),
+            is_pinned: Set(tab.is_pinned),
+            sort_order: Set(tab.sort_order),
+            content_width: Set(tab.content_width),
+            content_height: Set(tab.content_height),
+            created_at: Set(tab.created_at),
+            closed_at: Set(Utc::now()),
+        }
+        .insert(conn)
+        .await?;
+
+        let expired: Vec<String> = ClosedTabEntity::find()
+            .order_by_desc(closed_entity::Column::ClosedAt)
+            .all(conn)
+            .await?
+            .into_iter()
+            .skip(MAX_CLOSED_TABS)
+            .map(|c| c.id)
+            .collect();
+
+        if !expired.is_empty() {
+            ClosedTabEntity::delete_many()
+                .filter(closed_entity::Column::Id.is_in(expired.clone()))
+                .exec(conn)
+                .await?;
+            TabHistoryService::delete_for_tabs(conn, expired.clone()).await?;
+        }
+
+        Ok(expired)
+    }
+
+    /// 取出（并移除）指定的关闭记录，未指定 id 时取最近关闭的一条
+    pub async fn take<C>(conn: &C, id: Option<&str>) -> Result<Option<Model>>
+    where
+        C: ConnectionTrait,
+    {
+        let closed = match id {
+            Some(id) => {
+                ClosedTabEntity::find_by_id(id.to_string())
+                    .one(conn)
+                    .await?
+            }
+            None => {
+                ClosedTabEntity::find()
+                    .order_by_desc(closed_entity::Column::ClosedAt)
+                    .one(conn)
+                    .await?
+            }
+        };
+
+        if let Some(ref model) = closed {
+            ClosedTabEntity::delete_by_id(model.id.clone())
+                .exec(conn)
+                .await?;
+        }
+
+        Ok(closed)
+    }
+}
@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use url::Url;
+
+use crate::features::tab::core::models::FaviconCandidate;
+use crate::features::tab::data::favicon_entity::{self, Entity as FaviconEntity, Model};
+use crate::infrastructure::web::{fetch_public, is_public_url};
+
+/// 单个图标的大小上限
+const MAX_FAVICON_BYTES: usize = 512 * 1024;
+/// 下载超时
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+/// 标签栏显示的目标尺寸（像素）
+const PREFERRED_SIZE: u32 = 32;
+
+/// 网站图标缓存服务
+pub struct FaviconService;
+
+impl FaviconService {
+    pub async fn get(db: &DatabaseConnection, url: &str) -> Result<Option<Model>> {
+        let favicon = FaviconEntity::find_by_id(url.to_string()).one(db).await?;
+        Ok(favicon)
+    }
+
+    /// 获取图标，未缓存时下载并写入缓存
+    ///
+    /// 只在内容页上报图标时调用，`favicon://` 协议只读取已有的缓存
+    pub async fn fetch(db: &DatabaseConnection, url: &str) -> Result<Model> {
+        if let Some(cached) = Self::get(db, url).await? {
+            return Ok(cached);
+        }
+
+        let (mime_type, data) = Self::download(url).await?;
+
+        let favicon = Model {
+            url: url.to_string(),
+            mime_type,
+            data,
+            fetched_at: Utc::now(),
+        };
+
+        // 同一图标可能被多个标签同时请求，已存在时覆盖
+        FaviconEntity::insert(favicon_entity::ActiveModel::from(favicon.clone()))
+            .on_conflict(
+                OnConflict::column(favicon_entity::Column::Url)
+                    .update_columns([
+                        favicon_entity::Column::MimeType,
+                        favicon_entity::Column::Data,
+                        favicon_entity::Column::FetchedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(db)
+            .await?;
+
+        Ok(favicon)
+    }
+
+    /// 从能下载的候选图标中选出最接近目标尺寸的一个，尺寸相同时保留页面中靠前的
+    pub fn pick(candidates: &[FaviconCandidate]) -> Option<&FaviconCandidate> {
+        candidates
+            .iter()
+            .filter(|c| Self::is_fetchable(&c.url))
+            .min_by_key(|c| {
+                let size = Self::largest_size(c.sizes.as_deref());
+                // 偏小的图标放大后会模糊，距离加倍计算
+                if size >= PREFERRED_SIZE {
+                    size - PREFERRED_SIZE
+                } else {
+                    (PREFERRED_SIZE - size) * 2
+                }
+            })
+    }
+
+    /// data:image，或公网上的 http/https 地址（图标由应用在后台下载，不能指向本机或局域网）
+    fn is_fetchable(url: &str) -> bool {
+        if let Some(data) = url.strip_prefix("data:") {
+            return data.starts_with("image/");
+        }
+        Url::parse(url).is_ok_and(|url| is_public_url(&url))
+    }
+
+    /// 解析 sizes 属性中的最大边长；"any"（通常是 SVG）视为目标尺寸，缺省视为 16
+    fn largest_size(sizes: Option<&str>) -> u32 {
+        let Some(sizes) = sizes else {
+            return 16;
+        };
+
+        sizes
+            .split_whitespace()
+            .filter_map(|s| {
+                if s.eq_ignore_ascii_case("any") {
+                    return Some(PREFERRED_SIZE);
+                }
+                let (w, h) = s
+                    .to_ascii_lowercase()
+                    .split_once('x')
+                    .map(|(w, h)| (w.parse::<u32>().ok(), h.parse::<u32>().ok()))?;
+                Some(w?.max(h?))
+            })
+            .max()
+            .unwrap_or(16)
+    }
+
+    async fn download(url: &str) -> Result<(String, Vec<u8>)> {
+        if let Some(data_url) = url.strip_prefix("data:") {
+            return Self::decode_data_url(data_url);
+        }
+
+        let body = fetch_public(url, FETCH_TIMEOUT, MAX_FAVICON_BYTES).await?;
+        Self::validate(body.content_type, body.data)
+    }
+
+    /// 解析 `data:[<mime>][;base64],<payload>`（不含 `data:` 前缀）
+    fn decode_data_url(data_url: &str) -> Result<(String, Vec<u8>)> {
+        let (meta, payload) = data_url
+            .split_once(',')
+            .ok_or_else(|| anyhow!("invalid data url"))?;
+
+        let (mime, is_base64) = match meta.strip_suffix(";base64") {
+            Some(mime) => (mime, true),
+            None => (meta, false),
+        };
+
+        let data = if is_base64 {
+            base64::engine::general_purpose::STANDARD.decode(payload)?
+        } else {
+            percent_encoding::percent_decode_str(payload).collect()
+        };
+
+        let declared = (!mime.is_empty()).then(|| mime.to_string());
+        Self::validate(declared, data)
+    }
+
+    /// 校验大小并确定 MIME 类型，非图片内容（如 HTML 错误页）会被拒绝
+    fn validate(declared: Option<String>, data: Vec<u8>) -> Result<(String, Vec<u8>)> {
+        if data.is_empty() || data.len() > MAX_FAVICON_BYTES {
+            return Err(anyhow!("invalid favicon size: {} bytes", data.len()));
+        }
+
+        let mime_type = match declared {
+            Some(mime) if mime.starts_with("image/") => mime,
+            _ => Self::sniff_mime(&data)
+                .ok_or_else(|| anyhow!("favicon is not an image"))?
+                .to_string(),
+        };
+
+        Ok((mime_type, data))
+    }
+
+    fn sniff_mime(data: &[u8]) -> Option<&'static str> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some("image/png")
+        } else if data.starts_with(&[0x00, 0x00, 0x01, 0x00]) {
+            Some("image/x-icon")
+        } else if data.starts_with(b"GIF8") {
+            Some("image/gif")
+        } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some("image/jpeg")
+        } else if data.len() > 12 && &data[0..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+            Some("image/webp")
+        } else {
+            let head = String::from_utf8_lossy(&data[..data.len().min(256)]);
+            let head = head.trim_start();
+            (head.starts_with("<svg") || (head.starts_with("<?xml") && head.contains("<svg")))
+                .then_some("image/svg+xml")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(url: &str, sizes: Option<&str>) -> FaviconCandidate {
+        FaviconCandidate {
+            url: url.to_string(),
+            sizes: sizes.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn pick_prefers_the_size_closest_to_32() {
+        let candidates = [
+            candidate("https://example.com/16.png", Some("16x16")),
+            candidate("https://example.com/180.png", Some("180x180")),
+            candidate("https://example.com/48.png", Some("48x48")),
+        ];
+        let picked = FaviconService::pick(&candidates).unwrap();
+        assert_eq!(picked.url, "https://example.com/48.png");
+    }
+
+    #[test]
+    fn pick_skips_icons_that_cannot_be_fetched() {
+        let candidates = [
+            candidate("http://127.0.0.1/32.png", Some("32x32")),
+            candidate("http://192.168.1.1/32.png", Some("32x32")),
+            candidate("http://localhost/32.png", Some("32x32")),
+            candidate("data:text/html,<b>32</b>", Some("32x32")),
+            candidate("file:///icon.png", Some("32x32")),
+            candidate("", None),
+            candidate(
+                "https://github.githubassets.com/favicons/favicon.svg",
+                Some("any"),
+            ),
+        ];
+        let picked = FaviconService::pick(&candidates).unwrap();
+        assert_eq!(
+            picked.url,
+            "https://github.githubassets.com/favicons/favicon.svg"
+        );
+
+        assert!(FaviconService::pick(&candidates[..6]).is_none());
+    }
+}
@@ -0,0 +1,284 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+use crate::features::tab::core::history_service::TabHistoryService;
+use crate::features::tab::core::split_service::TabSplitService;
+use crate::features::tab::core::models::{
+    CreateTabGroupPayload, ReorderTabGroupsPayload, UpdateTabGroupPayload,
+};
+use crate::features::tab::data::entity::{self as tab_entity, Entity as TabEntity};
+use crate::features::tab::data::group_entity::{
+    self, Entity as TabGroupEntity, Model, DEFAULT_GROUP_ID,
+};
+
+/// Tab 分组（工作区）服务
+///
+/// 同一时间只有一个分组处于激活状态，TabService 的所有操作都作用于当前分组。
+pub struct TabGroupService;
+
+impl TabGroupService {
+    pub async fn list(db: &DatabaseConnection) -> Result<Vec<Model>> {
+        let groups = TabGroupEntity::find()
+            .order_by_asc(group_entity::Column::SortOrder)
+            .order_by_asc(group_entity::Column::CreatedAt)
+            .all(db)
+            .await?;
+
+        Ok(groups)
+    }
+
+    pub async fn get_active(db: &DatabaseConnection) -> Result<Option<Model>> {
+        let group = TabGroupEntity::find()
+            .filter(group_entity::Column::IsActive.eq(true))
+            .one(db)
+            .await?;
+        Ok(group)
+    }
+
+    /// 当前分组 ID
+    ///
+    /// 没有激活分组时回退到排序第一的分组，再回退到默认分组
+    pub async fn current_id<C>(conn: &C) -> Result<String>
+    where
+        C: ConnectionTrait,
+    {
+        if let Some(active) = TabGroupEntity::find()
+            .filter(group_entity::Column::IsActive.eq(true))
+            .one(conn)
+            .await?
+        {
+            return Ok(active.id);
+        }
+
+        let first = TabGroupEntity::find()
+            .order_by_asc(group_entity::Column::SortOrder)
+            .one(conn)
+            .await?;
+
+        Ok(first
+            .map(|g| g.id)
+            .unwrap_or_else(|| DEFAULT_GROUP_ID.to_string()))
+    }
+
+    /// 在给定连接（通常是事务）中切换到指定分组，分组不存在时返回 false
+    pub async fn switch_to<C>(conn: &C, id: &str) -> Result<bool>
+    where
+        C: ConnectionTrait,
+    {
+        if TabGroupEntity::find_by_id(id.to_string())
+            .one(conn)
+            .await?
+            .is_none()
+        {
+            return Ok(false);
+        }
+
+        Self::deactivate_all(conn).await?;
+        TabGroupEntity::update_many()
+            .col_expr(group_entity::Column::IsActive, Expr::value(true))
+            .filter(group_entity::Column::Id.eq(id))
+            .exec(conn)
+            .await?;
+
+        Ok(true)
+    }
+
+    /// 在给定连接中按名称查找分组，不存在时创建（不激活），返回分组 ID 以及是否新建
+    pub async fn find_or_create<C>(conn: &C, name: &str) -> Result<(String, bool)>
+    where
+        C: ConnectionTrait,
+    {
+        if let Some(existing) = TabGroupEntity::find()
+            .filter(group_entity::Column::Name.eq(name))
+            .one(conn)
+            .await?
+        {
+            return Ok((existing.id, false));
+        }
+
+        let next_order = TabGroupEntity::find()
+            .order_by_desc(group_entity::Column::SortOrder)
+            .one(conn)
+            .await?
+            .map(|g| g.sort_order + 1)
+            .unwrap_or(0);
+        let now = Utc::now();
+
+        let created = group_entity::ActiveModel {
+            id: Set(Uuid::new_v4().to_string()),
+            name: Set(name.to_string()),
+            is_active: Set(false),
+            sort_order: Set(next_order),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(conn)
+        .await?;
+
+        Ok((created.id, true))
+    }
+
+    pub async fn create(db: &DatabaseConnection, payload: CreateTabGroupPayload) -> Result<Model> {
+        let txn = db.begin().await?;
+        let now = Utc::now();
+        let should_activate = payload.activate.unwrap_or(true);
+
+        let next_order = TabGroupEntity::find()
+            .order_by_desc(group_entity::Column::SortOrder)
+            .one(&txn)
+            .await?
+            .map(|g| g.sort_order + 1)
+            .unwrap_or(0);
+
+        if should_activate {
+            Self::deactivate_all(&txn).await?;
+        }
+
+        let active_model = group_entity::ActiveModel {
+            id: Set(Uuid::new_v4().to_string()),
+            name: Set(payload.name),
+            is_active: Set(should_activate),
+            sort_order: Set(next_order),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let created = active_model.insert(&txn).await?;
+        txn.commit().await?;
+
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        payload: UpdateTabGroupPayload,
+    ) -> Result<Option<Model>> {
+        let existing = TabGroupEntity::find_by_id(payload.id.clone())
+            .one(db)
+            .await?;
+        if let Some(model) = existing {
+            let mut active_model: group_entity::ActiveModel = model.into();
+            if let Some(name) = payload.name {
+                active_model.name = Set(name);
+            }
+            active_model.updated_at = Set(Utc::now());
+
+            let updated = active_model.update(db).await?;
+            Ok(Some(updated))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// 切换到指定分组
+    pub async fn activate(db: &DatabaseConnection, id: &str) -> Result<Option<Model>> {
+        let txn = db.begin().await?;
+        let group = TabGroupEntity::find_by_id(id.to_string()).one(&txn).await?;
+
+        if let Some(model) = group {
+            Self::deactivate_all(&txn).await?;
+
+            let mut active_model: group_entity::ActiveModel = model.into();
+            active_model.is_active = Set(true);
+            active_model.updated_at = Set(Utc::now());
+
+            let updated = active_model.update(&txn).await?;
+            txn.commit().await?;
+            Ok(Some(updated))
+        } else {
+            txn.rollback().await.ok();
+            Ok(None)
+        }
+    }
+
+    /// 删除分组及其下所有标签
+    ///
+    /// 返回因此被激活的新分组，以及被删除的标签 id（用于销毁对应的 webview）
+    pub async fn delete(
+        db: &DatabaseConnection,
+        id: &str,
+    ) -> Result<(Option<Model>, Vec<String>)> {
+        let txn = db.begin().await?;
+        let group = TabGroupEntity::find_by_id(id.to_string()).one(&txn).await?;
+
+        let Some(model) = group else {
+            txn.rollback().await.ok();
+            return Ok((None, vec![]));
+        };
+
+        if TabGroupEntity::find().count(&txn).await? <= 1 {
+            txn.rollback().await.ok();
+            return Err(anyhow!("cannot delete the last tab group"));
+        }
+
+        let tab_ids: Vec<String> = TabEntity::find()
+            .filter(tab_entity::Column::GroupId.eq(id.to_string()))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        TabHistoryService::delete_for_tabs(&txn, tab_ids.clone()).await?;
+        TabSplitService::delete_for_tabs(&txn, tab_ids.clone()).await?;
+
+        TabEntity::delete_many()
+            .filter(tab_entity::Column::GroupId.eq(id.to_string()))
+            .exec(&txn)
+            .await?;
+        TabGroupEntity::delete_by_id(id.to_string())
+            .exec(&txn)
+            .await?;
+
+        let activated = if model.is_active {
+            if let Some(next) = TabGroupEntity::find()
+                .order_by_asc(group_entity::Column::SortOrder)
+                .one(&txn)
+                .await?
+            {
+                let mut active_model: group_entity::ActiveModel = next.into();
+                active_model.is_active = Set(true);
+                active_model.updated_at = Set(Utc::now());
+                Some(active_model.update(&txn).await?)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        txn.commit().await?;
+        Ok((activated, tab_ids))
+    }
+
+    pub async fn reorder(db: &DatabaseConnection, payload: ReorderTabGroupsPayload) -> Result<()> {
+        let txn = db.begin().await?;
+
+        for (idx, group_id) in payload.ordered_ids.iter().enumerate() {
+            TabGroupEntity::update_many()
+                .col_expr(group_entity::Column::SortOrder, Expr::value(idx as i32))
+                .filter(group_entity::Column::Id.eq(group_id.clone()))
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn deactivate_all<C>(conn: &C) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        TabGroupEntity::update_many()
+            .col_expr(group_entity::Column::IsActive, Expr::value(false))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use anyhow::Result;
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use crate::features::tab::data::history_entity::{self, Entity as TabHistoryEntity, Model};
+
+/// 每个标签页最多保留的历史条数
+const MAX_HISTORY_ENTRIES: usize = 100;
+
+/// 标签页导航历史服务
+pub struct TabHistoryService;
+
+impl TabHistoryService {
+    pub async fn list(db: &DatabaseConnection, tab_id: &str) -> Result<Vec<Model>> {
+        let entries = TabHistoryEntity::find()
+            .filter(history_entity::Column::TabId.eq(tab_id))
+            .order_by_asc(history_entity::Column::Position)
+            .all(db)
+            .await?;
+
+        Ok(entries)
+    }
+
+    /// 记录一次导航
+    ///
+    /// 与当前位置 URL 相同时忽略（后退/前进引起的加载、replaceState 等重复上报），
+    /// 否则丢弃当前位置之后的前进记录并追加新条目
+    pub async fn record<C>(conn: &C, tab_id: &str, url: &str) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        let current = Self::current(conn, tab_id).await?;
+
+        let position = match current {
+            Some(ref entry) if entry.url == url => return Ok(()),
+            Some(ref entry) => {
+                TabHistoryEntity::delete_many()
+                    .filter(history_entity::Column::TabId.eq(tab_id))
+                    .filter(history_entity::Column::Position.gt(entry.position))
+                    .exec(conn)
+                    .await?;
+                entry.position + 1
+            }
+            None => Self::next_position(conn, tab_id).await?,
+        };
+
+        Self::clear_current(conn, tab_id).await?;
+
+        history_entity::ActiveModel {
+            id: Set(Uuid::new_v4().to_string()),
+            tab_id: Set(tab_id.to_string()),
+            position: Set(position),
+            url: Set(url.to_string()),
+            title: Set(None),
+            is_current: Set(true),
+            created_at: Set(Utc::now()),
+        }
+        .insert(conn)
+        .await?;
+
+        Self::trim(conn, tab_id).await
+    }
+
+    /// 更新当前位置条目的标题
+    pub async fn update_current_title<C>(conn: &C, tab_id: &str, title: &str) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        TabHistoryEntity::update_many()
+            .col_expr(history_entity::Column::Title, Expr::value(title))
+            .filter(history_entity::Column::TabId.eq(tab_id))
+            .filter(history_entity::Column::IsCurrent.eq(true))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// 将当前位置向后（forward = false）或向前移动一步，返回新的当前条目
+    pub async fn step<C>(conn: &C, tab_id: &str, forward: bool) -> Result<Option<Model>>
+    where
+        C: ConnectionTrait,
+    {
+        let Some(current) = Self::current(conn, tab_id).await? else {
+            return Ok(None);
+        };
+
+        let query = TabHistoryEntity::find().filter(history_entity::Column::TabId.eq(tab_id));
+        let target = if forward {
+            query
+                .filter(history_entity::Column::Position.gt(current.position))
+                .order_by_asc(history_entity::Column::Position)
+                .one(conn)
+                .await?
+        } else {
+            query
+                .filter(history_entity::Column::Position.lt(current.position))
+                .order_by_desc(history_entity::Column::Position)
+                .one(conn)
+                .await?
+        };
+
+        let Some(target) = target else {
+            return Ok(None);
+        };
+
+        Self::clear_current(conn, tab_id).await?;
+
+        let mut active_model: history_entity::ActiveModel = target.into();
+        active_model.is_current = Set(true);
+        let updated = active_model.update(conn).await?;
+
+        Ok(Some(updated))
+    }
+
+    /// 删除指定标签页的全部历史
+    pub async fn delete_for_tabs<C>(conn: &C, tab_ids: Vec<String>) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        TabHistoryEntity::delete_many()
+            .filter(history_entity::Column::TabId.is_in(tab_ids))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn current<C>(conn: &C, tab_id: &str) -> Result<Option<Model>>
+    where
+        C: ConnectionTrait,
+    {
+        let entry = TabHistoryEntity::find()
+            .filter(history_entity::Column::TabId.eq(tab_id))
+            .filter(history_entity::Column::IsCurrent.eq(true))
+            .one(conn)
+            .await?;
+        Ok(entry)
+    }
+
+    async fn clear_current<C>(conn: &C, tab_id: &str) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        TabHistoryEntity::update_many()
+            .col_expr(history_entity::Column::IsCurrent, Expr::value(false))
+            .filter(history_entity::Column::TabId.eq(tab_id))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn next_position<C>(conn: &C, tab_id: &str) -> Result<i32>
+    where
+        C: ConnectionTrait,
+    {
+        let last = TabHistoryEntity::find()
+            .filter(history_entity::Column::TabId.eq(tab_id))
+            .order_by_desc(history_entity::Column::Position)
+            .one(conn)
+            .await?;
+
+        Ok(last.map(|e| e.position + 1).unwrap_or(0))
+    }
+
+    /// 超出上限时删除最早的条目
+    async fn trim<C>(conn: &C, tab_id: &str) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        let expired: Vec<String> = TabHistoryEntity::find()
+            .filter(history_entity::Column::TabId.eq(tab_id))
+            .order_by_desc(history_entity::Column::Position)
+            .all(conn)
+            .await?
+            .into_iter()
+            .skip(MAX_HISTORY_ENTRIES)
+            .map(|e| e.id)
+            .collect();
+
+        if !expired.is_empty() {
+            TabHistoryEntity::delete_many()
+                .filter(history_entity::Column::Id.is_in(expired))
+                .exec(conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
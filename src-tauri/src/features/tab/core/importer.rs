@@ -0,0 +1,452 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, Row};
+
+/// 书签文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportFormat {
+    /// 浏览器通用的 Netscape 书签 HTML 导出
+    NetscapeHtml,
+    /// Chrome / Edge 配置目录下的 `Bookmarks` 文件
+    ChromeJson,
+    /// Firefox 配置目录下的 `places.sqlite`
+    FirefoxPlaces,
+}
+
+/// 从书签文件中解析出的一条书签
+#[derive(Debug, Clone)]
+pub struct ImportedBookmark {
+    /// 所在文件夹（最内层），位于根目录时为 None
+    pub folder: Option<String>,
+    pub title: String,
+    pub url: String,
+}
+
+/// Firefox 根目录（菜单、工具栏、其他书签、移动设备书签）的 guid
+const FIREFOX_ROOT_GUIDS: [&str; 5] = [
+    "root________",
+    "menu________",
+    "toolbar_____",
+    "unfiled_____",
+    "mobile______",
+];
+
+/// 读取并解析书签文件，未指定格式时根据文件内容识别
+pub async fn read_bookmarks(
+    path: &Path,
+    format: Option<ImportFormat>,
+) -> Result<Vec<ImportedBookmark>> {
+    // 文件读取放到阻塞线程池，不占用异步运行时；places.sqlite 由 sqlx 读取
+    let file = path.to_path_buf();
+    let (format, bytes) =
+        tauri::async_runtime::spawn_blocking(move || -> Result<(ImportFormat, Vec<u8>)> {
+            let read = || {
+                std::fs::read(&file).with_context(|| format!("failed to read {}", file.display()))
+            };
+            match format {
+                Some(ImportFormat::FirefoxPlaces) => Ok((ImportFormat::FirefoxPlaces, Vec::new())),
+                Some(format) => Ok((format, read()?)),
+                None => {
+                    let bytes = read()?;
+                    let format = detect_format(&bytes)
+                        .ok_or_else(|| anyhow!("unrecognized bookmark file: {}", file.display()))?;
+                    Ok((format, bytes))
+                }
+            }
+        })
+        .await??;
+
+    match format {
+        ImportFormat::FirefoxPlaces => read_firefox_places(path).await,
+        ImportFormat::ChromeJson => parse_chrome_json(&String::from_utf8_lossy(&bytes)),
+        ImportFormat::NetscapeHtml => Ok(parse_netscape_html(&String::from_utf8_lossy(&bytes))),
+    }
+}
+
+fn detect_format(bytes: &[u8]) -> Option<ImportFormat> {
+    if bytes.starts_with(b"SQLite format 3\0") {
+        return Some(ImportFormat::FirefoxPlaces);
+    }
+
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(4096)]);
+    let trimmed = head.trim_start_matches('\u{feff}').trim_start();
+    if trimmed.starts_with('{') && trimmed.contains("\"roots\"") {
+        return Some(ImportFormat::ChromeJson);
+    }
+    let upper = trimmed.to_ascii_uppercase();
+    if upper.contains("NETSCAPE-BOOKMARK-FILE") || upper.contains("<DL") {
+        return Some(ImportFormat::NetscapeHtml);
+    }
+
+    None
+}
+
+/// 解析 Netscape 书签 HTML
+///
+/// `<H3>` 声明文件夹，紧随其后的 `<DL>` 为其内容；工具栏根目录不视为文件夹
+pub fn parse_netscape_html(content: &str) -> Vec<ImportedBookmark> {
+    let mut bookmarks = Vec::new();
+    // 每层 <DL> 对应的文件夹名
+    let mut folders: Vec<Option<String>> = Vec::new();
+    let mut pending_folder: Option<String> = None;
+    let mut rest = content;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        let after = &rest[end + 1..];
+        let name = tag
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or("")
+            .to_ascii_uppercase();
+
+        match name.as_str() {
+            "H3" => {
+                let (text, next) = inner_text(after, "</H3>");
+                let is_root = tag.to_ascii_uppercase().contains("PERSONAL_TOOLBAR_FOLDER");
+                pending_folder = (!is_root).then(|| decode_entities(text.trim()));
+                rest = next;
+                continue;
+            }
+            "DL" => folders.push(pending_folder.take()),
+            "/DL" => {
+                folders.pop();
+            }
+            "A" => {
+                let (text, next) = inner_text(after, "</A>");
+                if let Some(href) = attribute(tag, "HREF") {
+                    let url = decode_entities(&href);
+                    let title = decode_entities(text.trim());
+                    bookmarks.push(ImportedBookmark {
+                        folder: folders.iter().rev().flatten().next().cloned(),
+                        title: if title.is_empty() { url.clone() } else { title },
+                        url,
+                    });
+                }
+                rest = next;
+                continue;
+            }
+            _ => {}
+        }
+
+        rest = after;
+    }
+
+    bookmarks
+}
+
+/// 解析 Chrome `Bookmarks` JSON，roots 下的书签栏等根目录不视为文件夹
+pub fn parse_chrome_json(content: &str) -> Result<Vec<ImportedBookmark>> {
+    let value: Value = serde_json::from_str(content.trim_start_matches('\u{feff}'))?;
+    let roots = value
+        .get("roots")
+        .and_then(Value::as_object)
+        .ok_or_else(|| anyhow!("missing roots in Chrome bookmarks"))?;
+
+    let mut bookmarks = Vec::new();
+    for root in roots.values() {
+        if let Some(children) = root.get("children").and_then(Value::as_array) {
+            for child in children {
+                collect_chrome_node(child, None, &mut bookmarks);
+            }
+        }
+    }
+
+    Ok(bookmarks)
+}
+
+fn collect_chrome_node(node: &Value, folder: Option<&str>, out: &mut Vec<ImportedBookmark>) {
+    let name = node.get("name").and_then(Value::as_str).unwrap_or("");
+
+    match node.get("type").and_then(Value::as_str) {
+        Some("url") => {
+            if let Some(url) = node.get("url").and_then(Value::as_str) {
+                out.push(ImportedBookmark {
+                    folder: folder.map(str::to_string),
+                    title: if name.is_empty() { url } else { name }.to_string(),
+                    url: url.to_string(),
+                });
+            }
+        }
+        Some("folder") => {
+            if let Some(children) = node.get("children").and_then(Value::as_array) {
+                for child in children {
+                    collect_chrome_node(child, Some(name), out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 读取 Firefox `places.sqlite`
+///
+/// 以只读、immutable 方式打开，Firefox 运行中锁定数据库时也能读取
+async fn read_firefox_places(path: &Path) -> Result<Vec<ImportedBookmark>> {
+    let mut conn = SqliteConnectOptions::new()
+        .filename(path)
+        .read_only(true)
+        .immutable(true)
+        .connect()
+        .await
+        .with_context(|| format!("failed to open {}", path.display()))?;
+
+    let rows = sqlx::query(
+        "SELECT b.title AS title, p.url AS url, f.title AS folder, f.guid AS folder_guid \
+         FROM moz_bookmarks b \
+         JOIN moz_places p ON p.id = b.fk \
+         LEFT JOIN moz_bookmarks f ON f.id = b.parent \
+         WHERE b.type = 1 \
+         ORDER BY b.parent, b.position",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    let bookmarks = rows
+        .into_iter()
+        .filter_map(|row| {
+            let url: String = row.try_get("url").ok()?;
+            let title: Option<String> = row.try_get("title").ok().flatten();
+            let folder: Option<String> = row.try_get("folder").ok().flatten();
+            let folder_guid: Option<String> = row.try_get("folder_guid").ok().flatten();
+
+            let is_root = folder_guid
+                .as_deref()
+                .is_some_and(|guid| FIREFOX_ROOT_GUIDS.contains(&guid));
+
+            Some(ImportedBookmark {
+                folder: folder.filter(|f| !is_root && !f.is_empty()),
+                title: title
+                    .filter(|t| !t.is_empty())
+                    .unwrap_or_else(|| url.clone()),
+                url,
+            })
+        })
+        .collect();
+
+    Ok(bookmarks)
+}
+
+/// 截取到结束标签（不区分大小写）之前的文本，返回文本和结束标签之后的剩余内容
+fn inner_text<'a>(content: &'a str, close_tag: &str) -> (&'a str, &'a str) {
+    match content.to_ascii_uppercase().find(close_tag) {
+        Some(pos) => (&content[..pos], &content[pos + close_tag.len()..]),
+        None => (content, ""),
+    }
+}
+
+/// 读取标签中的属性值（属性名不区分大小写），值可以用双引号、单引号或不加引号
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let upper = tag.to_ascii_uppercase();
+    let mut from = 0;
+    while let Some(pos) = upper[from..].find(name) {
+        let start = from + pos;
+        from = start + name.len();
+        // 属性名前须为空白，避免匹配 DATA-HREF 之类的属性
+        if !tag[..start].ends_with(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = tag[from..].trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        return match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let value = &value[1..];
+                value.find(quote).map(|end| value[..end].to_string())
+            }
+            _ => value.split(char::is_whitespace).next().map(str::to_string),
+        };
+    }
+    None
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::Connection;
+    use std::path::PathBuf;
+
+    fn bookmark(folder: Option<&str>, title: &str, url: &str) -> (Option<String>, String, String) {
+        (
+            folder.map(str::to_string),
+            title.to_string(),
+            url.to_string(),
+        )
+    }
+
+    fn flatten(bookmarks: Vec<ImportedBookmark>) -> Vec<(Option<String>, String, String)> {
+        bookmarks
+            .into_iter()
+            .map(|b| (b.folder, b.title, b.url))
+            .collect()
+    }
+
+    #[test]
+    fn parses_netscape_html() {
+        let html = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+    <DT><H3 ADD_DATE="1" PERSONAL_TOOLBAR_FOLDER="true">Bookmarks bar</H3>
+    <DL><p>
+        <DT><A HREF="https://example.com/" ADD_DATE="1">Example</A>
+        <DT><H3>Work &amp; Docs</H3>
+        <DL><p>
+            <DT><A HREF='https://docs.example.com/?a=1&amp;b=2'>Docs</A>
+            <DT><a href=https://wiki.example.com/ icon="data:x">  </a>
+        </DL><p>
+        <DT><A DATA-HREF="https://wrong.example.com/" HREF="https://right.example.com/">Right</A>
+    </DL><p>
+    <DT><A HREF="https://top.example.com/">Top</A>
+</DL><p>
+"#;
+        assert_eq!(
+            flatten(parse_netscape_html(html)),
+            vec![
+                bookmark(None, "Example", "https://example.com/"),
+                bookmark(
+                    Some("Work & Docs"),
+                    "Docs",
+                    "https://docs.example.com/?a=1&b=2"
+                ),
+                bookmark(
+                    Some("Work & Docs"),
+                    "https://wiki.example.com/",
+                    "https://wiki.example.com/"
+                ),
+                bookmark(None, "Right", "https://right.example.com/"),
+                bookmark(None, "Top", "https://top.example.com/"),
+            ]
+        );
+    }
+
+    #[test]
+    fn attribute_values_may_be_quoted_or_bare() {
+        assert_eq!(attribute(r#"A HREF="x y""#, "HREF").as_deref(), Some("x y"));
+        assert_eq!(attribute("A href='x\"y'", "HREF").as_deref(), Some("x\"y"));
+        assert_eq!(
+            attribute("A HREF = x ADD_DATE=1", "HREF").as_deref(),
+            Some("x")
+        );
+        assert_eq!(attribute(r#"A DATA-HREF="x""#, "HREF"), None);
+        assert_eq!(attribute(r#"A HREF="x"#, "HREF"), None);
+    }
+
+    #[test]
+    fn parses_chrome_json() {
+        let json = "\u{feff}{
+  \"roots\": {
+    \"bookmark_bar\": {
+      \"type\": \"folder\",
+      \"name\": \"Bookmarks bar\",
+      \"children\": [
+        { \"type\": \"url\", \"name\": \"Example\", \"url\": \"https://example.com/\" },
+        {
+          \"type\": \"folder\",
+          \"name\": \"Work\",
+          \"children\": [{ \"type\": \"url\", \"name\": \"\", \"url\": \"https://docs.example.com/\" }]
+        }
+      ]
+    },
+    \"other\": {
+      \"type\": \"folder\",
+      \"name\": \"Other bookmarks\",
+      \"children\": [{ \"type\": \"url\", \"name\": \"Other\", \"url\": \"https://other.example.com/\" }]
+    },
+    \"synced\": { \"type\": \"folder\", \"name\": \"Mobile bookmarks\", \"children\": [] }
+  },
+  \"version\": 1
+}";
+        assert_eq!(
+            flatten(parse_chrome_json(json).unwrap()),
+            vec![
+                bookmark(None, "Example", "https://example.com/"),
+                bookmark(
+                    Some("Work"),
+                    "https://docs.example.com/",
+                    "https://docs.example.com/"
+                ),
+                bookmark(None, "Other", "https://other.example.com/"),
+            ]
+        );
+        assert!(parse_chrome_json("{\"version\": 1}").is_err());
+    }
+
+    #[tokio::test]
+    async fn reads_firefox_places() {
+        let path: PathBuf =
+            std::env::temp_dir().join(format!("nob-places-{}.sqlite", uuid::Uuid::new_v4()));
+        let mut conn = SqliteConnectOptions::new()
+            .filename(&path)
+            .create_if_missing(true)
+            .connect()
+            .await
+            .unwrap();
+        for statement in [
+            "CREATE TABLE moz_places (id INTEGER PRIMARY KEY, url TEXT)",
+            "CREATE TABLE moz_bookmarks (id INTEGER PRIMARY KEY, type INTEGER, fk INTEGER, \
+             parent INTEGER, position INTEGER, title TEXT, guid TEXT)",
+            "INSERT INTO moz_places VALUES (1, 'https://example.com/'), \
+             (2, 'https://docs.example.com/')",
+            "INSERT INTO moz_bookmarks VALUES \
+             (1, 2, NULL, 0, 0, '', 'root________'), \
+             (2, 2, NULL, 1, 0, 'toolbar', 'toolbar_____'), \
+             (3, 2, NULL, 2, 1, 'Work', 'abcdefghijkl'), \
+             (4, 1, 1, 2, 0, 'Example', 'bookmark0001'), \
+             (5, 1, 2, 3, 0, NULL, 'bookmark0002'), \
+             (6, 3, NULL, 2, 2, NULL, 'separator001')",
+        ] {
+            sqlx::query(statement).execute(&mut conn).await.unwrap();
+        }
+        conn.close().await.unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(detect_format(&bytes), Some(ImportFormat::FirefoxPlaces));
+        assert_eq!(
+            flatten(read_firefox_places(&path).await.unwrap()),
+            vec![
+                bookmark(None, "Example", "https://example.com/"),
+                bookmark(
+                    Some("Work"),
+                    "https://docs.example.com/",
+                    "https://docs.example.com/"
+                ),
+            ]
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn detects_format_from_content() {
+        assert_eq!(
+            detect_format(b"\xef\xbb\xbf { \"roots\": {} }"),
+            Some(ImportFormat::ChromeJson)
+        );
+        assert_eq!(
+            detect_format(b"<!DOCTYPE NETSCAPE-Bookmark-file-1>"),
+            Some(ImportFormat::NetscapeHtml)
+        );
+        assert_eq!(detect_format(b"just text"), None);
+    }
+}
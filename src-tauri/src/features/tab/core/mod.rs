@@ -1,2 +1,9 @@
+pub mod closed_service;
+pub mod favicon_service;
+pub mod group_service;
+pub mod history_service;
+pub mod importer;
 pub mod models;
 pub mod service;
+pub mod split_service;
+pub mod suspension;
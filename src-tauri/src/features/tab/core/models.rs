@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use crate::features::tab::data::entity;
+use crate::features::tab::core::importer::ImportFormat;
+use crate::features::tab::data::{
+    closed_entity, entity, group_entity, history_entity, split_entity,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tab {
     pub id: String,
+    pub group_id: String,
     pub title: String,
     pub url: String,
     pub initial_url: String,
@@ -12,15 +16,29 @@ pub struct Tab {
     pub is_pinned: bool,
     pub is_active: bool,
     pub sort_order: i32,
+    /// 内容窗口尺寸（逻辑像素），为空时使用默认尺寸
+    pub content_width: Option<f64>,
+    pub content_height: Option<f64>,
     pub last_opened_at: String,
     pub created_at: String,
     pub updated_at: String,
+    /// webview 已被挂起（销毁），激活时按 URL 重新加载
+    pub suspended: bool,
+    /// 已分离到独立窗口
+    pub detached: bool,
+}
+
+impl Tab {
+    /// 记录的内容窗口尺寸（宽、高），未记录时为 None
+    pub fn content_size(&self) -> Option<(f64, f64)> {
+        self.content_width.zip(self.content_height)
+    }
 }
 
 impl From<entity::Model> for Tab {
     fn from(model: entity::Model) -> Self {
         Self {
-            id: model.id,
+            group_id: model.group_id,
             title: model.title,
             url: model.url,
             initial_url: model.initial_url,
@@ -28,9 +46,67 @@ impl From<entity::Model> for Tab {
             is_pinned: model.is_pinned,
             is_active: model.is_active,
             sort_order: model.sort_order,
+            content_width: model.content_width,
+            content_height: model.content_height,
             last_opened_at: model.last_opened_at.to_rfc3339(),
             created_at: model.created_at.to_rfc3339(),
             updated_at: model.updated_at.to_rfc3339(),
+            // 窗口运行状态由 API 层填写
+            suspended: false,
+            detached: false,
+            id: model.id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TabHistoryEntry {
+    pub id: String,
+    pub tab_id: String,
+    pub position: i32,
+    pub url: String,
+    pub title: Option<String>,
+    pub is_current: bool,
+    pub created_at: String,
+}
+
+impl From<history_entity::Model> for TabHistoryEntry {
+    fn from(model: history_entity::Model) -> Self {
+        Self {
+            id: model.id,
+            tab_id: model.tab_id,
+            position: model.position,
+            url: model.url,
+            title: model.title,
+            is_current: model.is_current,
+            created_at: model.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosedTab {
+    pub id: String,
+    pub group_id: String,
+    pub title: String,
+    pub url: String,
+    pub favicon_url: Option<String>,
+    pub is_pinned: bool,
+    pub sort_order: i32,
+    pub closed_at: String,
+}
+
+impl From<closed_entity::Model> for ClosedTab {
+    fn from(model: closed_entity::Model) -> Self {
+        Self {
+            id: model.id,
+            group_id: model.group_id,
+            title: model.title,
+            url: model.url,
+            favicon_url: model.favicon_url,
+            is_pinned: model.is_pinned,
+            sort_order: model.sort_order,
+            closed_at: model.closed_at.to_rfc3339(),
         }
     }
 }
@@ -58,3 +134,159 @@ pub struct UpdateTabPayload {
 pub struct ReorderTabsPayload {
     pub ordered_ids: Vec<String>,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportTabsPayload {
+    /// 本地书签文件路径
+    pub path: String,
+    /// 未指定时根据文件内容识别
+    pub format: Option<ImportFormat>,
+    /// 是否将书签文件夹导入为分组，默认 true；否则全部导入当前分组
+    pub folders_as_groups: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportTabsResult {
+    pub created: u32,
+    /// URL 已存在或不是网页链接
+    pub skipped: u32,
+    pub failed: u32,
+    pub groups_created: u32,
+    /// 失败条目的原因
+    pub errors: Vec<String>,
+}
+
+/// 内容页上报的候选图标（`<link rel=icon>` 或 manifest icons）
+#[derive(Debug, Clone, Deserialize)]
+pub struct FaviconCandidate {
+    pub url: String,
+    /// 形如 "16x16 32x32" 或 "any"
+    pub sizes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TabGroup {
+    pub id: String,
+    pub name: String,
+    pub is_active: bool,
+    pub sort_order: i32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<group_entity::Model> for TabGroup {
+    fn from(model: group_entity::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            is_active: model.is_active,
+            sort_order: model.sort_order,
+            created_at: model.created_at.to_rfc3339(),
+            updated_at: model.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTabGroupPayload {
+    pub name: String,
+    /// 是否在创建时切换到该分组，默认 true。
+    pub activate: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateTabGroupPayload {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReorderTabGroupsPayload {
+    pub ordered_ids: Vec<String>,
+}
+
+/// 分屏比例的范围，避免某一侧被拖到不可用
+const MIN_RATIO: f64 = 0.15;
+const MAX_RATIO: f64 = 0.85;
+pub const DEFAULT_RATIO: f64 = 0.5;
+
+/// 限制分屏比例，非法值使用默认比例
+pub fn clamp_ratio(ratio: f64) -> f64 {
+    if ratio.is_finite() {
+        ratio.clamp(MIN_RATIO, MAX_RATIO)
+    } else {
+        DEFAULT_RATIO
+    }
+}
+
+/// 分屏方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitOrientation {
+    /// 左右并排
+    #[default]
+    Horizontal,
+    /// 上下并排
+    Vertical,
+}
+
+impl SplitOrientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+
+    /// 从数据库中的值解析，未知值按水平处理
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "vertical" => Self::Vertical,
+            _ => Self::Horizontal,
+        }
+    }
+}
+
+/// 分屏组合 (用于 API 响应)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TabSplit {
+    pub id: String,
+    pub primary_tab_id: String,
+    pub secondary_tab_id: String,
+    pub orientation: SplitOrientation,
+    /// primary 所占的比例
+    pub ratio: f64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<split_entity::Model> for TabSplit {
+    fn from(model: split_entity::Model) -> Self {
+        Self {
+            id: model.id,
+            primary_tab_id: model.primary_tab_id,
+            secondary_tab_id: model.secondary_tab_id,
+            orientation: SplitOrientation::parse(&model.orientation),
+            ratio: model.ratio,
+            created_at: model.created_at.to_rfc3339(),
+            updated_at: model.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTabSplitPayload {
+    pub primary_tab_id: String,
+    pub secondary_tab_id: String,
+    /// 默认左右并排
+    pub orientation: Option<SplitOrientation>,
+    /// 默认各占一半
+    pub ratio: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateTabSplitPayload {
+    pub id: String,
+    pub orientation: Option<SplitOrientation>,
+    pub ratio: Option<f64>,
+}
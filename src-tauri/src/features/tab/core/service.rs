@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use anyhow::Result;
 use chrono::Utc;
 use sea_orm::sea_query::Expr;
@@ -5,16 +7,29 @@ use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction,
     EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
 };
+use url::Url;
 use uuid::Uuid;
 
-use crate::features::tab::core::models::{CreateTabPayload, ReorderTabsPayload, UpdateTabPayload};
+use crate::features::tab::core::closed_service::ClosedTabService;
+use crate::features::tab::core::group_service::TabGroupService;
+use crate::features::tab::core::history_service::TabHistoryService;
+use crate::features::tab::core::importer::ImportedBookmark;
+use crate::features::tab::core::split_service::TabSplitService;
+use crate::features::tab::core::models::{
+    CreateTabPayload, ImportTabsResult, ReorderTabsPayload, UpdateTabPayload,
+};
 use crate::features::tab::data::entity::{self, Entity as TabEntity, Model};
 
+/// 标签页服务
+///
+/// 所有查询和激活状态都限定在当前分组（见 TabGroupService）内。
 pub struct TabService;
 
 impl TabService {
     pub async fn list(db: &DatabaseConnection) -> Result<Vec<Model>> {
+        let group_id = TabGroupService::current_id(db).await?;
         let tabs = TabEntity::find()
+            .filter(entity::Column::GroupId.eq(group_id))
             .order_by_desc(entity::Column::IsPinned)
             .order_by_asc(entity::Column::SortOrder)
             .order_by_desc(entity::Column::LastOpenedAt)
@@ -27,15 +42,17 @@ impl TabService {
     pub async fn create(db: &DatabaseConnection, payload: CreateTabPayload) -> Result<Model> {
         let txn = db.begin().await?;
         let now = Utc::now();
-        let next_order = Self::next_sort_order(&txn).await?;
+        let group_id = TabGroupService::current_id(&txn).await?;
+        let next_order = Self::next_sort_order(&txn, &group_id).await?;
         let should_activate = payload.activate.unwrap_or(true);
 
         if should_activate {
-            Self::deactivate_all(&txn).await?;
+            Self::deactivate_all(&txn, &group_id).await?;
         }
 
         let active_model = entity::ActiveModel {
             id: Set(Uuid::new_v4().to_string()),
+            group_id: Set(group_id),
             title: Set(payload.title.unwrap_or_else(|| payload.url.clone())),
             url: Set(payload.url.clone()),
             initial_url: Set(payload.url),
@@ -43,25 +60,30 @@ impl TabService {
             is_pinned: Set(payload.is_pinned.unwrap_or(false)),
             is_active: Set(should_activate),
             sort_order: Set(next_order),
+            content_width: Set(None),
+            content_height: Set(None),
             last_opened_at: Set(now),
             created_at: Set(now),
             updated_at: Set(now),
         };
 
         let created = active_model.insert(&txn).await?;
+        TabHistoryService::record(&txn, &created.id, &created.url).await?;
         txn.commit().await?;
 
         Ok(created)
     }
 
     pub async fn update(db: &DatabaseConnection, payload: UpdateTabPayload) -> Result<Option<Model>> {
-        let existing = TabEntity::find_by_id(payload.id.clone()).one(db).await?;
+        let txn = db.begin().await?;
+        let existing = TabEntity::find_by_id(payload.id.clone()).one(&txn).await?;
         if let Some(model) = existing {
             let mut active_model: entity::ActiveModel = model.into();
             if let Some(title) = payload.title {
                 active_model.title = Set(title);
             }
             if let Some(url) = payload.url {
+                TabHistoryService::record(&txn, &payload.id, &url).await?;
                 active_model.url = Set(url);
             }
             if let Some(favicon_url) = payload.favicon_url {
@@ -72,9 +94,11 @@ impl TabService {
             }
             active_model.updated_at = Set(Utc::now());
 
-            let updated = active_model.update(db).await?;
+            let updated = active_model.update(&txn).await?;
+            txn.commit().await?;
             Ok(Some(updated))
         } else {
+            txn.rollback().await.ok();
             Ok(None)
         }
     }
@@ -84,7 +108,7 @@ impl TabService {
         let tab = TabEntity::find_by_id(id.to_string()).one(&txn).await?;
 
         if let Some(model) = tab {
-            Self::deactivate_all(&txn).await?;
+            Self::deactivate_all(&txn, &model.group_id).await?;
 
             let mut active_model: entity::ActiveModel = model.into();
             active_model.is_active = Set(true);
@@ -100,15 +124,14 @@ impl TabService {
         }
     }
 
-    pub async fn update_active_url(db: &DatabaseConnection, url: String) -> Result<Option<Model>> {
+    /// 更新标签页当前 URL（内容窗口上报导航时调用）
+    pub async fn update_url(db: &DatabaseConnection, id: &str, url: String) -> Result<Option<Model>> {
         let txn = db.begin().await?;
-        // Find the currently active tab
-        let active_tab = TabEntity::find()
-            .filter(entity::Column::IsActive.eq(true))
-            .one(&txn)
-            .await?;
+        let tab = TabEntity::find_by_id(id.to_string()).one(&txn).await?;
+
+        if let Some(model) = tab {
+            TabHistoryService::record(&txn, &model.id, &url).await?;
 
-        if let Some(model) = active_tab {
             let mut active_model: entity::ActiveModel = model.into();
             active_model.url = Set(url);
             active_model.updated_at = Set(Utc::now());
@@ -122,15 +145,14 @@ impl TabService {
         }
     }
 
-    pub async fn update_active_title(db: &DatabaseConnection, title: String) -> Result<Option<Model>> {
+    /// 更新标签页标题（内容窗口上报标题时调用）
+    pub async fn update_title(db: &DatabaseConnection, id: &str, title: String) -> Result<Option<Model>> {
         let txn = db.begin().await?;
-        // Find the currently active tab
-        let active_tab = TabEntity::find()
-            .filter(entity::Column::IsActive.eq(true))
-            .one(&txn)
-            .await?;
+        let tab = TabEntity::find_by_id(id.to_string()).one(&txn).await?;
+
+        if let Some(model) = tab {
+            TabHistoryService::update_current_title(&txn, &model.id, &title).await?;
 
-        if let Some(model) = active_tab {
             let mut active_model: entity::ActiveModel = model.into();
             active_model.title = Set(title);
             active_model.updated_at = Set(Utc::now());
@@ -144,35 +166,252 @@ impl TabService {
         }
     }
 
-    /// 关闭指定标签，返回是否激活了新的标签
-    pub async fn close(
+    /// 更新标签的图标 URL（图标本身由 FaviconService 缓存），未变化时返回 None
+    pub async fn update_favicon(db: &DatabaseConnection, id: &str, favicon_url: &str) -> Result<Option<Model>> {
+        let tab = TabEntity::find_by_id(id.to_string()).one(db).await?;
+
+        match tab {
+            Some(model) if model.favicon_url.as_deref() != Some(favicon_url) => {
+                let mut active_model: entity::ActiveModel = model.into();
+                active_model.favicon_url = Set(Some(favicon_url.to_string()));
+                active_model.updated_at = Set(Utc::now());
+
+                let updated = active_model.update(db).await?;
+                Ok(Some(updated))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// 更新标签的内容窗口尺寸，size 为 None 时恢复默认尺寸
+    pub async fn update_content_size(
         db: &DatabaseConnection,
         id: &str,
+        size: Option<(f64, f64)>,
     ) -> Result<Option<Model>> {
+        let Some(model) = TabEntity::find_by_id(id.to_string()).one(db).await? else {
+            return Ok(None);
+        };
+
+        let mut active_model: entity::ActiveModel = model.into();
+        active_model.content_width = Set(size.map(|(width, _)| width));
+        active_model.content_height = Set(size.map(|(_, height)| height));
+        active_model.updated_at = Set(Utc::now());
+
+        Ok(Some(active_model.update(db).await?))
+    }
+
+    /// 在当前激活标签的导航历史中后退（forward = false）或前进一步
+    ///
+    /// 返回 URL 已更新的标签；没有可用的历史条目时返回 None
+    pub async fn navigate_history(db: &DatabaseConnection, forward: bool) -> Result<Option<Model>> {
+        let txn = db.begin().await?;
+
+        let Some(model) = Self::find_active(&txn).await? else {
+            txn.rollback().await.ok();
+            return Ok(None);
+        };
+
+        let Some(entry) = TabHistoryService::step(&txn, &model.id, forward).await? else {
+            txn.rollback().await.ok();
+            return Ok(None);
+        };
+
+        let mut active_model: entity::ActiveModel = model.into();
+        active_model.url = Set(entry.url);
+        if let Some(title) = entry.title {
+            active_model.title = Set(title);
+        }
+        active_model.updated_at = Set(Utc::now());
+
+        let updated = active_model.update(&txn).await?;
+        txn.commit().await?;
+        Ok(Some(updated))
+    }
+
+    /// 关闭指定标签
+    ///
+    /// 返回因此被激活的新标签，以及从最近关闭列表中淘汰的标签 id（用于清理其窗口记录）
+    pub async fn close(
+        db: &DatabaseConnection,
+        id: &str,
+    ) -> Result<(Option<Model>, Vec<String>)> {
         let txn = db.begin().await?;
         let tab = TabEntity::find_by_id(id.to_string()).one(&txn).await?;
 
         if let Some(model) = tab {
             let was_active = model.is_active;
 
+            // 放入最近关闭列表（导航历史保留，随关闭记录一起淘汰）
+            let expired = ClosedTabService::push(&txn, &model).await?;
+            TabSplitService::delete_for_tabs(&txn, vec![model.id.clone()]).await?;
             TabEntity::delete_by_id(id.to_string())
                 .exec(&txn)
                 .await?;
 
             let activated = if was_active {
-                Self::activate_next_available(&txn).await?
+                Self::activate_next_available(&txn, &model.group_id).await?
             } else {
                 None
             };
 
             txn.commit().await?;
-            Ok(activated)
+            Ok((activated, expired))
         } else {
             txn.rollback().await.ok();
-            Ok(None)
+            Ok((None, vec![]))
         }
     }
 
+    /// 重新打开最近关闭的标签页（未指定 id 时为最近关闭的一个）
+    ///
+    /// 原分组仍存在时放回原分组并切换过去，否则放入当前分组；
+    /// 标签恢复到关闭前的排序位置并被激活
+    pub async fn reopen_closed(db: &DatabaseConnection, id: Option<&str>) -> Result<Option<Model>> {
+        let txn = db.begin().await?;
+
+        let Some(closed) = ClosedTabService::take(&txn, id).await? else {
+            txn.rollback().await.ok();
+            return Ok(None);
+        };
+
+        let group_id = if TabGroupService::switch_to(&txn, &closed.group_id).await? {
+            closed.group_id
+        } else {
+            TabGroupService::current_id(&txn).await?
+        };
+
+        // 为原位置腾出空间
+        TabEntity::update_many()
+            .col_expr(
+                entity::Column::SortOrder,
+                Expr::col(entity::Column::SortOrder).add(1),
+            )
+            .filter(entity::Column::GroupId.eq(group_id.clone()))
+            .filter(entity::Column::SortOrder.gte(closed.sort_order))
+            .exec(&txn)
+            .await?;
+
+        Self::deactivate_all(&txn, &group_id).await?;
+
+        let now = Utc::now();
+        let active_model = entity::ActiveModel {
+            id: Set(closed.id),
+            group_id: Set(group_id),
+            title: Set(closed.title),
+            url: Set(closed.url),
+            initial_url: Set(closed.initial_url),
+            favicon_url: Set(closed.favicon_url),
+            is_pinned: Set(closed.is_pinned),
+            is_active: Set(true),
+            sort_order: Set(closed.sort_order),
+            content_width: Set(closed.content_width),
+            content_height: Set(closed.content_height),
+            last_opened_at: Set(now),
+            created_at: Set(closed.created_at),
+            updated_at: Set(now),
+        };
+
+        let restored = active_model.insert(&txn).await?;
+        txn.commit().await?;
+
+        Ok(Some(restored))
+    }
+
+    /// 在一个事务中批量导入书签
+    ///
+    /// 与已有标签（所有分组）或本次导入中重复的 URL 会被跳过；
+    /// folders_as_groups 为 true 时按文件夹名导入到对应分组（不存在则创建），否则导入当前分组
+    pub async fn import(
+        db: &DatabaseConnection,
+        bookmarks: Vec<ImportedBookmark>,
+        folders_as_groups: bool,
+    ) -> Result<ImportTabsResult> {
+        let txn = db.begin().await?;
+        let now = Utc::now();
+        let current_group = TabGroupService::current_id(&txn).await?;
+        let mut result = ImportTabsResult::default();
+
+        let mut known_urls: HashSet<String> = TabEntity::find()
+            .all(&txn)
+            .await?
+            .into_iter()
+            // 与导入的 URL 采用相同的规范化形式比较
+            .map(|t| Url::parse(&t.url).map(|u| u.to_string()).unwrap_or(t.url))
+            .collect();
+        let mut group_ids: HashMap<String, String> = HashMap::new();
+
+        for bookmark in bookmarks {
+            let url = match Url::parse(bookmark.url.trim()) {
+                Ok(url) if matches!(url.scheme(), "http" | "https") => url.to_string(),
+                // javascript:、place: 等非网页书签
+                Ok(_) => {
+                    result.skipped += 1;
+                    continue;
+                }
+                Err(e) => {
+                    result.failed += 1;
+                    result.errors.push(format!("{}: {}", bookmark.url, e));
+                    continue;
+                }
+            };
+
+            if !known_urls.insert(url.clone()) {
+                result.skipped += 1;
+                continue;
+            }
+
+            let group_id = match bookmark.folder.filter(|_| folders_as_groups) {
+                Some(folder) => match group_ids.get(&folder) {
+                    Some(id) => id.clone(),
+                    None => {
+                        let (id, created) = TabGroupService::find_or_create(&txn, &folder).await?;
+                        if created {
+                            result.groups_created += 1;
+                        }
+                        group_ids.insert(folder, id.clone());
+                        id
+                    }
+                },
+                None => current_group.clone(),
+            };
+            let next_order = Self::next_sort_order(&txn, &group_id).await?;
+
+            let inserted = entity::ActiveModel {
+                id: Set(Uuid::new_v4().to_string()),
+                group_id: Set(group_id),
+                title: Set(bookmark.title),
+                url: Set(url.clone()),
+                initial_url: Set(url.clone()),
+                favicon_url: Set(None),
+                is_pinned: Set(false),
+                is_active: Set(false),
+                sort_order: Set(next_order),
+                content_width: Set(None),
+                content_height: Set(None),
+                last_opened_at: Set(now),
+                created_at: Set(now),
+                updated_at: Set(now),
+            }
+            .insert(&txn)
+            .await;
+
+            match inserted {
+                Ok(created) => {
+                    TabHistoryService::record(&txn, &created.id, &created.url).await?;
+                    result.created += 1;
+                }
+                Err(e) => {
+                    result.failed += 1;
+                    result.errors.push(format!("{}: {}", url, e));
+                }
+            }
+        }
+
+        txn.commit().await?;
+        Ok(result)
+    }
+
     pub async fn reorder(db: &DatabaseConnection, payload: ReorderTabsPayload) -> Result<()> {
         let txn = db.begin().await?;
 
@@ -196,34 +435,50 @@ impl TabService {
         Self::activate_adjacent(db, false).await
     }
 
-    pub async fn close_active(db: &DatabaseConnection) -> Result<Option<Model>> {
-        if let Some(active) = TabEntity::find()
-            .filter(entity::Column::IsActive.eq(true))
-            .one(db)
-            .await?
-        {
-            Self::close(db, &active.id).await
-        } else {
-            Ok(None)
-        }
-    }
-
     pub async fn get(db: &DatabaseConnection, id: &str) -> Result<Option<Model>> {
         let tab = TabEntity::find_by_id(id.to_string()).one(db).await?;
         Ok(tab)
     }
 
     pub async fn get_active(db: &DatabaseConnection) -> Result<Option<Model>> {
+        Self::find_active(db).await
+    }
+
+    /// 恢复当前分组的激活标签（切换分组后调用）
+    ///
+    /// 分组内没有激活标签时，激活排序第一的标签
+    pub async fn restore_active(db: &DatabaseConnection) -> Result<Option<Model>> {
+        let txn = db.begin().await?;
+
+        if let Some(active) = Self::find_active(&txn).await? {
+            txn.rollback().await.ok();
+            return Ok(Some(active));
+        }
+
+        let group_id = TabGroupService::current_id(&txn).await?;
+        let activated = Self::activate_next_available(&txn, &group_id).await?;
+        txn.commit().await?;
+        Ok(activated)
+    }
+
+    async fn find_active<C>(conn: &C) -> Result<Option<Model>>
+    where
+        C: ConnectionTrait,
+    {
+        let group_id = TabGroupService::current_id(conn).await?;
         let tab = TabEntity::find()
+            .filter(entity::Column::GroupId.eq(group_id))
             .filter(entity::Column::IsActive.eq(true))
-            .one(db)
+            .one(conn)
             .await?;
         Ok(tab)
     }
 
     async fn activate_adjacent(db: &DatabaseConnection, forward: bool) -> Result<Option<Model>> {
         let txn = db.begin().await?;
+        let group_id = TabGroupService::current_id(&txn).await?;
         let tabs = TabEntity::find()
+            .filter(entity::Column::GroupId.eq(group_id.clone()))
             .order_by_desc(entity::Column::IsPinned)
             .order_by_asc(entity::Column::SortOrder)
             .order_by_desc(entity::Column::LastOpenedAt)
@@ -252,7 +507,7 @@ impl TabService {
         let target = tabs.get(next_idx).cloned();
 
         if let Some(target_tab) = target {
-            Self::deactivate_all(&txn).await?;
+            Self::deactivate_all(&txn, &group_id).await?;
 
             let mut active_model: entity::ActiveModel = target_tab.into();
             active_model.is_active = Set(true);
@@ -268,22 +523,24 @@ impl TabService {
         }
     }
 
-    async fn deactivate_all<C>(conn: &C) -> Result<()>
+    async fn deactivate_all<C>(conn: &C, group_id: &str) -> Result<()>
     where
         C: ConnectionTrait,
     {
         TabEntity::update_many()
             .col_expr(entity::Column::IsActive, Expr::value(false))
+            .filter(entity::Column::GroupId.eq(group_id))
             .exec(conn)
             .await?;
         Ok(())
     }
 
-    async fn next_sort_order<C>(conn: &C) -> Result<i32>
+    async fn next_sort_order<C>(conn: &C, group_id: &str) -> Result<i32>
     where
         C: ConnectionTrait,
     {
         let last = TabEntity::find()
+            .filter(entity::Column::GroupId.eq(group_id))
             .order_by_desc(entity::Column::SortOrder)
             .one(conn)
             .await?;
@@ -291,8 +548,12 @@ impl TabService {
         Ok(last.map(|t| t.sort_order + 1).unwrap_or(0))
     }
 
-    async fn activate_next_available(txn: &DatabaseTransaction) -> Result<Option<Model>> {
+    async fn activate_next_available(
+        txn: &DatabaseTransaction,
+        group_id: &str,
+    ) -> Result<Option<Model>> {
         if let Some(next) = TabEntity::find()
+            .filter(entity::Column::GroupId.eq(group_id))
             .order_by_desc(entity::Column::IsPinned)
             .order_by_asc(entity::Column::SortOrder)
             .order_by_desc(entity::Column::LastOpenedAt)
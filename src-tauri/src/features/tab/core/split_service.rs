@@ -0,0 +1,137 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use sea_orm::sea_query::Condition;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+use crate::features::tab::core::models::{clamp_ratio, SplitOrientation, DEFAULT_RATIO};
+use crate::features::tab::data::entity::Entity as TabEntity;
+use crate::features::tab::data::split_entity::{self, Entity as TabSplitEntity, Model};
+
+/// 分屏组合服务
+///
+/// 一个标签最多属于一个组合，创建新组合时会解除两个标签原有的组合
+pub struct TabSplitService;
+
+impl TabSplitService {
+    pub async fn list(db: &DatabaseConnection) -> Result<Vec<Model>> {
+        let splits = TabSplitEntity::find()
+            .order_by_asc(split_entity::Column::CreatedAt)
+            .all(db)
+            .await?;
+        Ok(splits)
+    }
+
+    /// 标签所属的组合
+    pub async fn find_for_tab<C>(conn: &C, tab_id: &str) -> Result<Option<Model>>
+    where
+        C: ConnectionTrait,
+    {
+        let split = TabSplitEntity::find()
+            .filter(involving(vec![tab_id.to_string()]))
+            .one(conn)
+            .await?;
+        Ok(split)
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        primary_tab_id: &str,
+        secondary_tab_id: &str,
+        orientation: Option<SplitOrientation>,
+        ratio: Option<f64>,
+    ) -> Result<Model> {
+        if primary_tab_id == secondary_tab_id {
+            return Err(anyhow!("cannot split a tab with itself"));
+        }
+
+        let txn = db.begin().await?;
+        for tab_id in [primary_tab_id, secondary_tab_id] {
+            if TabEntity::find_by_id(tab_id.to_string())
+                .one(&txn)
+                .await?
+                .is_none()
+            {
+                txn.rollback().await.ok();
+                return Err(anyhow!("tab {} not found", tab_id));
+            }
+        }
+
+        Self::delete_for_tabs(
+            &txn,
+            vec![primary_tab_id.to_string(), secondary_tab_id.to_string()],
+        )
+        .await?;
+
+        let now = Utc::now();
+        let model = split_entity::ActiveModel {
+            id: Set(Uuid::new_v4().to_string()),
+            primary_tab_id: Set(primary_tab_id.to_string()),
+            secondary_tab_id: Set(secondary_tab_id.to_string()),
+            orientation: Set(orientation.unwrap_or_default().as_str().to_string()),
+            ratio: Set(clamp_ratio(ratio.unwrap_or(DEFAULT_RATIO))),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+        Ok(model)
+    }
+
+    /// 修改方向或比例
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: &str,
+        orientation: Option<SplitOrientation>,
+        ratio: Option<f64>,
+    ) -> Result<Option<Model>> {
+        let Some(split) = TabSplitEntity::find_by_id(id.to_string()).one(db).await? else {
+            return Ok(None);
+        };
+
+        let mut active_model: split_entity::ActiveModel = split.into();
+        if let Some(orientation) = orientation {
+            active_model.orientation = Set(orientation.as_str().to_string());
+        }
+        if let Some(ratio) = ratio {
+            active_model.ratio = Set(clamp_ratio(ratio));
+        }
+        active_model.updated_at = Set(Utc::now());
+
+        Ok(Some(active_model.update(db).await?))
+    }
+
+    /// 解除组合，返回被删除的组合
+    pub async fn delete(db: &DatabaseConnection, id: &str) -> Result<Option<Model>> {
+        let Some(split) = TabSplitEntity::find_by_id(id.to_string()).one(db).await? else {
+            return Ok(None);
+        };
+        TabSplitEntity::delete_by_id(id.to_string())
+            .exec(db)
+            .await?;
+        Ok(Some(split))
+    }
+
+    /// 删除包含指定标签的组合（关闭标签、删除分组时调用）
+    pub async fn delete_for_tabs<C>(conn: &C, tab_ids: Vec<String>) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        TabSplitEntity::delete_many()
+            .filter(involving(tab_ids))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+}
+
+fn involving(tab_ids: Vec<String>) -> Condition {
+    Condition::any()
+        .add(split_entity::Column::PrimaryTabId.is_in(tab_ids.clone()))
+        .add(split_entity::Column::SecondaryTabId.is_in(tab_ids))
+}
@@ -0,0 +1,165 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+
+use crate::features::settings::core::service::SettingService;
+use crate::features::window::manager::LiveWebview;
+
+/// 空闲多少分钟后挂起后台标签（0 表示不按空闲时间挂起）
+pub const IDLE_MINUTES_KEY: &str = "tab.suspend.idle_minutes";
+/// 同时存活的内容 webview 上限
+pub const MAX_LIVE_WEBVIEWS_KEY: &str = "tab.suspend.max_live_webviews";
+
+const DEFAULT_IDLE_MINUTES: u64 = 30;
+const DEFAULT_MAX_LIVE_WEBVIEWS: usize = 8;
+/// 空闲时间上限（一周），更长的值与不按空闲时间挂起没有区别
+const MAX_IDLE_MINUTES: u64 = 7 * 24 * 60;
+
+/// 后台标签挂起策略
+///
+/// 挂起即销毁标签的 webview，URL 和标题保留在数据库中，激活时重新创建
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspensionPolicy {
+    pub idle_minutes: u64,
+    pub max_live_webviews: usize,
+}
+
+impl Default for SuspensionPolicy {
+    fn default() -> Self {
+        Self {
+            idle_minutes: DEFAULT_IDLE_MINUTES,
+            max_live_webviews: DEFAULT_MAX_LIVE_WEBVIEWS,
+        }
+    }
+}
+
+impl SuspensionPolicy {
+    /// 从设置中读取策略，缺失或非法的值使用默认值
+    pub async fn load(db: &DatabaseConnection) -> Result<Self> {
+        let idle_minutes = SettingService::get(db, IDLE_MINUTES_KEY)
+            .await?
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_IDLE_MINUTES);
+        let max_live_webviews = SettingService::get(db, MAX_LIVE_WEBVIEWS_KEY)
+            .await?
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_LIVE_WEBVIEWS);
+
+        Ok(Self {
+            idle_minutes,
+            max_live_webviews,
+        }
+        .normalized())
+    }
+
+    /// 保存策略到设置
+    pub async fn save(&self, db: &DatabaseConnection) -> Result<Self> {
+        let policy = self.normalized();
+        SettingService::set(db, IDLE_MINUTES_KEY, &policy.idle_minutes.to_string()).await?;
+        SettingService::set(
+            db,
+            MAX_LIVE_WEBVIEWS_KEY,
+            &policy.max_live_webviews.to_string(),
+        )
+        .await?;
+        Ok(policy)
+    }
+
+    /// 至少保留一个存活的 webview（当前激活的标签），空闲时间不超过上限
+    fn normalized(self) -> Self {
+        Self {
+            idle_minutes: self.idle_minutes.min(MAX_IDLE_MINUTES),
+            max_live_webviews: self.max_live_webviews.max(1),
+        }
+    }
+
+    /// 选出需要挂起的标签
+    ///
+    /// live 按最近使用排序（最近的在末尾），激活的标签永远不会被挂起
+    pub fn select(&self, live: &[LiveWebview], active: Option<&str>, now: Instant) -> Vec<String> {
+        let candidates: Vec<&LiveWebview> = live
+            .iter()
+            .filter(|w| Some(w.tab_id.as_str()) != active)
+            .collect();
+
+        let mut selected: Vec<String> = Vec::new();
+
+        if self.idle_minutes > 0 {
+            let idle = Duration::from_secs(self.idle_minutes.saturating_mul(60));
+            selected.extend(
+                candidates
+                    .iter()
+                    .filter(|w| now.saturating_duration_since(w.last_active) >= idle)
+                    .map(|w| w.tab_id.clone()),
+            );
+        }
+
+        let mut remaining = live.len() - selected.len();
+        for w in &candidates {
+            if remaining <= self.max_live_webviews {
+                break;
+            }
+            if !selected.contains(&w.tab_id) {
+                selected.push(w.tab_id.clone());
+                remaining -= 1;
+            }
+        }
+
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(idle_minutes: u64, max_live_webviews: usize) -> SuspensionPolicy {
+        SuspensionPolicy {
+            idle_minutes,
+            max_live_webviews,
+        }
+    }
+
+    /// 按最近使用排序的存活 webview，参数为各自空闲的分钟数
+    fn live(now: Instant, idle: &[(&str, u64)]) -> Vec<LiveWebview> {
+        idle.iter()
+            .map(|(tab_id, minutes)| LiveWebview {
+                tab_id: tab_id.to_string(),
+                last_active: now - Duration::from_secs(minutes * 60),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn idle_tabs_are_suspended() {
+        let now = Instant::now() + Duration::from_secs(3600);
+        let live = live(now, &[("a", 45), ("b", 30), ("c", 5)]);
+        assert_eq!(policy(30, 8).select(&live, Some("c"), now), vec!["a", "b"]);
+        // 0 表示不按空闲时间挂起
+        assert!(policy(0, 8).select(&live, Some("c"), now).is_empty());
+    }
+
+    #[test]
+    fn least_recently_used_tabs_are_suspended_over_the_limit() {
+        let now = Instant::now() + Duration::from_secs(3600);
+        let live = live(now, &[("a", 4), ("b", 3), ("c", 2), ("d", 1)]);
+        assert_eq!(policy(0, 2).select(&live, Some("d"), now), vec!["a", "b"]);
+        // 激活的标签即使最久未用也保留
+        assert_eq!(policy(0, 2).select(&live, Some("a"), now), vec!["b", "c"]);
+        // 已因空闲挂起的标签计入上限
+        assert_eq!(policy(3, 2).select(&live, Some("d"), now), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn huge_idle_minutes_do_not_overflow() {
+        let now = Instant::now() + Duration::from_secs(3600);
+        let live = live(now, &[("a", 45), ("b", 0)]);
+        assert!(policy(u64::MAX, 8).select(&live, Some("b"), now).is_empty());
+        assert_eq!(
+            policy(u64::MAX, 0).normalized(),
+            policy(MAX_IDLE_MINUTES, 1)
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigrationTrait;
+
+use super::closed_entity;
+use crate::infrastructure::database::schema;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClosedTabContentSizeMigration;
+
+impl MigrationName for ClosedTabContentSizeMigration {
+    fn name(&self) -> &str {
+        "m20240101_000012_add_closed_tab_content_size"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for ClosedTabContentSizeMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // 新建的数据库由 closed_tabs 表的 Migration 按实体创建，已包含这些列
+        let columns = [
+            ("content_width", closed_entity::Column::ContentWidth),
+            ("content_height", closed_entity::Column::ContentHeight),
+        ];
+        for (name, column) in columns {
+            if !schema::has_column(db, "closed_tabs", name).await? {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(closed_entity::Entity)
+                            .add_column(ColumnDef::new(column).double().null())
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [closed_entity::Column::ContentWidth, closed_entity::Column::ContentHeight] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(closed_entity::Entity)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+
+/// 最近关闭的标签页
+///
+/// id 沿用原标签页的 id，重新打开时原样恢复（导航历史随之保留）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "closed_tabs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub group_id: String,
+    pub title: String,
+    pub url: String,
+    pub initial_url: String,
+    pub favicon_url: Option<String>,
+    pub is_pinned: bool,
+    pub sort_order: i32,
+    /// 关闭前记录的内容窗口尺寸，重新打开时恢复
+    pub content_width: Option<f64>,
+    pub content_height: Option<f64>,
+    pub created_at: DateTimeUtc,
+    pub closed_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unreachable!("closed_tabs has no relations")
+    }
+}
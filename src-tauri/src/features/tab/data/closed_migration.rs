@@ -0,0 +1,38 @@
+use sea_orm::{ConnectionTrait, Schema};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigrationTrait;
+
+use super::closed_entity;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClosedTabMigration;
+
+impl MigrationName for ClosedTabMigration {
+    fn name(&self) -> &str {
+        "m20240101_000006_create_closed_tabs_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for ClosedTabMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_closed = schema.create_table_from_entity(closed_entity::Entity);
+        create_closed.if_not_exists();
+
+        db.execute(backend.build(&create_closed))
+            .await
+            .map_err(|e| DbErr::Custom(format!("failed to create closed_tabs table: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(closed_entity::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigrationTrait;
+
+use super::entity;
+use crate::infrastructure::database::schema;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TabContentSizeMigration;
+
+impl MigrationName for TabContentSizeMigration {
+    fn name(&self) -> &str {
+        "m20240101_000008_add_tab_content_size"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for TabContentSizeMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // 新建的数据库由 tabs 表的 Migration 按实体创建，已包含这些列
+        let columns = [
+            ("content_width", entity::Column::ContentWidth),
+            ("content_height", entity::Column::ContentHeight),
+        ];
+        for (name, column) in columns {
+            if !schema::has_column(db, "tabs", name).await? {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(entity::Entity)
+                            .add_column(ColumnDef::new(column).double().null())
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [entity::Column::ContentWidth, entity::Column::ContentHeight] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(entity::Entity)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
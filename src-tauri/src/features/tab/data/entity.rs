@@ -5,6 +5,7 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
+    pub group_id: String,
     pub title: String,
     pub url: String,
     pub initial_url: String,
@@ -12,6 +13,9 @@ pub struct Model {
     pub is_pinned: bool,
     pub is_active: bool,
     pub sort_order: i32,
+    /// 内容窗口尺寸（逻辑像素），为空时使用默认尺寸
+    pub content_width: Option<f64>,
+    pub content_height: Option<f64>,
     pub last_opened_at: DateTimeUtc,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
@@ -0,0 +1,27 @@
+use sea_orm::entity::prelude::*;
+
+/// 缓存的网站图标
+///
+/// 以图标 URL 为主键，标签页的 favicon_url 指向这里的记录，
+/// 通过 `favicon://` 协议提供给前端，离线和启动时无需重新下载
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "favicons")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub url: String,
+    pub mime_type: String,
+    #[sea_orm(column_type = "Blob")]
+    pub data: Vec<u8>,
+    pub fetched_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unreachable!("favicons has no relations")
+    }
+}
@@ -0,0 +1,38 @@
+use sea_orm::{ConnectionTrait, Schema};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigrationTrait;
+
+use super::favicon_entity;
+
+#[derive(Debug, Clone, Copy)]
+pub struct FaviconMigration;
+
+impl MigrationName for FaviconMigration {
+    fn name(&self) -> &str {
+        "m20240101_000007_create_favicons_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for FaviconMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_favicons = schema.create_table_from_entity(favicon_entity::Entity);
+        create_favicons.if_not_exists();
+
+        db.execute(backend.build(&create_favicons))
+            .await
+            .map_err(|e| DbErr::Custom(format!("failed to create favicons table: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(favicon_entity::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,27 @@
+use sea_orm::entity::prelude::*;
+
+/// 默认分组 ID（迁移时为已有 Tab 补齐 group_id 使用）
+pub const DEFAULT_GROUP_ID: &str = "default";
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "tab_groups")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub name: String,
+    pub is_active: bool,
+    pub sort_order: i32,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unreachable!("tab_groups has no relations")
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, EntityTrait, PaginatorTrait, Schema, Set};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigrationTrait;
+
+use super::{entity, group_entity};
+use crate::infrastructure::database::schema;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TabGroupMigration;
+
+impl MigrationName for TabGroupMigration {
+    fn name(&self) -> &str {
+        "m20240101_000004_create_tab_groups_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for TabGroupMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_groups = schema.create_table_from_entity(group_entity::Entity);
+        create_groups.if_not_exists();
+
+        db.execute(backend.build(&create_groups))
+            .await
+            .map_err(|e| DbErr::Custom(format!("failed to create tab_groups table: {}", e)))?;
+
+        // 保证至少存在一个默认分组
+        if group_entity::Entity::find().count(db).await? == 0 {
+            let now = Utc::now();
+            group_entity::ActiveModel {
+                id: Set(group_entity::DEFAULT_GROUP_ID.to_string()),
+                name: Set("默认".to_string()),
+                is_active: Set(true),
+                sort_order: Set(0),
+                created_at: Set(now),
+                updated_at: Set(now),
+            }
+            .insert(db)
+            .await?;
+        }
+
+        // 旧版本的 tabs 表没有 group_id 列，补齐并归入默认分组
+        if !schema::has_column(db, "tabs", "group_id").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(entity::Entity)
+                        .add_column(
+                            ColumnDef::new(entity::Column::GroupId)
+                                .string()
+                                .not_null()
+                                .default(group_entity::DEFAULT_GROUP_ID),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(group_entity::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,29 @@
+use sea_orm::entity::prelude::*;
+
+/// 标签页导航历史
+///
+/// 每次上报导航追加一条记录，position 在同一 tab 内递增，
+/// is_current 标记后退/前进的当前位置
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "tab_history")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub tab_id: String,
+    pub position: i32,
+    pub url: String,
+    pub title: Option<String>,
+    pub is_current: bool,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unreachable!("tab_history has no relations")
+    }
+}
@@ -0,0 +1,38 @@
+use sea_orm::{ConnectionTrait, Schema};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigrationTrait;
+
+use super::history_entity;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TabHistoryMigration;
+
+impl MigrationName for TabHistoryMigration {
+    fn name(&self) -> &str {
+        "m20240101_000005_create_tab_history_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for TabHistoryMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_history = schema.create_table_from_entity(history_entity::Entity);
+        create_history.if_not_exists();
+
+        db.execute(backend.build(&create_history))
+            .await
+            .map_err(|e| DbErr::Custom(format!("failed to create tab_history table: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(history_entity::Entity).to_owned())
+            .await
+    }
+}
@@ -1,3 +1,15 @@
 // 数据访问层
+pub mod closed_content_size_migration;
+pub mod closed_entity;
+pub mod closed_migration;
+pub mod content_size_migration;
 pub mod entity;
+pub mod favicon_entity;
+pub mod favicon_migration;
+pub mod group_entity;
+pub mod group_migration;
+pub mod history_entity;
+pub mod history_migration;
 pub mod migration;
+pub mod split_entity;
+pub mod split_migration;
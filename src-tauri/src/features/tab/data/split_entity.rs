@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+
+/// 分屏组合：两个标签在内容区并排显示
+///
+/// 每个标签最多属于一个组合，激活其中任一标签时恢复整个组合
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "tab_splits")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    /// 左侧（水平分屏）或上方（垂直分屏）的标签
+    pub primary_tab_id: String,
+    pub secondary_tab_id: String,
+    /// "horizontal" 左右并排，"vertical" 上下并排
+    pub orientation: String,
+    /// primary 所占的比例
+    pub ratio: f64,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unreachable!("tab_splits has no relations")
+    }
+}
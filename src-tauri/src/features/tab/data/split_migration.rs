@@ -0,0 +1,38 @@
+use sea_orm::{ConnectionTrait, Schema};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigrationTrait;
+
+use super::split_entity;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TabSplitMigration;
+
+impl MigrationName for TabSplitMigration {
+    fn name(&self) -> &str {
+        "m20240101_000009_create_tab_splits_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for TabSplitMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_splits = schema.create_table_from_entity(split_entity::Entity);
+        create_splits.if_not_exists();
+
+        db.execute(backend.build(&create_splits))
+            .await
+            .map_err(|e| DbErr::Custom(format!("failed to create tab_splits table: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(split_entity::Entity).to_owned())
+            .await
+    }
+}
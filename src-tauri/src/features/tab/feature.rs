@@ -1,21 +1,57 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
 use anyhow::Result;
 use async_trait::async_trait;
-use sea_orm_migration::MigrationTrait;
+use tauri::Manager;
 
 use crate::core::{AppState, Feature};
-use crate::infrastructure::database::DatabaseRegistry;
+use crate::infrastructure::database::{DatabaseRegistry, RegisteredMigration};
+use crate::infrastructure::injection::InjectionRegistry;
+use crate::features::tab::core::models::ClosedTab;
 use crate::features::tab::core::service::TabService;
+use crate::features::window::geometry;
 use crate::features::window::manager as window_manager;
+use crate::features::window::split;
 
+use super::api::commands::enforce_suspension;
+use super::api::content_size::remember_content_size;
+use super::api::split_commands::save_split_ratio;
+use super::api::tray::refresh_recently_closed;
+use super::data::closed_content_size_migration::ClosedTabContentSizeMigration;
+use super::data::closed_migration::ClosedTabMigration;
+use super::data::content_size_migration::TabContentSizeMigration;
+use super::data::favicon_migration::FaviconMigration;
+use super::data::group_migration::TabGroupMigration;
+use super::data::history_migration::TabHistoryMigration;
 use super::data::migration::TabMigration;
+use super::data::split_migration::TabSplitMigration;
+
+/// 空闲挂起检查间隔
+const SUSPENSION_CHECK_INTERVAL: Duration = Duration::from_secs(60);
 
 /// Tab Feature - 负责管理浏览器标签页的核心能力。
 /// 后续会扩展数据库迁移、Tauri commands、WebSocket handlers 等。
-pub struct TabFeature;
+pub struct TabFeature {
+    // 最近关闭标签缓存（用于同步构建托盘菜单）
+    recently_closed: Mutex<Vec<ClosedTab>>,
+}
 
 impl TabFeature {
     pub fn new() -> Self {
-        Self
+        Self {
+            recently_closed: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 获取最近关闭标签缓存
+    pub fn recently_closed(&self) -> Vec<ClosedTab> {
+        self.recently_closed.lock().unwrap().clone()
+    }
+
+    /// 更新最近关闭标签缓存
+    pub fn set_recently_closed(&self, tabs: Vec<ClosedTab>) {
+        *self.recently_closed.lock().unwrap() = tabs;
     }
 }
 
@@ -32,16 +68,47 @@ impl Feature for TabFeature {
     async fn initialize(&self, app_state: &AppState) -> Result<()> {
         // 初始化时，获取当前激活的 Tab 并设置到 Window Manager
         if let Ok(Some(tab)) = TabService::get_active(app_state.db()).await {
-            window_manager::set_current_url(tab.url);
+            geometry::remember_tab_size(&tab.id, tab.content_width.zip(tab.content_height));
+            window_manager::set_active_content(tab.id, tab.url);
         }
+        refresh_recently_closed(app_state).await;
+
+        // 内容窗口尺寸和分屏比例由窗口调整，由 tab feature 保存
+        geometry::on_tab_size_changed(remember_content_size);
+        split::on_ratio_changed(save_split_ratio);
+
+        // 定期挂起空闲过久的后台标签
+        let app_handle = app_state.app_handle();
+        tauri::async_runtime::spawn(async move {
+            loop {
+                tokio::time::sleep(SUSPENSION_CHECK_INTERVAL).await;
+                if let Some(state) = app_handle.try_state::<AppState>() {
+                    enforce_suspension(&state).await;
+                }
+            }
+        });
+
         Ok(())
     }
 
     fn register_database(&self, registry: &mut DatabaseRegistry) {
-        registry.register_migration("tabs_migration", |manager| {
-            let migration = TabMigration;
-            Box::pin(async move { migration.up(manager).await })
-        });
+        registry.register_migrations(
+            self.name(),
+            vec![
+                RegisteredMigration::new(TabMigration, 1),
+                RegisteredMigration::new(TabGroupMigration, 1),
+                RegisteredMigration::new(TabHistoryMigration, 1),
+                RegisteredMigration::new(ClosedTabMigration, 1),
+                RegisteredMigration::new(FaviconMigration, 1),
+                RegisteredMigration::new(TabContentSizeMigration, 1),
+                RegisteredMigration::new(TabSplitMigration, 1),
+                RegisteredMigration::new(ClosedTabContentSizeMigration, 1),
+            ],
+        );
+    }
+
+    fn register_scripts(&self, registry: &mut InjectionRegistry) {
+        registry.register_scripts(self.name(), super::api::scripts::script_modules());
     }
 
     fn command_names(&self) -> Vec<&'static str> {
@@ -51,12 +118,36 @@ impl Feature for TabFeature {
             "tabs_update",
             "tabs_activate",
             "tabs_close",
+            "tabs_import",
             "tabs_reorder",
             "tabs_activate_next",
             "tabs_activate_previous",
             "tabs_close_active",
             "tabs_reload",
+            "tabs_reset_content_size",
+            "tabs_detach",
+            "tabs_attach",
             "tabs_report_navigation",
+            "tabs_report_favicon",
+            "tabs_open_link",
+            "tabs_open_external",
+            "tabs_go_back",
+            "tabs_go_forward",
+            "tabs_history",
+            "tabs_reopen_closed",
+            "tabs_list_closed",
+            "tabs_get_suspension_policy",
+            "tabs_set_suspension_policy",
+            "tab_groups_list",
+            "tab_groups_create",
+            "tab_groups_update",
+            "tab_groups_activate",
+            "tab_groups_delete",
+            "tab_groups_reorder",
+            "tab_splits_list",
+            "tab_splits_create",
+            "tab_splits_update",
+            "tab_splits_delete",
         ]
     }
 }
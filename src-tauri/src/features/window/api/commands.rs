@@ -1,12 +1,190 @@
-use crate::features::window::manager;
+use tauri::State;
 
+use crate::core::AppState;
+use crate::features::window::appearance::{self, ForceDarkSettings};
+use crate::features::window::focus::{self, FocusTimings};
+use crate::features::window::geometry;
+use crate::features::window::layout::{self, LayoutSettings};
+use crate::features::window::manager::{self, DockSettings};
+use crate::features::window::monitors;
+use crate::features::window::pin;
+use crate::features::window::script_modules;
+use crate::infrastructure::injection::ScriptModuleInfo;
+use crate::features::window::shortcuts::{self, ShortcutBinding};
+
+/// 设置内容窗口是否固定（保存到设置）
+#[tauri::command]
+pub async fn set_content_window_pinned(
+    app_state: State<'_, AppState>,
+    pinned: bool,
+) -> Result<(), String> {
+    pin::set_pinned(&app_state, pinned)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 内容窗口当前是否固定
+#[tauri::command]
+pub async fn window_get_content_pinned() -> Result<bool, String> {
+    Ok(manager::is_content_window_pinned())
+}
+
+/// 前端打开/关闭原生对话框时调用，打开期间窗口失去焦点不自动隐藏
 #[tauri::command]
-pub async fn set_content_window_pinned(pinned: bool) -> Result<(), String> {
-    manager::set_content_window_pinned(pinned);
+pub async fn window_set_modal_open(app: tauri::AppHandle, open: bool) -> Result<(), String> {
+    manager::set_modal_open(&app, open);
     Ok(())
 }
 
+/// 获取自动隐藏相关的延迟
+#[tauri::command]
+pub async fn window_get_focus_timings(
+    app_state: State<'_, AppState>,
+) -> Result<FocusTimings, String> {
+    focus::load_timings(&app_state)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 修改自动隐藏相关的延迟，立即生效
+#[tauri::command]
+pub async fn window_set_focus_timings(
+    app_state: State<'_, AppState>,
+    timings: FocusTimings,
+) -> Result<FocusTimings, String> {
+    let timings = focus::save_timings(&app_state, timings)
+        .await
+        .map_err(|e| e.to_string())?;
+    manager::set_focus_timings(timings);
+    Ok(timings)
+}
+
+/// 获取强制暗色设置
+#[tauri::command]
+pub async fn window_get_force_dark() -> Result<ForceDarkSettings, String> {
+    Ok(appearance::force_dark_settings())
+}
+
+/// 修改强制暗色设置，立即应用到已打开的页面
+#[tauri::command]
+pub async fn window_set_force_dark(
+    app_state: State<'_, AppState>,
+    settings: ForceDarkSettings,
+) -> Result<ForceDarkSettings, String> {
+    let settings = appearance::save(&app_state, settings)
+        .await
+        .map_err(|e| e.to_string())?;
+    appearance::apply(&app_state.app_handle());
+    Ok(settings)
+}
+
+/// 列出注入到内容窗口的脚本模块（按注入顺序）
+#[tauri::command]
+pub async fn window_script_modules_list(
+    app_state: State<'_, AppState>,
+) -> Result<Vec<ScriptModuleInfo>, String> {
+    Ok(app_state.injection_registry().modules())
+}
+
+/// 启用或停用脚本模块，新建或重新加载的页面生效
+#[tauri::command]
+pub async fn window_script_modules_set_enabled(
+    app_state: State<'_, AppState>,
+    name: String,
+    enabled: bool,
+) -> Result<Vec<ScriptModuleInfo>, String> {
+    script_modules::set_enabled(&app_state, &name, enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(app_state.injection_registry().modules())
+}
+
 #[tauri::command]
 pub async fn resize_main_window(app: tauri::AppHandle, width: f64, height: f64) -> Result<(), String> {
     manager::resize_main_window(&app, width, height)
 }
+
+/// 列出全局快捷键的当前绑定
+#[tauri::command]
+pub async fn shortcuts_list() -> Result<Vec<ShortcutBinding>, String> {
+    Ok(shortcuts::bindings())
+}
+
+/// 修改快捷键绑定，accelerator 为空时禁用该快捷键
+#[tauri::command]
+pub async fn shortcuts_set(
+    app_state: State<'_, AppState>,
+    action: String,
+    accelerator: Option<String>,
+) -> Result<Vec<ShortcutBinding>, String> {
+    shortcuts::set_binding(&app_state, &action, accelerator.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(shortcuts::bindings())
+}
+
+/// 恢复默认快捷键，未指定 action 时恢复全部
+#[tauri::command]
+pub async fn shortcuts_reset(
+    app_state: State<'_, AppState>,
+    action: Option<String>,
+) -> Result<Vec<ShortcutBinding>, String> {
+    shortcuts::reset_bindings(&app_state, action.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(shortcuts::bindings())
+}
+
+/// 获取贴边停靠设置
+#[tauri::command]
+pub async fn window_get_dock_settings() -> Result<DockSettings, String> {
+    Ok(manager::dock_settings())
+}
+
+/// 修改贴边停靠设置，edge 为 none 时取消停靠
+#[tauri::command]
+pub async fn window_set_dock_settings(
+    app: tauri::AppHandle,
+    settings: DockSettings,
+) -> Result<DockSettings, String> {
+    manager::apply_dock_settings(&app, settings);
+    geometry::save_dock_settings(&app, settings);
+    Ok(settings)
+}
+
+/// 获取内容窗口布局
+#[tauri::command]
+pub async fn window_get_content_layout() -> Result<LayoutSettings, String> {
+    Ok(layout::layout_settings())
+}
+
+/// 修改内容窗口布局并立即重新定位
+#[tauri::command]
+pub async fn window_set_content_layout(
+    app_state: State<'_, AppState>,
+    settings: LayoutSettings,
+) -> Result<LayoutSettings, String> {
+    let settings = layout::save(&app_state, settings)
+        .await
+        .map_err(|e| e.to_string())?;
+    manager::sync_content_window_position(&app_state.app_handle());
+    Ok(settings)
+}
+
+/// 唤出时是否移动到鼠标所在的显示器
+#[tauri::command]
+pub async fn window_get_summon_on_cursor_monitor() -> Result<bool, String> {
+    Ok(monitors::summon_on_cursor_monitor())
+}
+
+/// 设置唤出时是否移动到鼠标所在的显示器
+#[tauri::command]
+pub async fn window_set_summon_on_cursor_monitor(
+    app_state: State<'_, AppState>,
+    enabled: bool,
+) -> Result<bool, String> {
+    monitors::set_summon_on_cursor_monitor(&app_state, enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(enabled)
+}
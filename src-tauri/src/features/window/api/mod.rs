@@ -1,3 +1,4 @@
 // API 接口层
 pub mod commands;
+pub mod scripts;
 pub mod tray;
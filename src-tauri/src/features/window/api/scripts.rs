@@ -0,0 +1,126 @@
+use crate::features::window::appearance;
+use crate::infrastructure::injection::ScriptModule;
+
+/// 公共桥接：其他脚本模块通过 `window.__NOB__` 调用命令、打开外部链接
+const BRIDGE_SCRIPT: &str = r#"
+(() => {
+  if (window.__NOB__) return;
+
+  const getInvoker = () => {
+    const tauri = window.__TAURI__;
+    return tauri?.core?.invoke ?? tauri?.invoke;
+  };
+
+  window.__NOB__ = {
+    invoke(cmd, args) {
+      const invoker = getInvoker();
+      return invoker ? invoker(cmd, args) : Promise.resolve(null);
+    },
+
+    openExternal(url) {
+      const invoker = getInvoker();
+      if (!invoker) {
+        console.warn("[NoB] __TAURI__ invoke not available");
+        return;
+      }
+      invoker("tabs_open_external", { url }).catch((e) =>
+        console.warn("[NoB] open external rejected", e)
+      );
+    },
+
+    isExternal(href) {
+      try {
+        const u = new URL(href, window.location.href);
+        return u.origin !== window.location.origin;
+      } catch {
+        return false;
+      }
+    },
+
+    async safeInit(name, fn) {
+      try {
+        await fn();
+        console.log(`[NoB] ${name} initialized successfully.`);
+      } catch (e) {
+        console.error(`[NoB] ${name} initialization failed:`, e);
+      }
+    },
+  };
+})();
+"#;
+
+/// 拦截 window.open：按链接规则打开，未启用规则时改用系统默认浏览器打开
+const WINDOW_OPEN_SCRIPT: &str = r#"
+(() => {
+  const nob = window.__NOB__;
+  if (!nob) return;
+
+  nob.safeInit("WindowOpenInterceptor", () => {
+    const originalOpen = window.open;
+    window.open = function(url, target, features) {
+      if (typeof url === "string") {
+        if (nob.followLink) {
+          nob.followLink(url, true);
+        } else {
+          nob.openExternal(url);
+        }
+        return null;
+      }
+      return originalOpen?.apply(this, [url, target, features]);
+    };
+  });
+})();
+"#;
+
+/// 拦截链接点击：按链接规则打开，未启用规则时 target=_blank / 跨域链接改用系统默认浏览器打开
+const EXTERNAL_LINK_SCRIPT: &str = r#"
+(() => {
+  const nob = window.__NOB__;
+  if (!nob) return;
+
+  nob.safeInit("ExternalLinkInterceptor", () => {
+    document.addEventListener("click", (e) => {
+      const el = e.target;
+      if (!(el instanceof Element)) return;
+      const anchor = el.closest("a");
+      if (!anchor || !anchor.href) return;
+
+      if (nob.followLink) {
+        if (nob.followLink(anchor.href, anchor.target === "_blank")) e.preventDefault();
+        return;
+      }
+
+      const shouldOpenExternally = anchor.target === "_blank" || nob.isExternal(anchor.href);
+
+      if (shouldOpenExternally) {
+        console.log("[NoB] external link intercepted", anchor.href);
+        e.preventDefault();
+        nob.openExternal(anchor.href);
+      }
+    }, true);
+  });
+})();
+"#;
+
+/// Window Feature 注入的脚本模块
+pub fn script_modules() -> Vec<ScriptModule> {
+    vec![
+        ScriptModule::new("window.bridge", "公共桥接", 0, BRIDGE_SCRIPT).required(),
+        ScriptModule::new(
+            "window.open_interceptor",
+            "按规则处理 window.open",
+            10,
+            WINDOW_OPEN_SCRIPT,
+        ),
+        ScriptModule::new(
+            "window.external_links",
+            "按规则处理链接点击",
+            50,
+            EXTERNAL_LINK_SCRIPT,
+        ),
+        ScriptModule::dynamic("window.force_dark", "强制暗色", 90, |app| {
+            vec![appearance::force_dark_script(app)]
+        })
+        .reapply_on_load(),
+    ]
+}
@@ -1,6 +1,6 @@
 use tauri::AppHandle;
 
-use crate::features::window::manager;
+use crate::features::window::{manager, pin};
 use crate::infrastructure::tray::TrayMenuItem;
 
 /// 窗口显示/隐藏切换菜单项
@@ -9,3 +9,11 @@ pub fn toggle_window_item() -> TrayMenuItem {
         let _ = manager::toggle_main_window(app);
     })
 }
+
+/// 固定内容窗口（不自动隐藏）菜单项
+pub fn pin_content_item() -> TrayMenuItem {
+    TrayMenuItem::always_visible("pin_content", "固定内容窗口", |app: &AppHandle| {
+        pin::toggle(app);
+    })
+    .with_checked(|_| manager::is_content_window_pinned())
+}
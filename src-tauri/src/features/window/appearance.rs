@@ -0,0 +1,184 @@
+use std::sync::Mutex;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Theme, Wry};
+use url::Url;
+
+use crate::core::AppState;
+use crate::features::settings::core::service::SettingService;
+use crate::features::window::manager;
+
+/// 强制暗色设置
+const FORCE_DARK_KEY: &str = "window.force_dark";
+
+/// 强制暗色：暗色外观下为没有暗色主题的页面注入反色样式
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ForceDarkSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 不注入的站点（域名，同时匹配其子域名）
+    #[serde(default)]
+    pub excluded_hosts: Vec<String>,
+}
+
+static FORCE_DARK: Mutex<ForceDarkSettings> = Mutex::new(ForceDarkSettings {
+    enabled: false,
+    excluded_hosts: Vec::new(),
+});
+
+/// 注入的样式：整页反色，图片和视频等再反色回来
+const FORCE_DARK_CSS: &str = ":where(html) { background-color: #fff; } \
+html { filter: invert(1) hue-rotate(180deg); } \
+img, video, picture, canvas, iframe, svg image, [style*=\"background-image\"] { filter: invert(1) hue-rotate(180deg); }";
+
+/// 当前强制暗色设置
+pub fn force_dark_settings() -> ForceDarkSettings {
+    FORCE_DARK.lock().unwrap().clone()
+}
+
+/// 从设置中读取强制暗色设置
+pub async fn load(app_state: &AppState) -> Result<ForceDarkSettings> {
+    let settings: ForceDarkSettings = SettingService::get(app_state.db(), FORCE_DARK_KEY)
+        .await?
+        .and_then(|value| serde_json::from_str(&value).ok())
+        .unwrap_or_default();
+    *FORCE_DARK.lock().unwrap() = settings.clone();
+    Ok(settings)
+}
+
+/// 保存强制暗色设置（站点统一为小写域名并去重）
+pub async fn save(
+    app_state: &AppState,
+    mut settings: ForceDarkSettings,
+) -> Result<ForceDarkSettings> {
+    let mut hosts: Vec<String> = Vec::new();
+    for host in settings
+        .excluded_hosts
+        .iter()
+        .filter_map(|h| normalize_host(h))
+    {
+        if !hosts.contains(&host) {
+            hosts.push(host);
+        }
+    }
+    settings.excluded_hosts = hosts;
+
+    SettingService::set(
+        app_state.db(),
+        FORCE_DARK_KEY,
+        &serde_json::to_string(&settings)?,
+    )
+    .await?;
+    *FORCE_DARK.lock().unwrap() = settings.clone();
+    Ok(settings)
+}
+
+/// 规范化站点：接受域名或完整 url
+fn normalize_host(value: &str) -> Option<String> {
+    let value = value.trim().to_lowercase();
+    let host = if value.contains("://") {
+        Url::parse(&value).ok()?.host_str()?.to_string()
+    } else {
+        value
+            .trim_start_matches("*.")
+            .trim_end_matches('/')
+            .to_string()
+    };
+    Some(host).filter(|h| !h.is_empty())
+}
+
+/// 当前主题偏好（light / dark / system）
+fn preference(app: &AppHandle<Wry>) -> String {
+    app.try_state::<AppState>()
+        .map(|state| state.get_theme())
+        .unwrap_or_else(|| "system".to_string())
+}
+
+/// 偏好对应的原生窗口主题，system 时为 None（跟随系统）
+pub fn window_theme(app: &AppHandle<Wry>) -> Option<Theme> {
+    match preference(app).as_str() {
+        "light" => Some(Theme::Light),
+        "dark" => Some(Theme::Dark),
+        _ => None,
+    }
+}
+
+/// 当前是否为暗色外观（跟随系统时以主窗口的主题为准）
+fn is_dark(app: &AppHandle<Wry>) -> bool {
+    match window_theme(app) {
+        Some(theme) => theme == Theme::Dark,
+        None => app
+            .get_webview_window("main")
+            .and_then(|window| window.theme().ok())
+            .is_some_and(|theme| theme == Theme::Dark),
+    }
+}
+
+/// 内容页面的强制暗色脚本，按当前外观和设置生成
+///
+/// 同时作为初始化脚本和页面加载后的修正脚本；页面自身背景已是暗色时不注入
+pub fn force_dark_script(app: &AppHandle<Wry>) -> String {
+    let settings = force_dark_settings();
+    let on = settings.enabled && is_dark(app);
+    format!(
+        r#"(() => {{
+  const on = {on};
+  const excluded = {excluded};
+  const css = {css};
+  const STYLE_ID = "nob-force-dark";
+  const host = location.hostname;
+  const isExcluded = excluded.some((h) => host === h || host.endsWith("." + h));
+  const isDark = (el) => {{
+    if (!el) return false;
+    const parts = getComputedStyle(el).backgroundColor.match(/[\d.]+/g);
+    if (!parts || parts.length < 3 || (parts.length > 3 && Number(parts[3]) === 0)) return false;
+    const [r, g, b] = parts.map(Number);
+    return 0.299 * r + 0.587 * g + 0.114 * b < 128;
+  }};
+  const apply = () => {{
+    let style = document.getElementById(STYLE_ID);
+    if (!on || isExcluded || isDark(document.body) || isDark(document.documentElement)) {{
+      if (style) style.remove();
+      return;
+    }}
+    const parent = document.head || document.documentElement;
+    if (!style && parent) {{
+      style = document.createElement("style");
+      style.id = STYLE_ID;
+      style.textContent = css;
+      parent.appendChild(style);
+    }}
+  }};
+  apply();
+  if (document.readyState === "loading") {{
+    document.addEventListener("DOMContentLoaded", apply, {{ once: true }});
+  }}
+}})();"#,
+        on = on,
+        excluded = serde_json::to_string(&settings.excluded_hosts).unwrap_or_else(|_| "[]".into()),
+        css = serde_json::to_string(FORCE_DARK_CSS).unwrap_or_else(|_| "\"\"".into()),
+    )
+}
+
+/// 将当前外观应用到所有内容窗口和分离窗口（主题偏好或强制暗色设置修改后调用）
+pub fn apply(app: &AppHandle<Wry>) {
+    let theme = window_theme(app);
+    let script = force_dark_script(app);
+    for (label, window) in app.webview_windows() {
+        if manager::tab_id_from_label(&label).is_none()
+            && manager::tab_id_from_detached_label(&label).is_none()
+        {
+            continue;
+        }
+        let _ = window.set_theme(theme);
+        let _ = window.eval(&script);
+    }
+}
+
+/// 系统外观变化：跟随系统时更新注入的样式（原生外观会自动跟随）
+pub fn on_system_theme_changed(app: &AppHandle<Wry>) {
+    if window_theme(app).is_none() {
+        apply(app);
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use crate::core::{AppState, Feature};
+use crate::infrastructure::injection::InjectionRegistry;
 
 /// Window Feature - 窗口管理功能
 ///
@@ -27,11 +28,76 @@ impl Feature for WindowFeature {
     async fn initialize(&self, _app_state: &AppState) -> Result<()> {
         println!("[WindowFeature] Initialized");
         super::manager::configure_startup_behavior(&_app_state.app_handle());
+
+        load_settings(_app_state).await;
+        if let Err(e) = super::geometry::restore(_app_state).await {
+            println!("[WindowFeature] Failed to restore window geometry: {}", e);
+        }
+        super::monitors::watch(&_app_state.app_handle());
         Ok(())
     }
 
+    fn register_scripts(&self, registry: &mut InjectionRegistry) {
+        registry.register_scripts(self.name(), super::api::scripts::script_modules());
+    }
+
+    fn command_names(&self) -> Vec<&'static str> {
+        vec![
+            "set_content_window_pinned",
+            "window_get_content_pinned",
+            "resize_main_window",
+            "shortcuts_list",
+            "shortcuts_set",
+            "shortcuts_reset",
+            "window_get_dock_settings",
+            "window_set_dock_settings",
+            "window_get_content_layout",
+            "window_set_content_layout",
+            "window_get_summon_on_cursor_monitor",
+            "window_set_summon_on_cursor_monitor",
+            "window_set_modal_open",
+            "window_get_focus_timings",
+            "window_set_focus_timings",
+            "window_get_force_dark",
+            "window_set_force_dark",
+            "window_script_modules_list",
+            "window_script_modules_set_enabled",
+        ]
+    }
+
     async fn cleanup(&self) -> Result<()> {
         println!("[WindowFeature] Cleaned up");
         Ok(())
     }
 }
+
+/// 读取保存在设置中的窗口状态（启动时和导入数据后调用）
+///
+/// 主窗口位置和停靠由 geometry 单独恢复
+pub(crate) async fn load_settings(app_state: &AppState) {
+    match super::focus::load_timings(app_state).await {
+        Ok(timings) => super::manager::set_focus_timings(timings),
+        Err(e) => println!("[WindowFeature] Failed to load focus timings: {}", e),
+    }
+    if let Err(e) = super::script_modules::load(app_state).await {
+        println!(
+            "[WindowFeature] Failed to load script module switches: {}",
+            e
+        );
+    }
+    if let Err(e) = super::appearance::load(app_state).await {
+        println!("[WindowFeature] Failed to load force dark settings: {}", e);
+    }
+    if let Err(e) = super::pin::load(app_state).await {
+        println!("[WindowFeature] Failed to restore content pin: {}", e);
+    }
+    if let Err(e) = super::layout::load(app_state).await {
+        println!("[WindowFeature] Failed to load content layout: {}", e);
+    }
+    if let Err(e) = super::monitors::load(app_state).await {
+        println!("[WindowFeature] Failed to load monitor settings: {}", e);
+    }
+    if let Err(e) = super::shortcuts::reload(app_state).await {
+        println!("[WindowFeature] Failed to register shortcuts: {}", e);
+    }
+}
@@ -0,0 +1,600 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::core::AppState;
+use crate::features::settings::core::service::SettingService;
+
+/// 焦点/自动隐藏的延迟设置
+const TIMINGS_KEY: &str = "window.focus_timings";
+const DEFAULT_BLUR_DELAY_MS: u64 = 100;
+const DEFAULT_DRAG_SETTLE_MS: u64 = 500;
+/// 延迟上限，过大的值会让 `Instant` 计算溢出
+const MAX_TIMING_MS: u64 = 10_000;
+
+/// 自动隐藏相关的延迟（毫秒）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FocusTimings {
+    /// 所有窗口失去焦点后多久隐藏内容窗口（允许焦点在窗口间切换）
+    #[serde(default = "default_blur_delay_ms")]
+    pub blur_delay_ms: u64,
+    /// 主窗口停止移动多久后视为拖动结束
+    #[serde(default = "default_drag_settle_ms")]
+    pub drag_settle_ms: u64,
+}
+
+impl Default for FocusTimings {
+    fn default() -> Self {
+        DEFAULT_TIMINGS
+    }
+}
+
+pub const DEFAULT_TIMINGS: FocusTimings = FocusTimings {
+    blur_delay_ms: DEFAULT_BLUR_DELAY_MS,
+    drag_settle_ms: DEFAULT_DRAG_SETTLE_MS,
+};
+
+impl FocusTimings {
+    /// 把各项延迟限制在 0..=MAX_TIMING_MS
+    pub fn normalized(self) -> Self {
+        Self {
+            blur_delay_ms: self.blur_delay_ms.min(MAX_TIMING_MS),
+            drag_settle_ms: self.drag_settle_ms.min(MAX_TIMING_MS),
+        }
+    }
+}
+
+fn default_blur_delay_ms() -> u64 {
+    DEFAULT_BLUR_DELAY_MS
+}
+
+fn default_drag_settle_ms() -> u64 {
+    DEFAULT_DRAG_SETTLE_MS
+}
+
+/// 从设置中读取延迟，超出范围的值被截断
+pub async fn load_timings(app_state: &AppState) -> Result<FocusTimings> {
+    Ok(SettingService::get(app_state.db(), TIMINGS_KEY)
+        .await?
+        .and_then(|value| serde_json::from_str::<FocusTimings>(&value).ok())
+        .unwrap_or_default()
+        .normalized())
+}
+
+/// 保存延迟设置，返回实际保存的值
+pub async fn save_timings(app_state: &AppState, timings: FocusTimings) -> Result<FocusTimings> {
+    let timings = timings.normalized();
+    SettingService::set(
+        app_state.db(),
+        TIMINGS_KEY,
+        &serde_json::to_string(&timings)?,
+    )
+    .await?;
+    Ok(timings)
+}
+
+/// 时钟，测试中替换为手动推进的时钟
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// 系统时钟
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// 状态机的输入事件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FocusEvent {
+    /// 主窗口获得/失去焦点
+    MainFocus(bool),
+    /// 内容窗口（按 label 区分，分屏时有两个）获得/失去焦点
+    ContentFocus {
+        label: String,
+        focused: bool,
+    },
+    /// 主窗口被移动（拖动中会连续触发）
+    MainMoved,
+    /// 固定内容窗口（不自动隐藏）
+    Pinned(bool),
+    /// 原生对话框打开/关闭，期间窗口失去焦点不隐藏
+    ModalOpened,
+    ModalClosed,
+    /// 定时检查到期
+    Tick,
+}
+
+/// 状态机要求执行的窗口操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusAction {
+    /// 展示激活 tab 的内容窗口（不抢夺焦点）
+    ShowContent,
+    /// 拖动开始：隐藏内容窗口
+    HideForDrag,
+    /// 拖动结束：吸附停靠边缘、保存位置
+    DragEnded,
+    /// 所有窗口失去焦点：隐藏内容窗口，停靠时主窗口滑出
+    AutoHide,
+}
+
+/// 焦点/拖动/自动隐藏状态机
+///
+/// 不直接操作窗口，只根据事件和时钟给出要执行的操作；
+/// 调用方在 `next_deadline` 到期时发送 `Tick`
+#[derive(Debug)]
+pub struct FocusMachine<C: Clock> {
+    clock: C,
+    timings: FocusTimings,
+    main_focused: bool,
+    /// 当前获得焦点的内容窗口
+    focused_content: Option<String>,
+    pinned: bool,
+    /// 打开中的原生对话框数量
+    modals: u32,
+    /// 最近一次移动的时间，None 表示未在拖动
+    last_moved: Option<Instant>,
+    /// 所有窗口失去焦点的时间，None 表示无需检查
+    blurred_at: Option<Instant>,
+}
+
+impl<C: Clock> FocusMachine<C> {
+    pub const fn new(clock: C, timings: FocusTimings) -> Self {
+        Self {
+            clock,
+            timings,
+            main_focused: false,
+            focused_content: None,
+            pinned: false,
+            modals: 0,
+            last_moved: None,
+            blurred_at: None,
+        }
+    }
+
+    pub fn set_timings(&mut self, timings: FocusTimings) {
+        self.timings = timings.normalized();
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.last_moved.is_some()
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// 仅 macOS 在显示内容窗口后需要把焦点还给主窗口
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    pub fn is_main_focused(&self) -> bool {
+        self.main_focused
+    }
+
+    /// 下一次需要发送 Tick 的时间
+    pub fn next_deadline(&self) -> Option<Instant> {
+        let drag = self
+            .last_moved
+            .map(|at| at + Duration::from_millis(self.timings.drag_settle_ms));
+        let blur = self
+            .blurred_at
+            .map(|at| at + Duration::from_millis(self.timings.blur_delay_ms));
+        match (drag, blur) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// 处理事件，返回需要执行的操作（按顺序执行）
+    pub fn handle(&mut self, event: FocusEvent) -> Vec<FocusAction> {
+        let now = self.clock.now();
+        match event {
+            FocusEvent::MainFocus(true) => {
+                self.main_focused = true;
+                self.blurred_at = None;
+                // 拖动中不显示，拖动结束时再显示
+                if self.is_dragging() {
+                    vec![]
+                } else {
+                    vec![FocusAction::ShowContent]
+                }
+            }
+            FocusEvent::MainFocus(false) => {
+                self.main_focused = false;
+                self.start_blur_check(now);
+                vec![]
+            }
+            FocusEvent::ContentFocus {
+                label,
+                focused: true,
+            } => {
+                self.focused_content = Some(label);
+                self.blurred_at = None;
+                vec![]
+            }
+            FocusEvent::ContentFocus {
+                label,
+                focused: false,
+            } => {
+                // 焦点在两个窗格间切换时，失去焦点的事件可能晚于获得焦点的事件
+                if self.focused_content.as_deref() == Some(label.as_str()) {
+                    self.focused_content = None;
+                }
+                self.start_blur_check(now);
+                vec![]
+            }
+            FocusEvent::MainMoved => {
+                let started = self.last_moved.is_none();
+                self.last_moved = Some(now);
+                if started {
+                    vec![FocusAction::HideForDrag]
+                } else {
+                    vec![]
+                }
+            }
+            FocusEvent::Pinned(pinned) => {
+                self.pinned = pinned;
+                if !pinned {
+                    self.start_blur_check(now);
+                }
+                vec![]
+            }
+            FocusEvent::ModalOpened => {
+                self.modals += 1;
+                self.blurred_at = None;
+                vec![]
+            }
+            FocusEvent::ModalClosed => {
+                self.modals = self.modals.saturating_sub(1);
+                self.start_blur_check(now);
+                vec![]
+            }
+            FocusEvent::Tick => self.tick(now),
+        }
+    }
+
+    fn tick(&mut self, now: Instant) -> Vec<FocusAction> {
+        let mut actions = vec![];
+
+        if let Some(at) = self.last_moved {
+            if now >= at + Duration::from_millis(self.timings.drag_settle_ms) {
+                self.last_moved = None;
+                actions.push(FocusAction::DragEnded);
+                actions.push(FocusAction::ShowContent);
+                // 拖动期间失去焦点时，结束后重新开始计时
+                self.start_blur_check(now);
+            }
+        }
+
+        if let Some(at) = self.blurred_at {
+            if now >= at + Duration::from_millis(self.timings.blur_delay_ms) {
+                self.blurred_at = None;
+                if self.should_hide() {
+                    actions.push(FocusAction::AutoHide);
+                }
+            }
+        }
+
+        actions
+    }
+
+    /// 没有任何窗口持有焦点时开始计时
+    fn start_blur_check(&mut self, now: Instant) {
+        if !self.main_focused && self.focused_content.is_none() {
+            self.blurred_at = Some(now);
+        }
+    }
+
+    fn should_hide(&self) -> bool {
+        !self.main_focused
+            && self.focused_content.is_none()
+            && !self.pinned
+            && self.modals == 0
+            && !self.is_dragging()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    /// 手动推进的时钟
+    #[derive(Clone)]
+    struct ManualClock {
+        start: Instant,
+        offset: Rc<Cell<Duration>>,
+    }
+
+    impl ManualClock {
+        fn new() -> Self {
+            Self {
+                start: Instant::now(),
+                offset: Rc::new(Cell::new(Duration::ZERO)),
+            }
+        }
+
+        fn advance(&self, ms: u64) {
+            self.offset
+                .set(self.offset.get() + Duration::from_millis(ms));
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> Instant {
+            self.start + self.offset.get()
+        }
+    }
+
+    fn machine() -> (FocusMachine<ManualClock>, ManualClock) {
+        let clock = ManualClock::new();
+        (FocusMachine::new(clock.clone(), DEFAULT_TIMINGS), clock)
+    }
+
+    fn content(label: &str, focused: bool) -> FocusEvent {
+        FocusEvent::ContentFocus {
+            label: label.to_string(),
+            focused,
+        }
+    }
+
+    #[test]
+    fn main_focus_shows_content() {
+        let (mut m, _) = machine();
+        assert_eq!(
+            m.handle(FocusEvent::MainFocus(true)),
+            vec![FocusAction::ShowContent]
+        );
+        assert_eq!(m.next_deadline(), None);
+    }
+
+    #[test]
+    fn blur_hides_after_delay() {
+        let (mut m, clock) = machine();
+        m.handle(FocusEvent::MainFocus(true));
+        assert!(m.handle(FocusEvent::MainFocus(false)).is_empty());
+
+        clock.advance(99);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+        clock.advance(1);
+        assert_eq!(m.handle(FocusEvent::Tick), vec![FocusAction::AutoHide]);
+        assert_eq!(m.next_deadline(), None);
+    }
+
+    #[test]
+    fn focus_moving_to_content_does_not_hide() {
+        let (mut m, clock) = machine();
+        m.handle(FocusEvent::MainFocus(true));
+        m.handle(FocusEvent::MainFocus(false));
+        m.handle(content("content-a", true));
+
+        clock.advance(200);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+    }
+
+    #[test]
+    fn focus_moving_to_detached_window_hides_content() {
+        let (mut m, clock) = machine();
+        m.handle(FocusEvent::MainFocus(true));
+        m.handle(content("content-a", true));
+        // 分离窗口获得焦点时不发送事件，只会收到主窗口和内容窗口失去焦点
+        m.handle(FocusEvent::MainFocus(false));
+        m.handle(content("content-a", false));
+
+        clock.advance(100);
+        assert_eq!(m.handle(FocusEvent::Tick), vec![FocusAction::AutoHide]);
+
+        // 焦点回到主窗口时重新显示
+        assert_eq!(
+            m.handle(FocusEvent::MainFocus(true)),
+            vec![FocusAction::ShowContent]
+        );
+    }
+
+    #[test]
+    fn late_blur_from_other_pane_is_ignored() {
+        let (mut m, clock) = machine();
+        m.handle(content("content-a", true));
+        // 获得焦点的事件先于另一个窗格失去焦点的事件到达
+        m.handle(content("content-b", true));
+        m.handle(content("content-a", false));
+
+        clock.advance(200);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+
+        m.handle(content("content-b", false));
+        clock.advance(100);
+        assert_eq!(m.handle(FocusEvent::Tick), vec![FocusAction::AutoHide]);
+    }
+
+    #[test]
+    fn pinned_content_is_not_hidden() {
+        let (mut m, clock) = machine();
+        m.handle(FocusEvent::Pinned(true));
+        m.handle(FocusEvent::MainFocus(true));
+        m.handle(FocusEvent::MainFocus(false));
+
+        clock.advance(100);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+    }
+
+    #[test]
+    fn unpinning_without_focus_hides() {
+        let (mut m, clock) = machine();
+        m.handle(FocusEvent::Pinned(true));
+        m.handle(FocusEvent::Pinned(false));
+
+        clock.advance(100);
+        assert_eq!(m.handle(FocusEvent::Tick), vec![FocusAction::AutoHide]);
+    }
+
+    #[test]
+    fn modal_dialog_suppresses_hide_until_closed() {
+        let (mut m, clock) = machine();
+        m.handle(FocusEvent::MainFocus(true));
+        m.handle(FocusEvent::ModalOpened);
+        m.handle(FocusEvent::MainFocus(false));
+
+        clock.advance(1000);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+
+        m.handle(FocusEvent::ModalClosed);
+        clock.advance(100);
+        assert_eq!(m.handle(FocusEvent::Tick), vec![FocusAction::AutoHide]);
+    }
+
+    #[test]
+    fn modal_closed_with_focus_back_does_not_hide() {
+        let (mut m, clock) = machine();
+        m.handle(FocusEvent::ModalOpened);
+        m.handle(FocusEvent::MainFocus(true));
+        m.handle(FocusEvent::ModalClosed);
+
+        clock.advance(100);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+        assert_eq!(m.next_deadline(), None);
+    }
+
+    #[test]
+    fn full_modal_dialog_sequence() {
+        let (mut m, clock) = machine();
+        assert_eq!(
+            m.handle(FocusEvent::MainFocus(true)),
+            vec![FocusAction::ShowContent]
+        );
+
+        // 前端先标记对话框打开，随后对话框夺走焦点
+        m.handle(FocusEvent::ModalOpened);
+        m.handle(FocusEvent::MainFocus(false));
+        clock.advance(5000);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+        assert_eq!(m.next_deadline(), None);
+
+        // 对话框关闭：焦点先回到主窗口，前端再标记对话框关闭
+        assert_eq!(
+            m.handle(FocusEvent::MainFocus(true)),
+            vec![FocusAction::ShowContent]
+        );
+        m.handle(FocusEvent::ModalClosed);
+        clock.advance(100);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+        assert_eq!(m.next_deadline(), None);
+
+        // 之后失去焦点照常自动隐藏
+        m.handle(FocusEvent::MainFocus(false));
+        clock.advance(100);
+        assert_eq!(m.handle(FocusEvent::Tick), vec![FocusAction::AutoHide]);
+    }
+
+    #[test]
+    fn drag_hides_once_and_shows_after_settling() {
+        let (mut m, clock) = machine();
+        assert_eq!(
+            m.handle(FocusEvent::MainMoved),
+            vec![FocusAction::HideForDrag]
+        );
+        assert!(m.is_dragging());
+
+        clock.advance(300);
+        assert!(m.handle(FocusEvent::MainMoved).is_empty());
+        clock.advance(499);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+
+        clock.advance(1);
+        assert_eq!(
+            m.handle(FocusEvent::Tick),
+            vec![FocusAction::DragEnded, FocusAction::ShowContent]
+        );
+        assert!(!m.is_dragging());
+    }
+
+    #[test]
+    fn main_focus_during_drag_does_not_show_content() {
+        let (mut m, _) = machine();
+        m.handle(FocusEvent::MainMoved);
+        assert!(m.handle(FocusEvent::MainFocus(true)).is_empty());
+    }
+
+    #[test]
+    fn blur_during_drag_does_not_hide() {
+        let (mut m, clock) = machine();
+        m.handle(FocusEvent::MainMoved);
+        m.handle(FocusEvent::MainFocus(false));
+
+        clock.advance(100);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+
+        clock.advance(400);
+        assert_eq!(
+            m.handle(FocusEvent::Tick),
+            vec![FocusAction::DragEnded, FocusAction::ShowContent]
+        );
+        clock.advance(100);
+        assert_eq!(m.handle(FocusEvent::Tick), vec![FocusAction::AutoHide]);
+    }
+
+    #[test]
+    fn next_deadline_is_earliest_pending_check() {
+        let (mut m, clock) = machine();
+        let start = clock.now();
+        m.handle(FocusEvent::MainMoved);
+        m.handle(FocusEvent::MainFocus(false));
+
+        assert_eq!(m.next_deadline(), Some(start + Duration::from_millis(100)));
+        clock.advance(100);
+        m.handle(FocusEvent::Tick);
+        assert_eq!(m.next_deadline(), Some(start + Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn configured_timings_are_used() {
+        let (mut m, clock) = machine();
+        m.set_timings(FocusTimings {
+            blur_delay_ms: 250,
+            drag_settle_ms: 1000,
+        });
+        m.handle(FocusEvent::MainFocus(false));
+
+        clock.advance(100);
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+        clock.advance(150);
+        assert_eq!(m.handle(FocusEvent::Tick), vec![FocusAction::AutoHide]);
+    }
+
+    #[test]
+    fn timings_default_missing_fields() {
+        let timings: FocusTimings = serde_json::from_str(r#"{"blur_delay_ms":300}"#).unwrap();
+        assert_eq!(timings.blur_delay_ms, 300);
+        assert_eq!(timings.drag_settle_ms, DEFAULT_DRAG_SETTLE_MS);
+    }
+
+    #[test]
+    fn out_of_range_timings_are_clamped() {
+        let stored = format!(r#"{{"blur_delay_ms":{},"drag_settle_ms":20000}}"#, u64::MAX);
+        let timings = serde_json::from_str::<FocusTimings>(&stored)
+            .unwrap()
+            .normalized();
+        assert_eq!(timings.blur_delay_ms, MAX_TIMING_MS);
+        assert_eq!(timings.drag_settle_ms, MAX_TIMING_MS);
+
+        let (mut m, clock) = machine();
+        m.set_timings(FocusTimings {
+            blur_delay_ms: u64::MAX,
+            drag_settle_ms: u64::MAX,
+        });
+        let start = clock.now();
+        m.handle(FocusEvent::MainMoved);
+        m.handle(FocusEvent::MainFocus(false));
+        assert_eq!(
+            m.next_deadline(),
+            Some(start + Duration::from_millis(MAX_TIMING_MS))
+        );
+        assert!(m.handle(FocusEvent::Tick).is_empty());
+    }
+}
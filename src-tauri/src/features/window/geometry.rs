@@ -0,0 +1,345 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tauri::{
+    AppHandle, LogicalSize, Manager, PhysicalPosition, PhysicalSize, Position, WebviewWindow, Wry,
+};
+
+use crate::core::AppState;
+use crate::features::settings::core::service::SettingService;
+use crate::features::window::manager::{self, DockSettings};
+use crate::infrastructure::debounce::DebouncedWrites;
+
+/// 主窗口位置（物理坐标）
+const MAIN_POSITION_KEY: &str = "window.main_position";
+/// 停靠设置（停靠边缘、唤出角落和延迟）
+const DOCK_KEY: &str = "window.dock";
+/// 分离窗口的位置和尺寸，完整 key 为 `window.detached.<tab_id>`
+const DETACHED_PREFIX: &str = "window.detached.";
+
+/// 未单独记录尺寸的标签使用的内容窗口尺寸（逻辑尺寸，与缩放无关）
+const DEFAULT_CONTENT_SIZE: LogicalSize<f64> = LogicalSize {
+    width: 1100.0,
+    height: 780.0,
+};
+const MIN_CONTENT_SIZE: f64 = 200.0;
+/// 移动/调整大小停止后多久写入设置
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// 各标签记录的内容窗口尺寸（激活标签时由 tab feature 写入）
+static TAB_CONTENT_SIZES: Mutex<Vec<(String, LogicalSize<f64>)>> = Mutex::new(Vec::new());
+/// 标签尺寸调整后的回调（由 tab feature 注册，负责写入数据库）
+static TAB_SIZE_LISTENER: Mutex<Option<fn(&AppHandle<Wry>, &str, LogicalSize<f64>)>> =
+    Mutex::new(None);
+/// 移动/调整后尚未写入设置的分离窗口位置和尺寸
+static PENDING_DETACHED: DebouncedWrites<String, DetachedGeometry> =
+    DebouncedWrites::new(SAVE_DELAY);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct SavedPosition {
+    x: i32,
+    y: i32,
+}
+
+/// 分离窗口的位置（物理坐标）和尺寸（逻辑尺寸）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DetachedGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// 内容窗口的默认尺寸（新标签和恢复默认尺寸的标签使用）
+///
+/// 调整某个标签的尺寸只记录到该标签，不改变默认尺寸
+pub fn default_content_size() -> LogicalSize<f64> {
+    DEFAULT_CONTENT_SIZE
+}
+
+/// 标签的内容窗口应使用的尺寸，未单独记录时使用默认尺寸
+pub fn content_size(tab_id: &str) -> LogicalSize<f64> {
+    TAB_CONTENT_SIZES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(id, _)| id == tab_id)
+        .map(|(_, size)| *size)
+        .unwrap_or_else(default_content_size)
+}
+
+/// 缓存标签记录的尺寸（宽、高），None 表示使用默认尺寸
+pub fn remember_tab_size(tab_id: &str, size: Option<(f64, f64)>) {
+    let mut sizes = TAB_CONTENT_SIZES.lock().unwrap();
+    sizes.retain(|(id, _)| id != tab_id);
+    if let Some((width, height)) = size {
+        sizes.push((tab_id.to_string(), LogicalSize { width, height }));
+    }
+}
+
+/// 记录用户调整后的标签内容窗口尺寸
+pub fn set_content_size(app: &AppHandle<Wry>, tab_id: &str, size: LogicalSize<f64>) {
+    if size.width < MIN_CONTENT_SIZE || size.height < MIN_CONTENT_SIZE {
+        return;
+    }
+
+    let current = content_size(tab_id);
+    if (current.width - size.width).abs() < 1.0 && (current.height - size.height).abs() < 1.0 {
+        return;
+    }
+
+    remember_tab_size(tab_id, Some((size.width, size.height)));
+
+    let listener = *TAB_SIZE_LISTENER.lock().unwrap();
+    if let Some(listener) = listener {
+        listener(app, tab_id, size);
+    }
+}
+
+/// 注册标签尺寸调整后的回调
+pub fn on_tab_size_changed(listener: fn(&AppHandle<Wry>, &str, LogicalSize<f64>)) {
+    *TAB_SIZE_LISTENER.lock().unwrap() = Some(listener);
+}
+
+/// 记录分离窗口当前的位置和尺寸（最小化时忽略）
+pub fn remember_detached_geometry(app: &AppHandle<Wry>, tab_id: &str, window: &WebviewWindow<Wry>) {
+    if window.is_minimized().unwrap_or(false) {
+        return;
+    }
+    let (Ok(position), Ok(size), Ok(factor)) = (
+        window.outer_position(),
+        window.inner_size(),
+        window.scale_factor(),
+    ) else {
+        return;
+    };
+    let size = size.to_logical::<f64>(factor);
+    if size.width < MIN_CONTENT_SIZE || size.height < MIN_CONTENT_SIZE {
+        return;
+    }
+
+    let geometry = DetachedGeometry {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+    };
+    PENDING_DETACHED.set(tab_id.to_string(), geometry);
+    schedule_save(app);
+}
+
+/// 读取分离窗口记录的位置和尺寸，并限制在当前连接的显示器范围内
+pub async fn load_detached_geometry(
+    app_state: &AppState,
+    tab_id: &str,
+) -> Result<Option<DetachedGeometry>> {
+    let saved = match PENDING_DETACHED.get(tab_id) {
+        Some(geometry) => Some(geometry),
+        None => SettingService::get(app_state.db(), &detached_key(tab_id))
+            .await?
+            .and_then(|value| serde_json::from_str::<DetachedGeometry>(&value).ok()),
+    };
+    let Some(saved) = saved else {
+        return Ok(None);
+    };
+
+    let app = app_state.app_handle();
+    let (max_width, max_height) = largest_monitor_size(&app);
+    let width = saved
+        .width
+        .clamp(MIN_CONTENT_SIZE, max_width.max(MIN_CONTENT_SIZE));
+    let height = saved
+        .height
+        .clamp(MIN_CONTENT_SIZE, max_height.max(MIN_CONTENT_SIZE));
+    let position = clamp_to_monitors(
+        &app,
+        PhysicalPosition::new(saved.x, saved.y),
+        PhysicalSize::new(width as u32, height as u32),
+    );
+
+    Ok(Some(DetachedGeometry {
+        x: position.x,
+        y: position.y,
+        width,
+        height,
+    }))
+}
+
+/// 删除已不存在的标签记录的分离窗口位置和尺寸
+pub async fn forget_detached_geometry(app_state: &AppState, tab_ids: &[String]) -> Result<()> {
+    if tab_ids.is_empty() {
+        return Ok(());
+    }
+
+    for tab_id in tab_ids {
+        PENDING_DETACHED.discard(tab_id);
+    }
+    SettingService::delete_keys(
+        app_state.db(),
+        tab_ids.iter().map(|id| detached_key(id)).collect(),
+    )
+    .await
+}
+
+/// 防抖保存窗口位置和尺寸
+pub fn schedule_save(app: &AppHandle<Wry>) {
+    let app_handle = app.clone();
+    PENDING_DETACHED.schedule(async move {
+        if let Err(e) = save(&app_handle).await {
+            println!("[WindowFeature] Failed to save window geometry: {}", e);
+        }
+    });
+}
+
+async fn save(app: &AppHandle<Wry>) -> Result<()> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Ok(());
+    };
+
+    // 分离窗口逐个写入，某一个失败不影响其它窗口和主窗口的设置
+    save_detached(&state).await;
+
+    if let Some(window) = app.get_webview_window("main") {
+        // 隐藏时的位置不可靠（部分平台会返回屏幕外坐标），贴边隐藏时位于屏幕外
+        if window.is_visible().unwrap_or(false) && !manager::is_dock_hidden() {
+            let position = window.outer_position()?;
+            let saved = SavedPosition {
+                x: position.x,
+                y: position.y,
+            };
+            SettingService::set(
+                state.db(),
+                MAIN_POSITION_KEY,
+                &serde_json::to_string(&saved)?,
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 写入分离窗口的位置和尺寸
+async fn save_detached(state: &AppState) {
+    let db = state.db();
+    let failed = PENDING_DETACHED
+        .flush(|tab_id, geometry| async move {
+            let value = serde_json::to_string(&geometry)?;
+            SettingService::set(db, &detached_key(&tab_id), &value)
+                .await
+                .map(|_| ())
+        })
+        .await;
+    for (tab_id, e) in failed {
+        println!(
+            "[WindowFeature] Failed to save detached window of tab {}: {}",
+            tab_id, e
+        );
+    }
+}
+
+/// 启动时恢复主窗口位置，并限制在当前连接的显示器范围内
+pub async fn restore(app_state: &AppState) -> Result<()> {
+    let app = app_state.app_handle();
+    let db = app_state.db();
+
+    let saved_position = SettingService::get(db, MAIN_POSITION_KEY)
+        .await?
+        .and_then(|value| serde_json::from_str::<SavedPosition>(&value).ok());
+    if let (Some(saved), Some(window)) = (saved_position, app.get_webview_window("main")) {
+        let size = window.outer_size()?;
+        let position = clamp_to_monitors(&app, PhysicalPosition::new(saved.x, saved.y), size);
+        window.set_position(Position::Physical(position))?;
+    }
+
+    // 恢复位置后再吸附到停靠边缘
+    let dock = load_dock_settings(app_state).await?;
+    manager::apply_dock_settings(&app, dock);
+
+    Ok(())
+}
+
+/// 读取停靠设置
+pub async fn load_dock_settings(app_state: &AppState) -> Result<DockSettings> {
+    Ok(SettingService::get(app_state.db(), DOCK_KEY)
+        .await?
+        .and_then(|value| serde_json::from_str(&value).ok())
+        .unwrap_or_default())
+}
+
+/// 保存停靠设置（拖动吸附时在后台写入）
+pub fn save_dock_settings(app: &AppHandle<Wry>, settings: DockSettings) {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let Some(state) = app_handle.try_state::<AppState>() else {
+            return;
+        };
+        let result = match serde_json::to_string(&settings) {
+            Ok(value) => SettingService::set(state.db(), DOCK_KEY, &value)
+                .await
+                .map(|_| ()),
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = result {
+            println!("[WindowFeature] Failed to save dock settings: {}", e);
+        }
+    });
+}
+
+/// 将窗口位置限制在某个显示器内：优先选择包含窗口左上角的显示器，否则使用主显示器
+fn clamp_to_monitors(
+    app: &AppHandle<Wry>,
+    position: PhysicalPosition<i32>,
+    size: PhysicalSize<u32>,
+) -> PhysicalPosition<i32> {
+    let monitors = app.available_monitors().unwrap_or_default();
+    let contains = |monitor: &tauri::Monitor| {
+        let origin = monitor.position();
+        let extent = monitor.size();
+        position.x >= origin.x
+            && position.y >= origin.y
+            && position.x < origin.x + extent.width as i32
+            && position.y < origin.y + extent.height as i32
+    };
+
+    let monitor = monitors
+        .iter()
+        .find(|m| contains(m))
+        .cloned()
+        .or_else(|| app.primary_monitor().ok().flatten())
+        .or_else(|| monitors.first().cloned());
+    let Some(monitor) = monitor else {
+        return position;
+    };
+
+    let origin = monitor.position();
+    let extent = monitor.size();
+    let max_x = origin.x + (extent.width as i32 - size.width as i32).max(0);
+    let max_y = origin.y + (extent.height as i32 - size.height as i32).max(0);
+
+    PhysicalPosition::new(
+        position.x.clamp(origin.x, max_x),
+        position.y.clamp(origin.y, max_y),
+    )
+}
+
+/// 所有显示器中最大的逻辑宽度和高度
+fn largest_monitor_size(app: &AppHandle<Wry>) -> (f64, f64) {
+    let monitors = app.available_monitors().unwrap_or_default();
+    if monitors.is_empty() {
+        return (f64::MAX, f64::MAX);
+    }
+
+    monitors
+        .iter()
+        .fold((0.0, 0.0), |(width, height), monitor| {
+            let size = monitor.size().to_logical::<f64>(monitor.scale_factor());
+            (f64::max(width, size.width), f64::max(height, size.height))
+        })
+}
+
+fn detached_key(tab_id: &str) -> String {
+    format!("{}{}", DETACHED_PREFIX, tab_id)
+}
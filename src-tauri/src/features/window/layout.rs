@@ -0,0 +1,318 @@
+use std::sync::Mutex;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::core::AppState;
+use crate::features::settings::core::service::SettingService;
+
+/// 内容窗口布局设置
+const LAYOUT_KEY: &str = "window.content_layout";
+const DEFAULT_GAP: f64 = 10.0;
+
+/// 内容窗口相对主窗口的位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentLayout {
+    Below,
+    Above,
+    Left,
+    Right,
+    /// 在工作区居中，覆盖在主窗口之上
+    Overlay,
+    /// 优先放在下方，空间不足时依次尝试上方、右侧、左侧
+    #[default]
+    Auto,
+}
+
+/// 布局设置
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LayoutSettings {
+    #[serde(default)]
+    pub mode: ContentLayout,
+    /// 与主窗口的间隙（逻辑像素，按显示器缩放）
+    #[serde(default = "default_gap")]
+    pub gap: f64,
+}
+
+impl Default for LayoutSettings {
+    fn default() -> Self {
+        DEFAULT_LAYOUT
+    }
+}
+
+const DEFAULT_LAYOUT: LayoutSettings = LayoutSettings {
+    mode: ContentLayout::Auto,
+    gap: DEFAULT_GAP,
+};
+
+fn default_gap() -> f64 {
+    DEFAULT_GAP
+}
+
+static LAYOUT: Mutex<LayoutSettings> = Mutex::new(DEFAULT_LAYOUT);
+
+/// 物理坐标下的矩形
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Rect {
+    fn right(&self) -> i32 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> i32 {
+        self.y + self.height
+    }
+}
+
+/// 当前布局设置
+pub fn layout_settings() -> LayoutSettings {
+    *LAYOUT.lock().unwrap()
+}
+
+/// 从设置中读取布局
+pub async fn load(app_state: &AppState) -> Result<LayoutSettings> {
+    let settings = SettingService::get(app_state.db(), LAYOUT_KEY)
+        .await?
+        .and_then(|value| serde_json::from_str(&value).ok())
+        .unwrap_or_default();
+    *LAYOUT.lock().unwrap() = settings;
+    Ok(settings)
+}
+
+/// 保存并应用布局设置
+pub async fn save(app_state: &AppState, mut settings: LayoutSettings) -> Result<LayoutSettings> {
+    settings.gap = settings.gap.max(0.0);
+    SettingService::set(
+        app_state.db(),
+        LAYOUT_KEY,
+        &serde_json::to_string(&settings)?,
+    )
+    .await?;
+    *LAYOUT.lock().unwrap() = settings;
+    Ok(settings)
+}
+
+/// 计算内容窗口的位置，结果始终位于工作区内
+///
+/// main / work_area 为物理坐标，content 为内容窗口的物理尺寸，gap 为物理像素
+pub fn content_position(
+    settings: &LayoutSettings,
+    main: Rect,
+    content: (i32, i32),
+    work_area: Rect,
+    gap: i32,
+) -> (i32, i32) {
+    let (width, height) = content;
+    let place = |mode: ContentLayout| match mode {
+        ContentLayout::Below => (main.x, main.bottom() + gap),
+        ContentLayout::Above => (main.x, main.y - gap - height),
+        ContentLayout::Right => (main.right() + gap, main.y),
+        ContentLayout::Left => (main.x - gap - width, main.y),
+        ContentLayout::Overlay | ContentLayout::Auto => (
+            work_area.x + (work_area.width - width) / 2,
+            work_area.y + (work_area.height - height) / 2,
+        ),
+    };
+    let fits = |mode: ContentLayout| match mode {
+        ContentLayout::Below => main.bottom() + gap + height <= work_area.bottom(),
+        ContentLayout::Above => main.y - gap - height >= work_area.y,
+        ContentLayout::Right => main.right() + gap + width <= work_area.right(),
+        ContentLayout::Left => main.x - gap - width >= work_area.x,
+        ContentLayout::Overlay | ContentLayout::Auto => true,
+    };
+
+    let mode = match settings.mode {
+        ContentLayout::Auto => [
+            ContentLayout::Below,
+            ContentLayout::Above,
+            ContentLayout::Right,
+            ContentLayout::Left,
+        ]
+        .into_iter()
+        .find(|mode| fits(*mode))
+        // 都放不下时选择上下中空间较大的一侧，再由下面的限制拉回工作区
+        .unwrap_or(
+            if main.y - work_area.y > work_area.bottom() - main.bottom() {
+                ContentLayout::Above
+            } else {
+                ContentLayout::Below
+            },
+        ),
+        mode => mode,
+    };
+
+    let (x, y) = place(mode);
+    (
+        x.clamp(work_area.x, (work_area.right() - width).max(work_area.x)),
+        y.clamp(work_area.y, (work_area.bottom() - height).max(work_area.y)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORK_AREA: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 1920,
+        height: 1040,
+    };
+    const CONTENT: (i32, i32) = (800, 400);
+    const GAP: i32 = 10;
+
+    fn settings(mode: ContentLayout) -> LayoutSettings {
+        LayoutSettings {
+            mode,
+            gap: DEFAULT_GAP,
+        }
+    }
+
+    fn main_at(x: i32, y: i32) -> Rect {
+        Rect {
+            x,
+            y,
+            width: 600,
+            height: 60,
+        }
+    }
+
+    #[test]
+    fn fixed_modes_place_next_to_main_window() {
+        let main = main_at(500, 450);
+        let position = |mode| content_position(&settings(mode), main, CONTENT, WORK_AREA, GAP);
+
+        assert_eq!(position(ContentLayout::Below), (500, 520));
+        assert_eq!(position(ContentLayout::Above), (500, 40));
+        assert_eq!(position(ContentLayout::Right), (1110, 450));
+        assert_eq!(position(ContentLayout::Left), (0, 450));
+    }
+
+    #[test]
+    fn overlay_centers_in_work_area() {
+        let main = main_at(100, 100);
+        assert_eq!(
+            content_position(&settings(ContentLayout::Overlay), main, CONTENT, WORK_AREA, GAP),
+            (560, 320)
+        );
+    }
+
+    #[test]
+    fn auto_prefers_below_then_above_right_left() {
+        let auto = settings(ContentLayout::Auto);
+
+        // 下方放得下
+        let main = main_at(500, 100);
+        assert_eq!(content_position(&auto, main, CONTENT, WORK_AREA, GAP), (500, 170));
+
+        // 下方不够，上方放得下
+        let main = main_at(500, 900);
+        assert_eq!(content_position(&auto, main, CONTENT, WORK_AREA, GAP), (500, 490));
+
+        // 上下都不够时依次尝试右侧、左侧
+        let tall = (400, 1000);
+        let main = main_at(100, 500);
+        assert_eq!(content_position(&auto, main, tall, WORK_AREA, GAP), (710, 40));
+        let main = main_at(1400, 500);
+        assert_eq!(content_position(&auto, main, tall, WORK_AREA, GAP), (990, 40));
+    }
+
+    #[test]
+    fn auto_falls_back_to_larger_vertical_side() {
+        let auto = settings(ContentLayout::Auto);
+        let huge = (1900, 1000);
+
+        // 上方空间较大：放在上方并拉回工作区
+        let main = Rect {
+            x: 10,
+            y: 700,
+            width: 1900,
+            height: 60,
+        };
+        assert_eq!(content_position(&auto, main, huge, WORK_AREA, GAP), (10, 0));
+
+        // 下方空间较大：放在下方并拉回工作区
+        let main = Rect { y: 200, ..main };
+        assert_eq!(content_position(&auto, main, huge, WORK_AREA, GAP), (10, 40));
+    }
+
+    #[test]
+    fn clamps_to_screen_edges() {
+        // 主窗口贴近右下角，下方/右侧放置会超出工作区
+        let main = main_at(1700, 1000);
+        assert_eq!(
+            content_position(&settings(ContentLayout::Below), main, CONTENT, WORK_AREA, GAP),
+            (1120, 640)
+        );
+        assert_eq!(
+            content_position(&settings(ContentLayout::Right), main, CONTENT, WORK_AREA, GAP),
+            (1120, 640)
+        );
+
+        // 主窗口贴近左上角，上方/左侧放置会超出工作区
+        let main = main_at(-50, 0);
+        assert_eq!(
+            content_position(&settings(ContentLayout::Above), main, CONTENT, WORK_AREA, GAP),
+            (0, 0)
+        );
+        assert_eq!(
+            content_position(&settings(ContentLayout::Left), main, CONTENT, WORK_AREA, GAP),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn content_larger_than_work_area_stays_at_origin() {
+        let main = main_at(500, 100);
+        assert_eq!(
+            content_position(&settings(ContentLayout::Below), main, (2400, 1200), WORK_AREA, GAP),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn mixed_dpi_secondary_monitor() {
+        // 主显示器 1920x1080@1x，副显示器在其左侧，2880x1620@1.5x，任务栏 60 物理像素
+        let scale = 1.5;
+        let work_area = Rect {
+            x: -2880,
+            y: 0,
+            width: 2880,
+            height: 1560,
+        };
+        let main = Rect {
+            x: -2000,
+            y: 300,
+            width: 900,
+            height: 90,
+        };
+        // 与 manager 一致：间隙和内容尺寸按副显示器缩放
+        let gap = (DEFAULT_GAP * scale).round() as i32;
+        let content = ((800.0 * scale) as i32, (600.0 * scale) as i32);
+        let auto = settings(ContentLayout::Auto);
+
+        assert_eq!(gap, 15);
+        assert_eq!(content_position(&auto, main, content, work_area, gap), (-2000, 405));
+
+        // 靠近副显示器右边缘时不会越过到主显示器
+        let main = Rect { x: -700, ..main };
+        assert_eq!(
+            content_position(&auto, main, content, work_area, gap),
+            (-1200, 405)
+        );
+
+        // 靠近副显示器左边缘时不会超出负坐标的工作区
+        let main = Rect { x: -2900, ..main };
+        assert_eq!(
+            content_position(&settings(ContentLayout::Left), main, content, work_area, gap),
+            (-2880, 300)
+        );
+    }
+}
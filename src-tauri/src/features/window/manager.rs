@@ -5,221 +5,172 @@ use tauri::{
     AppHandle, CloseRequestApi, Manager, PhysicalPosition, Position, Runtime,
     WebviewUrl, Window, WebviewWindow, Wry, Size, LogicalSize,
 };
+use serde::{Deserialize, Serialize};
 use url::Url;
 use std::sync::Mutex;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use tauri::async_runtime::JoinHandle;
+use tauri::webview::{PageLoadEvent, PageLoadPayload};
+
+use super::geometry::{self, DetachedGeometry};
+use super::focus::{self, FocusAction, FocusEvent, FocusMachine, FocusTimings, SystemClock};
+use super::split::{self, SplitView};
+use super::{appearance, layout, monitors};
+use crate::core::AppState;
+use crate::features::link_rule::core::engine as link_engine;
+use crate::features::tab::core::models::SplitOrientation;
+use crate::infrastructure::ipc::emit_to_app;
 
 #[cfg(target_os = "macos")]
 use objc2_app_kit::{NSWindow, NSWindowCollectionBehavior};
 
-static MOVE_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
-static MAIN_FOCUSED: AtomicBool = AtomicBool::new(false);
-static CONTENT_FOCUSED: AtomicBool = AtomicBool::new(false);
-static IS_DRAGGING: AtomicBool = AtomicBool::new(false);
-static IS_PINNED: AtomicBool = AtomicBool::new(false);
-static FOCUS_CHECK_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
-static CURRENT_URL: Mutex<Option<String>> = Mutex::new(None);
+/// 焦点/拖动/自动隐藏状态机，窗口事件都转换为状态机事件
+static FOCUS: Mutex<FocusMachine<SystemClock>> =
+    Mutex::new(FocusMachine::new(SystemClock, focus::DEFAULT_TIMINGS));
+/// 等待状态机下一次检查的定时任务
+static FOCUS_TIMER: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+static ACTIVE_CONTENT: Mutex<Option<ActiveContent>> = Mutex::new(None);
+/// 存活的内容 webview（最近使用的在末尾），回收策略见 tab feature 的 suspension
+static CONTENT_POOL: Mutex<Vec<LiveWebview>> = Mutex::new(Vec::new());
+/// 已分离到独立窗口的 tab
+static DETACHED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// 内容窗口 label 前缀，每个 tab 对应一个 `content-<tab_id>` 窗口
+const CONTENT_LABEL_PREFIX: &str = "content-";
+/// 分离窗口 label 前缀，分离出来的 tab 对应一个 `detached-<tab_id>` 窗口
+const DETACHED_LABEL_PREFIX: &str = "detached-";
+const CONTENT_USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.2 Safari/605.1.15";
+
+/// 存活的内容 webview 及其最近活动时间
+#[derive(Debug, Clone)]
+pub struct LiveWebview {
+    pub tab_id: String,
+    pub last_active: Instant,
+}
+
+/// 当前激活的 tab（webview 被销毁后用 url 重新创建）
+#[derive(Debug, Clone)]
+struct ActiveContent {
+    tab_id: String,
+    url: String,
+}
 
 /// 设置内容窗口是否固定（不自动隐藏）
-pub fn set_content_window_pinned(pinned: bool) {
-    IS_PINNED.store(pinned, Ordering::SeqCst);
+pub fn set_content_window_pinned(app: &AppHandle<Wry>, pinned: bool) {
+    dispatch_focus_event(app, FocusEvent::Pinned(pinned));
 }
 
-/// 设置当前激活的 URL
-pub fn set_current_url(url: String) {
-    let mut guard = CURRENT_URL.lock().unwrap();
-    *guard = Some(url);
+/// 内容窗口当前是否固定
+pub fn is_content_window_pinned() -> bool {
+    FOCUS.lock().unwrap().is_pinned()
 }
 
-/// 获取当前激活的 URL
-pub fn get_current_url() -> Option<String> {
-    let guard = CURRENT_URL.lock().unwrap();
-    guard.clone()
+/// 原生对话框打开/关闭（打开期间窗口失去焦点不自动隐藏）
+pub fn set_modal_open(app: &AppHandle<Wry>, open: bool) {
+    let event = if open {
+        FocusEvent::ModalOpened
+    } else {
+        FocusEvent::ModalClosed
+    };
+    dispatch_focus_event(app, event);
 }
 
-/// 清除当前激活的 URL
-pub fn clear_current_url() {
-    let mut guard = CURRENT_URL.lock().unwrap();
-    *guard = None;
+/// 修改自动隐藏相关的延迟
+pub fn set_focus_timings(timings: FocusTimings) {
+    FOCUS.lock().unwrap().set_timings(timings);
 }
 
-/// JS 注入脚本：拦截 window.open 和 target=_blank / 跨域链接，改用系统默认浏览器打开。
-// 拦截 window.open / 外部链接，改用 Tauri opener 插件从系统浏览器打开。
-const EXTERNAL_OPEN_SCRIPT: &str = r#"
-(async () => {
-  console.log("[NoB] Starting injection script...");
+/// 主窗口是否正在被拖动
+pub fn is_dragging() -> bool {
+    FOCUS.lock().unwrap().is_dragging()
+}
 
-  // --- Helper Functions ---
-  const getInvoker = () => {
-    const tauri = window.__TAURI__;
-    return tauri?.core?.invoke ?? tauri?.invoke;
-  };
+/// 设置当前激活的 tab
+pub fn set_active_content(tab_id: String, url: String) {
+    let mut guard = ACTIVE_CONTENT.lock().unwrap();
+    *guard = Some(ActiveContent { tab_id, url });
+}
 
-  const invokeOpen = (url) => {
-    const invoker = getInvoker();
-    if (!invoker) {
-      console.warn("[NoB] __TAURI__ invoke not available");
-      return;
+/// 更新 tab 的最新 URL（仅当其为当前激活 tab 时记录）
+pub fn update_active_content_url(tab_id: &str, url: String) {
+    let mut guard = ACTIVE_CONTENT.lock().unwrap();
+    if let Some(active) = guard.as_mut() {
+        if active.tab_id == tab_id {
+            active.url = url;
+        }
     }
-    invoker("plugin:opener|open_url", { url, with: null }).catch((e) =>
-      console.warn("[NoB] opener open_url rejected", e)
-    );
-  };
-
-  const isExternal = (href) => {
-    try {
-      const u = new URL(href, window.location.href);
-      return u.origin !== window.location.origin;
-    } catch {
-      return false;
-    }
-  };
-
-  const safeInit = async (name, fn) => {
-    try {
-      await fn();
-      console.log(`[NoB] ${name} initialized successfully.`);
-    } catch (e) {
-      console.error(`[NoB] ${name} initialization failed:`, e);
-    }
-  };
-
-  // --- Feature Initializers ---
-
-  // 1. Window Open Interceptor
-  await safeInit("WindowOpenInterceptor", async () => {
-    const originalOpen = window.open;
-    window.open = function(url, target, features) {
-      if (typeof url === "string") {
-        invokeOpen(url);
-        return null;
-      }
-      return originalOpen?.apply(this, [url, target, features]);
-    };
-  });
-
-  // 2. Navigation Reporter
-  await safeInit("NavigationReporter", async () => {
-    const reportNavigation = () => {
-      const invoker = getInvoker();
-      if (invoker) {
-        invoker("tabs_report_navigation", { url: window.location.href }).catch(e => 
-          console.warn("[NoB] report navigation failed", e)
-        );
-        // Also report title on navigation as a fallback
-        invoker("tabs_report_title", { title: document.title || "Untitled" }).catch(e => 
-          console.warn("[NoB] report title failed", e)
-        );
-      }
-    };
+}
 
-    window.addEventListener("popstate", reportNavigation);
-    window.addEventListener("hashchange", reportNavigation);
+/// 获取当前激活的 tab id
+pub fn active_content_tab_id() -> Option<String> {
+    let guard = ACTIVE_CONTENT.lock().unwrap();
+    guard.as_ref().map(|active| active.tab_id.clone())
+}
 
-    const originalPushState = history.pushState;
-    history.pushState = function(...args) {
-      const result = originalPushState.apply(this, args);
-      reportNavigation();
-      return result;
-    };
+/// 获取当前激活 tab 的 URL
+pub fn active_content_url() -> Option<String> {
+    let guard = ACTIVE_CONTENT.lock().unwrap();
+    guard.as_ref().map(|active| active.url.clone())
+}
 
-    const originalReplaceState = history.replaceState;
-    history.replaceState = function(...args) {
-      const result = originalReplaceState.apply(this, args);
-      reportNavigation();
-      return result;
-    };
+/// 清除当前激活的 tab
+pub fn clear_active_content() {
+    let mut guard = ACTIVE_CONTENT.lock().unwrap();
+    *guard = None;
+}
 
-    // Initial report
-    reportNavigation();
-  });
-
-  // 3. Title Reporter
-  await safeInit("TitleReporter", async () => {
-    const reportTitle = () => {
-      const invoker = getInvoker();
-      if (invoker) {
-        invoker("tabs_report_title", { title: document.title || "Untitled" }).catch(e => 
-          console.warn("[NoB] report title failed", e)
-        );
-      }
-    };
+/// tab 对应的内容窗口 label
+pub fn content_label(tab_id: &str) -> String {
+    format!("{}{}", CONTENT_LABEL_PREFIX, tab_id)
+}
 
-    const startObserver = () => {
-      const target = document.querySelector('title') || document.head;
-      if (target) {
-        new MutationObserver(reportTitle).observe(target, { 
-          subtree: true, 
-          characterData: true, 
-          childList: true 
-        });
-        console.log("[NoB] Title observer started on", target);
-        // Report immediately when observer starts
-        reportTitle();
-      } else {
-        console.warn("[NoB] No title or head found to observe");
-      }
-    };
+/// 从内容窗口 label 解析 tab id
+pub fn tab_id_from_label(label: &str) -> Option<&str> {
+    label.strip_prefix(CONTENT_LABEL_PREFIX)
+}
 
-    if (document.readyState === "loading") {
-      document.addEventListener("DOMContentLoaded", () => {
-        startObserver();
-        reportTitle();
-      });
-    } else {
-      startObserver();
-      reportTitle();
-    }
-    
-    // Also report on window load to ensure final title is captured
-    window.addEventListener("load", reportTitle);
-  });
-
-  // 4. External Link Interceptor
-  await safeInit("ExternalLinkInterceptor", async () => {
-    document.addEventListener("click", (e) => {
-      // console.log("[NoB] click event detected");
-      const el = e.target;
-      if (!(el instanceof Element)) return;
-      const anchor = el.closest("a");
-      if (!anchor || !anchor.href) return;
-      
-      const shouldOpenExternally = anchor.target === "_blank" || isExternal(anchor.href);
-      
-      if (shouldOpenExternally) {
-        console.log("[NoB] external link intercepted", anchor.href);
-        e.preventDefault();
-        invokeOpen(anchor.href);
-      }
-    }, true);
-  });
-
-  // 5. URL Responder
-  await safeInit("UrlResponder", async () => {
-    const tauri = window.__TAURI__;
-    const event = tauri?.event;
-    const invoker = getInvoker();
-
-    if (event && invoker) {
-      console.log("[NoB] Listening for 'get-url' event");
-      await event.listen("get-url", () => {
-        console.log("[NoB] Received 'get-url' request, responding with:", window.location.href);
-        invoker("tabs_respond_url", { url: window.location.href }).catch(e => 
-          console.warn("[NoB] respond url failed", e)
-        );
-      });
-    } else {
-      console.warn("[NoB] Tauri event or invoker not available for UrlResponder");
-    }
-  });
+/// tab 对应的分离窗口 label
+pub fn detached_label(tab_id: &str) -> String {
+    format!("{}{}", DETACHED_LABEL_PREFIX, tab_id)
+}
+
+/// 从分离窗口 label 解析 tab id
+pub fn tab_id_from_detached_label(label: &str) -> Option<&str> {
+    label.strip_prefix(DETACHED_LABEL_PREFIX)
+}
+
+/// tab 是否已分离到独立窗口
+pub fn is_detached(tab_id: &str) -> bool {
+    DETACHED.lock().unwrap().iter().any(|id| id == tab_id)
+}
+
+/// 获取当前激活 tab 的内容窗口
+pub fn active_content_window(app: &AppHandle<Wry>) -> Option<WebviewWindow<Wry>> {
+    let tab_id = active_content_tab_id()?;
+    app.get_webview_window(&content_label(&tab_id))
+}
 
-})();
-"#;
+/// 获取分屏中与激活 tab 并排显示的内容窗口
+pub fn split_partner_window(app: &AppHandle<Wry>) -> Option<WebviewWindow<Wry>> {
+    let tab_id = active_content_tab_id()?;
+    let partner = split::partner(&tab_id)?;
+    app.get_webview_window(&content_label(&partner.tab_id))
+}
 
 /// 显示主窗口并设置焦点
 /// macOS: 同时显示 Dock 图标
 pub fn show_main_window(app: &AppHandle<Wry>) -> Result<(), String> {
+    if is_dock_hidden() {
+        reveal_docked_window(app);
+        return Ok(());
+    }
+    if monitors::summon_on_cursor_monitor() {
+        monitors::move_main_to_cursor_monitor(app);
+        if dock_settings().edge != DockEdge::None {
+            apply_dock_settings(app, dock_settings());
+        }
+    }
     if let Some(window) = app.get_webview_window("main") {
         window.show().map_err(|e| e.to_string())?;
         window.set_focus().map_err(|e| e.to_string())?;
@@ -245,7 +196,9 @@ pub fn hide_main_window(app: &AppHandle<Wry>) -> Result<(), String> {
 /// 切换主窗口显示/隐藏状态
 pub fn toggle_main_window(app: &AppHandle<Wry>) -> Result<(), String> {
     if let Some(window) = app.get_webview_window("main") {
-        if window.is_visible().unwrap_or(false) {
+        if is_dock_hidden() {
+            show_main_window(app)
+        } else if window.is_visible().unwrap_or(false) {
             hide_main_window(app)
         } else {
             show_main_window(app)
@@ -280,33 +233,45 @@ pub fn resize_main_window(app: &AppHandle<Wry>, width: f64, height: f64) -> Resu
     }
 }
 
-/// 同步内容窗口位置（紧贴主窗口下方）
+/// 同步内容窗口位置（按布局设置放在主窗口周围，并限制在当前显示器的工作区内）
+///
+/// 分屏时两个窗格共同占据内容区，按方向和比例划分
 pub fn sync_content_window_position(app: &AppHandle<Wry>) {
-    let main_window = match app.get_webview_window("main") {
-        Some(w) => w,
-        None => return,
-    };
-    let content_window = match app.get_webview_window("content") {
+    if let Some(split) = active_split_view() {
+        sync_split_panes(app, &split);
+        return;
+    }
+
+    let content_window = match active_content_window(app) {
         Some(w) => w,
         None => return,
     };
-
-    // 使用物理坐标以确保精确度
-    let main_pos = match main_window.outer_position() {
-        Ok(p) => p,
-        Err(_) => return,
+    let Some(placement) = content_placement(app) else {
+        return;
     };
-    let main_size = match main_window.outer_size() {
-        Ok(s) => s,
+    let work_area = placement.work_area;
+
+    // 内容窗口不超过工作区
+    let _ = content_window.set_max_size(Some(Size::Physical(tauri::PhysicalSize::new(
+        work_area.width as u32,
+        work_area.height as u32,
+    ))));
+    let content_size = match content_window.outer_size() {
+        Ok(s) => (
+            (s.width as i32).min(work_area.width),
+            (s.height as i32).min(work_area.height),
+        ),
         Err(_) => return,
     };
 
-    const GAP: i32 = 10; // 间隙像素
-
-    let new_pos = PhysicalPosition {
-        x: main_pos.x,
-        y: main_pos.y + main_size.height as i32 + GAP,
-    };
+    let (x, y) = layout::content_position(
+        &placement.settings,
+        placement.main,
+        content_size,
+        work_area,
+        placement.gap,
+    );
+    let new_pos = PhysicalPosition { x, y };
 
     // 仅在位置发生变化时更新，避免不必要的重绘/闪烁
     if let Ok(current_pos) = content_window.outer_position() {
@@ -318,75 +283,270 @@ pub fn sync_content_window_position(app: &AppHandle<Wry>) {
     let _ = content_window.set_position(Position::Physical(new_pos));
 }
 
+/// 计算内容区位置所需的主窗口区域、工作区和间隙（物理坐标）
+struct ContentPlacement {
+    settings: layout::LayoutSettings,
+    main: layout::Rect,
+    work_area: layout::Rect,
+    gap: i32,
+    scale: f64,
+}
+
+fn content_placement(app: &AppHandle<Wry>) -> Option<ContentPlacement> {
+    let main_window = app.get_webview_window("main")?;
+
+    // 使用物理坐标以确保精确度
+    let main_pos = main_window.outer_position().ok()?;
+    let main_size = main_window.outer_size().ok()?;
+    // 内容窗口始终放在主窗口所在的显示器上
+    let monitor = monitors::main_monitor(app).or_else(|| app.primary_monitor().ok().flatten())?;
+
+    let work_area = monitor.work_area();
+    let work_area = layout::Rect {
+        x: work_area.position.x,
+        y: work_area.position.y,
+        width: work_area.size.width as i32,
+        height: work_area.size.height as i32,
+    };
+
+    let settings = layout::layout_settings();
+    let main = layout::Rect {
+        x: main_pos.x,
+        y: main_pos.y,
+        width: main_size.width as i32,
+        height: main_size.height as i32,
+    };
+    let scale = monitor.scale_factor();
+    let gap = (settings.gap * scale).round() as i32;
+
+    Some(ContentPlacement {
+        settings,
+        main,
+        work_area,
+        gap,
+        scale,
+    })
+}
+
+/// 激活 tab 所在的分屏组合
+fn active_split_view() -> Option<SplitView> {
+    let tab_id = active_content_tab_id()?;
+    split::active_split().filter(|split| split.contains(&tab_id))
+}
+
+/// 分屏内容区（使用激活 tab 记录的尺寸）和窗格间隙，物理坐标
+fn split_area(app: &AppHandle<Wry>) -> Option<(layout::Rect, i32)> {
+    let placement = content_placement(app)?;
+    let tab_id = active_content_tab_id()?;
+    let work_area = placement.work_area;
+
+    let size = geometry::content_size(&tab_id);
+    let width = ((size.width * placement.scale).round() as i32).min(work_area.width);
+    let height = ((size.height * placement.scale).round() as i32).min(work_area.height);
+    let (x, y) = layout::content_position(
+        &placement.settings,
+        placement.main,
+        (width, height),
+        work_area,
+        placement.gap,
+    );
+
+    let divider = (split::DIVIDER * placement.scale).round() as i32;
+    Some((
+        layout::Rect {
+            x,
+            y,
+            width,
+            height,
+        },
+        divider,
+    ))
+}
+
+/// 按分屏方向和比例放置两个窗格
+fn sync_split_panes(app: &AppHandle<Wry>, split: &SplitView) {
+    let Some((area, divider)) = split_area(app) else {
+        return;
+    };
+    let (primary, secondary) = split::pane_rects(area, split.orientation, split.ratio, divider);
+
+    for (pane, rect) in [(&split.primary, primary), (&split.secondary, secondary)] {
+        let Some(window) = app.get_webview_window(&content_label(&pane.tab_id)) else {
+            continue;
+        };
+        let _ = window.set_max_size(None::<Size>);
+        let size = tauri::PhysicalSize::new(rect.width.max(1) as u32, rect.height.max(1) as u32);
+        if window.outer_size().ok() != Some(size) {
+            let _ = window.set_size(Size::Physical(size));
+        }
+        let position = PhysicalPosition::new(rect.x, rect.y);
+        if window.outer_position().ok() != Some(position) {
+            let _ = window.set_position(Position::Physical(position));
+        }
+    }
+}
+
+/// 用户拖动窗格边缘时按新尺寸调整分屏比例
+fn on_split_pane_resized(app: &AppHandle<Wry>, tab_id: &str, window: &WebviewWindow<Wry>) {
+    let Some(split) = active_split_view() else {
+        return;
+    };
+    let (Some((area, divider)), Ok(size)) = (split_area(app), window.outer_size()) else {
+        return;
+    };
+    let extent = match split.orientation {
+        SplitOrientation::Horizontal => size.width,
+        SplitOrientation::Vertical => size.height,
+    } as i32;
+
+    if let Some(ratio) = split::ratio_from_pane(&split, tab_id, area, extent, divider) {
+        split::update_active_split(&split.id, split.orientation, ratio);
+        sync_split_panes(app, &active_split_view().unwrap_or(split));
+        split::schedule_ratio_save(app);
+    }
+}
+
 /// 处理主窗口调整大小事件
 pub fn on_main_window_resized(app: &AppHandle<Wry>) {
     sync_content_window_position(app);
+    geometry::schedule_save(app);
 }
 
-/// 处理主窗口移动/调整大小事件（防抖：拖动时隐藏，停止后显示）
-pub fn on_main_window_moved(app: &AppHandle<Wry>) {
-    let app_handle = app.clone();
-    
-    // 1. 标记拖动状态并隐藏
-    IS_DRAGGING.store(true, Ordering::SeqCst);
-    hide_content_window(app);
+/// 处理内容窗口调整大小事件，记录用户调整后的尺寸
+pub fn on_content_window_resized(app: &AppHandle<Wry>, label: &str) {
+    let Some(window) = app.get_webview_window(label) else {
+        return;
+    };
+    if let Some(tab_id) = tab_id_from_detached_label(label) {
+        geometry::remember_detached_geometry(app, tab_id, &window);
+        return;
+    }
+    // 分屏窗格的尺寸由比例决定，拖动边缘即调整比例
+    if let Some(tab_id) = tab_id_from_label(label).filter(|id| split::is_split_pane(id)) {
+        if window.is_visible().unwrap_or(false) && !is_dragging() {
+            on_split_pane_resized(app, tab_id, &window);
+        }
+        return;
+    }
+    // 只记录可见的激活窗口，忽略隐藏、移出屏幕时的尺寸变化
+    let is_active = tab_id_from_label(label)
+        .is_some_and(|tab_id| active_content_tab_id().as_deref() == Some(tab_id));
+    if !is_active || !window.is_visible().unwrap_or(false) {
+        return;
+    }
 
-    // 2. 防抖处理
-    let mut task_guard = MOVE_TASK.lock().unwrap();
-    
-    // 取消上一个任务
-    if let Some(task) = task_guard.take() {
-        task.abort();
+    if let (Some(tab_id), Ok(size), Ok(factor)) = (
+        tab_id_from_label(label),
+        window.inner_size(),
+        window.scale_factor(),
+    ) {
+        geometry::set_content_size(app, tab_id, size.to_logical::<f64>(factor));
     }
+}
 
-    // 启动新任务
-    let task = tauri::async_runtime::spawn(async move {
-        // 等待 500ms (或者用户觉得合适的延迟)
-        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-        
-        // 拖动结束
-        IS_DRAGGING.store(false, Ordering::SeqCst);
+/// 处理内容窗口移动事件：只记录分离窗口的位置，内容栏中的窗口由主窗口决定位置
+pub fn on_content_window_moved(app: &AppHandle<Wry>, label: &str) {
+    let Some(tab_id) = tab_id_from_detached_label(label) else {
+        return;
+    };
+    if let Some(window) = app.get_webview_window(label) {
+        geometry::remember_detached_geometry(app, tab_id, &window);
+    }
+}
 
-        // 重新定位并显示
-        sync_content_window_position(&app_handle);
-        if let Some(w) = app_handle.get_webview_window("content") {
-             let _ = w.show();
-             // 保持置顶和焦点可能需要
-             // let _ = w.set_focus(); 
-        }
-    });
-    
-    *task_guard = Some(task);
+/// 处理主窗口移动事件（拖动时隐藏内容窗口，停止移动一段时间后显示）
+pub fn on_main_window_moved(app: &AppHandle<Wry>) {
+    // 贴边隐藏/唤出的动画不视为拖动
+    if DOCK_SLIDING.load(Ordering::SeqCst) || is_dock_hidden() {
+        return;
+    }
+    dispatch_focus_event(app, FocusEvent::MainMoved);
 }
 
-/// 确保内容窗口存在并显示（统一入口）
-/// 如果提供了 URL，则更新并导航；如果没有提供，尝试使用缓存的 URL。
+/// 展示激活 tab 的内容窗口（不存在时以记录的 URL 重新创建）
 /// focus: 是否在显示后聚焦内容窗口
-pub fn present_content_window(app: &AppHandle<Wry>, url: Option<&str>, focus: bool) -> Result<(), String> {
-    // 1. 确定目标 URL
-    let target_url = if let Some(u) = url {
-        set_current_url(u.to_string());
-        Some(u.to_string())
-    } else {
-        get_current_url()
-    };
-
-    let url_str = match target_url {
-        Some(ref u) => u.as_str(),
+pub fn present_active_content_window(app: &AppHandle<Wry>, focus: bool) -> Result<(), String> {
+    let active = ACTIVE_CONTENT.lock().unwrap().clone();
+    match active {
+        Some(active) => present_content_window(app, &active.tab_id, &active.url, false, focus),
         None => {
-            // 如果没有 URL，确保窗口隐藏
+            // 如果没有激活的 tab，确保窗口隐藏
             hide_content_window(app);
-            return Ok(());
-        }, 
-    };
+            Ok(())
+        }
+    }
+}
 
-    let parsed = Url::parse(url_str).map_err(|e| format!("Invalid URL: {}", e))?;
+/// 导航到应用指定的地址（地址栏、刷新、前进后退），链接规则不拦截
+fn navigate_content(window: &WebviewWindow<Wry>, url: Url) -> Result<(), String> {
+    link_engine::expect_navigation(window.label(), &url);
+    window.navigate(url).map_err(|e| e.to_string())
+}
+
+/// 确保 tab 的内容窗口存在并显示（统一入口）
+///
+/// 每个 tab 拥有独立的 webview，切换 tab 只切换显示的窗口，不会重新加载页面。
+/// webview 不存在时以 url 创建；navigate 为 true 时强制导航到 url（刷新、前进后退）。
+/// focus: 是否在显示后聚焦内容窗口
+pub fn present_content_window(
+    app: &AppHandle<Wry>,
+    tab_id: &str,
+    url: &str,
+    navigate: bool,
+    focus: bool,
+) -> Result<(), String> {
+    let parsed = Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
+    let label = content_label(tab_id);
+
+    set_active_content(tab_id.to_string(), url.to_string());
+
+    // 拖动主窗口期间不显示内容窗口，拖动结束后会重新展示
+    if is_dragging() {
+        if navigate {
+            if let Some(window) = app.get_webview_window(&label) {
+                navigate_content(&window, parsed)?;
+            }
+        }
+        return Ok(());
+    }
+
+    // 已分离的 tab 在独立窗口中展示，内容栏不显示任何窗口
+    if is_detached(tab_id) {
+        hide_content_window(app);
+        if let Some(window) = app.get_webview_window(&detached_label(tab_id)) {
+            if navigate {
+                navigate_content(&window, parsed)?;
+            }
+            if focus {
+                let _ = window.unminimize();
+                window.show().map_err(|e| e.to_string())?;
+                window.set_focus().map_err(|e| e.to_string())?;
+            }
+        }
+        return Ok(());
+    }
+
+    // 1. 隐藏其它 tab 的内容窗口（分屏时保留另一个窗格）
+    let partner = split::partner(tab_id);
+    for other in live_content_tab_ids() {
+        if other == tab_id || partner.as_ref().is_some_and(|p| p.tab_id == other) {
+            continue;
+        }
+        if let Some(window) = app.get_webview_window(&content_label(&other)) {
+            if window.is_visible().unwrap_or(false) {
+                let _ = window.hide();
+            }
+        }
+    }
 
     // 2. 检查窗口是否存在
-    if let Some(window) = app.get_webview_window("content") {
-        // 复用已有内容窗口
+    if let Some(window) = app.get_webview_window(&label) {
+        // 复用已有内容窗口，使用该标签记录的尺寸（分屏时由窗格布局决定）
+        if partner.is_none() {
+            apply_content_size(&window, tab_id);
+        }
         sync_content_window_position(app);
-        
+
         let is_visible = window.is_visible().unwrap_or(false);
 
         if !is_visible {
@@ -394,104 +554,333 @@ pub fn present_content_window(app: &AppHandle<Wry>, url: Option<&str>, focus: bo
                 window.show().map_err(|e| e.to_string())?;
                 window.set_focus().map_err(|e| e.to_string())?;
             } else {
-                #[cfg(target_os = "macos")]
-                {
-                    // 记录当前主窗口是否聚焦
-                    let was_main_focused = MAIN_FOCUSED.load(Ordering::SeqCst);
-
-                    window.show().map_err(|e| e.to_string())?;
-                    
-                    // 如果之前主窗口是激活的，强制重新聚焦主窗口
-                    if was_main_focused {
-                        let app_handle = app.clone();
-                        tauri::async_runtime::spawn(async move {
-                            // 延迟一小段时间以确保窗口显示处理完成
-                            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
-                            print!("[NoB] restoring focus to main window\n");
-                            if let Some(main_win) = app_handle.get_webview_window("main") {
-                                let _ = main_win.set_focus();
-                            }
-                        });
-                    }
-                }
-                #[cfg(not(target_os = "macos"))]
-                {
-                    window.show().map_err(|e| e.to_string())?;
-                }
+                show_content_window_unfocused(app, &window)?;
             }
         } else if focus {
             // 如果已经显示且需要焦点，则聚焦
             window.set_focus().map_err(|e| e.to_string())?;
         }
-        
-        // 只有当提供了新的 URL 时才导航，避免刷新
-        if url.is_some() {
-             window
-                .navigate(parsed.clone())
-                .map_err(|e| e.to_string())?;
+
+        // 只有明确要求时才导航，避免刷新
+        if navigate {
+            navigate_content(&window, parsed)?;
         }
-        Ok(())
     } else {
         // 新建内容窗口
-        let window = tauri::WebviewWindowBuilder::new(app, "content", WebviewUrl::External(parsed))
-            .title("NoB 内容")
-            .inner_size(1100.0, 780.0)
-            .position(120.0, 120.0)
-            .decorations(false)
-            .always_on_top(true)
-            .accept_first_mouse(true)
-            .focused(focus) // 设置初始焦点状态
-            .user_agent("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.2 Safari/605.1.15")
-            .initialization_script(EXTERNAL_OPEN_SCRIPT)
-            .on_navigation(|url| {
-                // 允许所有导航，但可以在这里记录 URL 变化
-                // 注意：on_navigation 在 Rust 侧触发，比 JS 更可靠，但可能不包含 pushState
-                // 我们主要依赖 JS 注入来处理 SPA，这里作为补充或调试
-                println!("[NoB] on_navigation: {}", url);
-                true
-            })
-            .build()
-            .map_err(|e| e.to_string())?;
-        
-        #[cfg(target_os = "macos")]
-        {
-          use objc2_app_kit::NSColor;
-          unsafe {
-            if let Ok(raw) = window.ns_window() {
-                let ns_window: &NSWindow = &*raw.cast();
-                
-                ns_window.setOpaque(false);
-                let clear = NSColor::clearColor();
-                ns_window.setBackgroundColor(Some(&clear));
-                
-                if let Some(content) = ns_window.contentView() {
-                    if let Some(frame) = content.superview() {
-                        frame.setWantsLayer(true);
-                        if let Some(layer) = frame.layer() {
-                            layer.setCornerRadius(12.0);
-                            layer.setMasksToBounds(true);
-                            layer.setBorderWidth(0.0);
-                        }
+        build_content_window(app, &label, parsed, focus)?;
+        sync_content_window_position(app);
+    }
+
+    // 3. 分屏时同时显示另一个窗格（不抢夺焦点）
+    if let Some(partner) = partner {
+        present_split_partner(app, &partner)?;
+        sync_content_window_position(app);
+    }
+
+    touch_content_webview(tab_id);
+    Ok(())
+}
+
+/// 确保分屏另一个窗格的 webview 存在并显示
+fn present_split_partner(app: &AppHandle<Wry>, partner: &split::SplitPane) -> Result<(), String> {
+    let label = content_label(&partner.tab_id);
+    match app.get_webview_window(&label) {
+        Some(window) => {
+            if !window.is_visible().unwrap_or(false) {
+                show_content_window_unfocused(app, &window)?;
+            }
+        }
+        None => {
+            let url = Url::parse(&partner.url).map_err(|e| format!("Invalid URL: {}", e))?;
+            build_content_window(app, &label, url, false)?;
+        }
+    }
+    touch_content_webview(&partner.tab_id);
+    Ok(())
+}
+
+/// 销毁 tab 的内容 webview（关闭或挂起 tab 时调用），包括分离窗口
+pub fn close_content_webview(app: &AppHandle<Wry>, tab_id: &str) {
+    CONTENT_POOL.lock().unwrap().retain(|live| live.tab_id != tab_id);
+    if let Some(window) = app.get_webview_window(&content_label(tab_id)) {
+        let _ = window.destroy();
+    }
+    if is_detached(tab_id) {
+        attach_content_window(app, tab_id);
+    }
+}
+
+/// 内容窗口被销毁时从 webview 池中移除，并清除链接规则记录的页面
+///
+/// 用户直接关闭分离窗口时视为放回内容栏，并通知前端
+pub fn on_content_window_destroyed(app: &AppHandle<Wry>, label: &str) {
+    link_engine::forget_window(label);
+    if let Some(tab_id) = tab_id_from_label(label) {
+        CONTENT_POOL.lock().unwrap().retain(|live| live.tab_id != tab_id);
+    } else if let Some(tab_id) = tab_id_from_detached_label(label) {
+        let mut detached = DETACHED.lock().unwrap();
+        let was_detached = detached.iter().any(|id| id == tab_id);
+        detached.retain(|id| id != tab_id);
+        drop(detached);
+
+        if was_detached {
+            let _ = emit_to_app(
+                app,
+                "tabs-changed",
+                serde_json::json!({ "action": "attached", "data": { "id": tab_id } }),
+            );
+        }
+    }
+}
+
+/// 将 tab 分离到独立窗口（可移动、可调整大小的普通窗口），按记录的位置和尺寸打开
+///
+/// 内容栏中的 webview 会被销毁，页面在独立窗口中按 url 重新加载
+pub fn detach_content_window(
+    app: &AppHandle<Wry>,
+    tab_id: &str,
+    url: &str,
+    title: &str,
+    saved: Option<DetachedGeometry>,
+) -> Result<(), String> {
+    let label = detached_label(tab_id);
+    if let Some(window) = app.get_webview_window(&label) {
+        let _ = window.unminimize();
+        window.show().map_err(|e| e.to_string())?;
+        return window.set_focus().map_err(|e| e.to_string());
+    }
+
+    let parsed = Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
+    close_content_webview(app, tab_id);
+
+    let size = saved
+        .map(|saved| LogicalSize::new(saved.width, saved.height))
+        .unwrap_or_else(|| geometry::content_size(tab_id));
+    let mut builder = tauri::WebviewWindowBuilder::new(app, &label, WebviewUrl::External(parsed))
+        .title(if title.is_empty() { "NoB" } else { title })
+        .inner_size(size.width, size.height)
+        .decorations(true)
+        .resizable(true)
+        .focused(true)
+        .user_agent(CONTENT_USER_AGENT)
+        .theme(appearance::window_theme(app))
+        .on_page_load(on_content_page_load)
+        .on_navigation(link_engine::navigation_handler(app, &label));
+    for script in initialization_scripts(app) {
+        builder = builder.initialization_script(script);
+    }
+    if saved.is_none() {
+        builder = builder.center();
+    }
+    let window = builder.build().map_err(|e| e.to_string())?;
+    if let Some(saved) = saved {
+        let _ = window.set_position(Position::Physical(PhysicalPosition::new(saved.x, saved.y)));
+    }
+
+    DETACHED.lock().unwrap().push(tab_id.to_string());
+    Ok(())
+}
+
+/// 关闭 tab 的分离窗口（放回内容栏），关闭前记录其位置和尺寸
+pub fn attach_content_window(app: &AppHandle<Wry>, tab_id: &str) {
+    DETACHED.lock().unwrap().retain(|id| id != tab_id);
+    if let Some(window) = app.get_webview_window(&detached_label(tab_id)) {
+        geometry::remember_detached_geometry(app, tab_id, &window);
+        let _ = window.destroy();
+    }
+}
+
+/// 关闭所有分离窗口，不记录位置和尺寸（替换导入后标签已不存在时调用）
+pub fn close_detached_windows(app: &AppHandle<Wry>) {
+    let tab_ids = std::mem::take(&mut *DETACHED.lock().unwrap());
+    for tab_id in tab_ids {
+        if let Some(window) = app.get_webview_window(&detached_label(&tab_id)) {
+            let _ = window.destroy();
+        }
+    }
+}
+
+/// 更新分离窗口的标题（页面上报标题时调用）
+pub fn set_detached_title(app: &AppHandle<Wry>, tab_id: &str, title: &str) {
+    if let Some(window) = app.get_webview_window(&detached_label(tab_id)) {
+        let _ = window.set_title(title);
+    }
+}
+
+/// 当前存活的内容 webview（按最近使用排序，最近的在末尾）
+pub fn live_content_webviews() -> Vec<LiveWebview> {
+    CONTENT_POOL.lock().unwrap().clone()
+}
+
+/// tab 是否拥有存活的 webview（否则处于挂起状态）
+pub fn is_content_live(tab_id: &str) -> bool {
+    CONTENT_POOL
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|live| live.tab_id == tab_id)
+}
+
+/// 当前存活的内容 webview 对应的 tab id
+fn live_content_tab_ids() -> Vec<String> {
+    live_content_webviews()
+        .into_iter()
+        .map(|live| live.tab_id)
+        .collect()
+}
+
+/// 将 tab 标记为最近使用
+fn touch_content_webview(tab_id: &str) {
+    let mut pool = CONTENT_POOL.lock().unwrap();
+    pool.retain(|live| live.tab_id != tab_id);
+    pool.push(LiveWebview {
+        tab_id: tab_id.to_string(),
+        last_active: Instant::now(),
+    });
+}
+
+/// 将 tab 的内容窗口调整为记录的尺寸（重置尺寸后调用）
+pub fn resize_content_window(app: &AppHandle<Wry>, tab_id: &str) {
+    if let Some(window) = app.get_webview_window(&content_label(tab_id)) {
+        apply_content_size(&window, tab_id);
+    }
+}
+
+/// 将内容窗口调整为 tab 记录的尺寸
+fn apply_content_size(window: &WebviewWindow<Wry>, tab_id: &str) {
+    let size = geometry::content_size(tab_id);
+    if let (Ok(current), Ok(factor)) = (window.inner_size(), window.scale_factor()) {
+        let current = current.to_logical::<f64>(factor);
+        if (current.width - size.width).abs() < 1.0 && (current.height - size.height).abs() < 1.0 {
+            return;
+        }
+    }
+    let _ = window.set_size(Size::Logical(size));
+}
+
+/// 显示内容窗口但不抢夺焦点
+fn show_content_window_unfocused(
+    app: &AppHandle<Wry>,
+    window: &WebviewWindow<Wry>,
+) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        // 记录当前主窗口是否聚焦
+        let was_main_focused = FOCUS.lock().unwrap().is_main_focused();
+
+        window.show().map_err(|e| e.to_string())?;
+
+        // 如果之前主窗口是激活的，强制重新聚焦主窗口
+        if was_main_focused {
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                // 延迟一小段时间以确保窗口显示处理完成
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                print!("[NoB] restoring focus to main window\n");
+                if let Some(main_win) = app_handle.get_webview_window("main") {
+                    let _ = main_win.set_focus();
+                }
+            });
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = app;
+        window.show().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// 各 Feature 注册的注入脚本（按注册表中的顺序）
+fn initialization_scripts(app: &AppHandle<Wry>) -> Vec<String> {
+    app.try_state::<AppState>()
+        .map(|state| state.injection_registry().initialization_scripts(app))
+        .unwrap_or_default()
+}
+
+/// 内容页面开始加载：记录为链接规则的来源页面；
+/// 加载完成：重新执行需要按当前状态更新的脚本（外观、站点脚本等）
+fn on_content_page_load(window: WebviewWindow<Wry>, payload: PageLoadPayload<'_>) {
+    if payload.event() == PageLoadEvent::Started {
+        link_engine::record_page(window.label(), payload.url());
+        return;
+    }
+    let app = window.app_handle();
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    for script in state.injection_registry().reload_scripts(app) {
+        let _ = window.eval(script);
+    }
+}
+
+/// 创建内容窗口
+fn build_content_window(
+    app: &AppHandle<Wry>,
+    label: &str,
+    url: Url,
+    focus: bool,
+) -> Result<WebviewWindow<Wry>, String> {
+    let size = tab_id_from_label(label)
+        .map(geometry::content_size)
+        .unwrap_or_else(geometry::default_content_size);
+    let mut builder = tauri::WebviewWindowBuilder::new(app, label, WebviewUrl::External(url))
+        .title("NoB 内容")
+        .inner_size(size.width, size.height)
+        .position(120.0, 120.0)
+        .decorations(false)
+        .always_on_top(true)
+        .accept_first_mouse(true)
+        .focused(focus) // 设置初始焦点状态
+        .user_agent(CONTENT_USER_AGENT)
+        .theme(appearance::window_theme(app))
+        .on_page_load(on_content_page_load)
+        // pushState 等 SPA 导航不经过这里，由注入脚本上报
+        .on_navigation(link_engine::navigation_handler(app, label));
+    for script in initialization_scripts(app) {
+        builder = builder.initialization_script(script);
+    }
+    let window = builder.build().map_err(|e| e.to_string())?;
+
+    #[cfg(target_os = "macos")]
+    {
+      use objc2_app_kit::NSColor;
+      unsafe {
+        if let Ok(raw) = window.ns_window() {
+            let ns_window: &NSWindow = &*raw.cast();
+
+            ns_window.setOpaque(false);
+            let clear = NSColor::clearColor();
+            ns_window.setBackgroundColor(Some(&clear));
+
+            if let Some(content) = ns_window.contentView() {
+                if let Some(frame) = content.superview() {
+                    frame.setWantsLayer(true);
+                    if let Some(layer) = frame.layer() {
+                        layer.setCornerRadius(12.0);
+                        layer.setMasksToBounds(true);
+                        layer.setBorderWidth(0.0);
                     }
                 }
             }
-          }
         }
+      }
+    }
 
-        set_window_on_all_workspaces(&window);
+    set_window_on_all_workspaces(&window);
 
-        sync_content_window_position(app);
-        Ok(())
-    }
+    Ok(window)
 }
 
 /// 隐藏内容窗口
 pub fn hide_content_window(app: &AppHandle<Wry>) {
-    if let Some(content_window) = app.get_webview_window("content") {
-        // 先移出屏幕以避免磁力吸附
-        let _ = content_window.set_position(Position::Physical(PhysicalPosition { x: -10000, y: -10000 }));
-        // 然后隐藏
-        let _ = content_window.hide();
+    for tab_id in live_content_tab_ids() {
+        if let Some(content_window) = app.get_webview_window(&content_label(&tab_id)) {
+            // 先移出屏幕以避免磁力吸附
+            let _ = content_window.set_position(Position::Physical(PhysicalPosition { x: -10000, y: -10000 }));
+            // 然后隐藏
+            let _ = content_window.hide();
+        }
     }
 }
 
@@ -533,58 +922,535 @@ pub fn configure_startup_behavior(app: &AppHandle<Wry>) {
 
 /// 处理窗口焦点变化
 pub fn handle_focus_change(app: &AppHandle<Wry>, window_label: &str, focused: bool) {
-    if window_label == "main" {
-        MAIN_FOCUSED.store(focused, Ordering::SeqCst);
-    } else if window_label == "content" {
-        CONTENT_FOCUSED.store(focused, Ordering::SeqCst);
+    let event = if window_label == "main" {
+        FocusEvent::MainFocus(focused)
+    } else if let Some(tab_id) = tab_id_from_label(window_label) {
+        if focused {
+            touch_content_webview(tab_id);
+        }
+        FocusEvent::ContentFocus {
+            label: window_label.to_string(),
+            focused,
+        }
     } else {
+        // 有意忽略分离窗口（`detached-*`）等其他窗口：分离窗口是独立的普通窗口，
+        // 焦点切到分离窗口与切到其他应用相同，内容栏照常自动隐藏
         return;
+    };
+    dispatch_focus_event(app, event);
+}
+
+/// 向焦点状态机发送事件，执行其给出的操作，并按下一次检查时间重新设置定时任务
+fn dispatch_focus_event(app: &AppHandle<Wry>, event: FocusEvent) {
+    let (actions, deadline) = {
+        let mut machine = FOCUS.lock().unwrap();
+        let actions = machine.handle(event);
+        (actions, machine.next_deadline())
+    };
+
+    schedule_focus_tick(app, deadline);
+    for action in actions {
+        run_focus_action(app, action);
     }
+}
 
-    if focused {
-        // 任意窗口获得焦点，取消隐藏任务
-        let mut task_guard = FOCUS_CHECK_TASK.lock().unwrap();
-        if let Some(task) = task_guard.take() {
-            task.abort();
+fn schedule_focus_tick(app: &AppHandle<Wry>, deadline: Option<Instant>) {
+    let mut timer = FOCUS_TIMER.lock().unwrap();
+    if let Some(task) = timer.take() {
+        task.abort();
+    }
+    let Some(deadline) = deadline else {
+        return;
+    };
+
+    let app_handle = app.clone();
+    *timer = Some(tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(deadline.saturating_duration_since(Instant::now())).await;
+        // 先取出自身的句柄，避免派发时被自己 abort
+        FOCUS_TIMER.lock().unwrap().take();
+        dispatch_focus_event(&app_handle, FocusEvent::Tick);
+    }));
+}
+
+fn run_focus_action(app: &AppHandle<Wry>, action: FocusAction) {
+    match action {
+        // 显示激活 tab 的内容窗口（不存在则按记录的 URL 重新创建），不抢夺焦点
+        FocusAction::ShowContent => {
+            let _ = present_active_content_window(app, false);
         }
-        
-        // 如果是主窗口获得焦点，显示内容窗口
-        if window_label == "main" {
-             // 检查是否正在拖动，如果是则不显示
-             if IS_DRAGGING.load(Ordering::SeqCst) {
-                 return;
-             }
+        FocusAction::HideForDrag => hide_content_window(app),
+        // 拖动结束，靠近屏幕边缘时吸附
+        FocusAction::DragEnded => {
+            if snap_main_window(app).is_some() {
+                geometry::save_dock_settings(app, dock_settings());
+            }
+            geometry::schedule_save(app);
+        }
+        FocusAction::AutoHide => {
+            if let Some(content) = active_content_window(app) {
+                let _ = content.hide();
+            }
+            if let Some(partner) = split_partner_window(app) {
+                let _ = partner.hide();
+            }
+            // 停靠时主窗口也滑出屏幕
+            hide_docked_window(app);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 贴边停靠：拖到屏幕边缘时吸附，失去焦点后滑出屏幕只露出一条边，
+// 鼠标移到该边缘（或指定的屏幕角落）停留一段时间后滑回
+// ---------------------------------------------------------------------------
+
+/// 距离屏幕边缘多近（逻辑像素）时吸附
+const DOCK_SNAP_DISTANCE: f64 = 24.0;
+/// 隐藏后留在屏幕内的宽度（逻辑像素）
+const DOCK_PEEK: f64 = 4.0;
+/// 角落触发区域的边长（逻辑像素）
+const DOCK_CORNER_SIZE: f64 = 8.0;
+/// 隐藏时轮询鼠标位置的间隔
+const DOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// 滑入/滑出动画
+const DOCK_SLIDE_STEPS: i32 = 8;
+const DOCK_SLIDE_STEP_DELAY: Duration = Duration::from_millis(15);
+const DEFAULT_REVEAL_DELAY_MS: u64 = 300;
+
+/// 停靠的屏幕边缘
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DockEdge {
+    #[default]
+    None,
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// 额外的唤出角落
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DockCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// 停靠设置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DockSettings {
+    #[serde(default)]
+    pub edge: DockEdge,
+    /// 鼠标移到该角落也会唤出
+    #[serde(default)]
+    pub reveal_corner: Option<DockCorner>,
+    /// 鼠标在触发区域停留多久后唤出（毫秒）
+    #[serde(default = "default_reveal_delay_ms")]
+    pub reveal_delay_ms: u64,
+}
+
+impl Default for DockSettings {
+    fn default() -> Self {
+        DEFAULT_DOCK_SETTINGS
+    }
+}
+
+const DEFAULT_DOCK_SETTINGS: DockSettings = DockSettings {
+    edge: DockEdge::None,
+    reveal_corner: None,
+    reveal_delay_ms: DEFAULT_REVEAL_DELAY_MS,
+};
 
-             // 尝试显示内容窗口（如果不存在则尝试创建，前提是有缓存的 URL）
-             // 注意：这里 focus 设为 false，因为是主窗口获得焦点触发的，不应抢夺焦点
-             let _ = present_content_window(app, None, false);
+fn default_reveal_delay_ms() -> u64 {
+    DEFAULT_REVEAL_DELAY_MS
+}
+
+/// 停靠所在显示器的区域（物理坐标）
+#[derive(Debug, Clone, Copy)]
+struct MonitorRect {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    scale: f64,
+}
+
+impl MonitorRect {
+    fn of(monitor: &tauri::Monitor) -> Self {
+        Self {
+            x: monitor.position().x,
+            y: monitor.position().y,
+            width: monitor.size().width as i32,
+            height: monitor.size().height as i32,
+            scale: monitor.scale_factor(),
         }
-    } else {
-        // 窗口失去焦点，启动延迟检查任务
-        let app_handle = app.clone();
-        let mut task_guard = FOCUS_CHECK_TASK.lock().unwrap();
-        if let Some(task) = task_guard.take() {
-            task.abort();
-        }
-        
-        let task = tauri::async_runtime::spawn(async move {
-            // 等待 100ms，允许焦点在窗口间切换
-            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-            
-            let main_focus = MAIN_FOCUSED.load(Ordering::SeqCst);
-            let content_focus = CONTENT_FOCUSED.load(Ordering::SeqCst);
-            
-            // 如果两个窗口都没有焦点，且未固定，则隐藏内容窗口
-            if !main_focus && !content_focus {
-                if IS_PINNED.load(Ordering::SeqCst) {
-                    return;
-                }
-                if let Some(content) = app_handle.get_webview_window("content") {
-                    let _ = content.hide();
+    }
+
+    fn px(&self, logical: f64) -> i32 {
+        (logical * self.scale).round() as i32
+    }
+}
+
+struct DockState {
+    settings: DockSettings,
+    /// 隐藏时记录所在显示器，滑出屏幕后无法再按窗口位置判断
+    hidden_on: Option<MonitorRect>,
+}
+
+static DOCK: Mutex<DockState> = Mutex::new(DockState {
+    settings: DEFAULT_DOCK_SETTINGS,
+    hidden_on: None,
+});
+/// 正在执行停靠动画，忽略由此产生的移动事件
+static DOCK_SLIDING: AtomicBool = AtomicBool::new(false);
+/// 每次开始或取消停靠动画时递增，旧的动画在下一步检查到变化后停止
+static DOCK_SLIDE_GENERATION: AtomicU64 = AtomicU64::new(0);
+static DOCK_POLL_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+/// 当前停靠设置
+pub fn dock_settings() -> DockSettings {
+    DOCK.lock().unwrap().settings
+}
+
+/// 主窗口是否处于贴边隐藏状态
+pub fn is_dock_hidden() -> bool {
+    DOCK.lock().unwrap().hidden_on.is_some()
+}
+
+/// 应用停靠设置：停靠时吸附到对应边缘，取消停靠时恢复显示
+pub fn apply_dock_settings(app: &AppHandle<Wry>, settings: DockSettings) {
+    DOCK.lock().unwrap().settings = settings;
+
+    if settings.edge == DockEdge::None {
+        if is_dock_hidden() {
+            reveal_docked_window(app);
+        }
+        return;
+    }
+
+    if let Some(window) = app.get_webview_window("main") {
+        if let Some(monitor) = monitors::main_monitor(app) {
+            if let Some(position) = window_docked_position(&window, MonitorRect::of(&monitor), settings.edge) {
+                let _ = window.set_position(Position::Physical(position));
+            }
+        }
+    }
+}
+
+/// 显示器变化后检查贴边隐藏时所在的显示器是否仍存在，不存在时取消隐藏状态
+///
+/// 窗口随后由 monitors 移回可见的显示器并重新吸附
+pub fn revalidate_dock(app: &AppHandle<Wry>) {
+    let Some(hidden_on) = DOCK.lock().unwrap().hidden_on else {
+        return;
+    };
+    let still_connected = app.available_monitors().unwrap_or_default().iter().any(|m| {
+        let rect = MonitorRect::of(m);
+        rect.x == hidden_on.x
+            && rect.y == hidden_on.y
+            && rect.width == hidden_on.width
+            && rect.height == hidden_on.height
+    });
+    if still_connected {
+        return;
+    }
+
+    DOCK.lock().unwrap().hidden_on = None;
+    cancel_dock_slide();
+    if let Some(task) = DOCK_POLL_TASK.lock().unwrap().take() {
+        task.abort();
+    }
+}
+
+/// 拖动结束后检测是否靠近屏幕边缘，返回新的停靠边缘（未变化时返回 None）
+fn snap_main_window(app: &AppHandle<Wry>) -> Option<DockEdge> {
+    let window = app.get_webview_window("main")?;
+    let monitor = MonitorRect::of(&monitors::main_monitor(app)?);
+    let position = window.outer_position().ok()?;
+    let size = window.outer_size().ok()?;
+
+    let threshold = monitor.px(DOCK_SNAP_DISTANCE);
+    let distances = [
+        (DockEdge::Top, position.y - monitor.y),
+        (DockEdge::Bottom, monitor.y + monitor.height - (position.y + size.height as i32)),
+        (DockEdge::Left, position.x - monitor.x),
+        (DockEdge::Right, monitor.x + monitor.width - (position.x + size.width as i32)),
+    ];
+    let edge = distances
+        .iter()
+        .filter(|(_, distance)| distance.abs() <= threshold)
+        .min_by_key(|(_, distance)| distance.abs())
+        .map(|(edge, _)| *edge)
+        .unwrap_or(DockEdge::None);
+
+    if edge != DockEdge::None {
+        if let Some(target) = docked_position(position, size, monitor, edge) {
+            if target != position {
+                let _ = window.set_position(Position::Physical(target));
+            }
+        }
+    }
+
+    let mut dock = DOCK.lock().unwrap();
+    if dock.settings.edge == edge {
+        return None;
+    }
+    dock.settings.edge = edge;
+    Some(edge)
+}
+
+/// 贴边显示时主窗口的位置
+fn window_docked_position(
+    window: &WebviewWindow<Wry>,
+    monitor: MonitorRect,
+    edge: DockEdge,
+) -> Option<PhysicalPosition<i32>> {
+    let position = window.outer_position().ok()?;
+    let size = window.outer_size().ok()?;
+    docked_position(position, size, monitor, edge)
+}
+
+/// 位于 position、大小为 size 的窗口贴到 edge 后的位置
+fn docked_position(
+    position: PhysicalPosition<i32>,
+    size: tauri::PhysicalSize<u32>,
+    monitor: MonitorRect,
+    edge: DockEdge,
+) -> Option<PhysicalPosition<i32>> {
+    let (width, height) = (size.width as i32, size.height as i32);
+
+    // 沿边缘方向保持当前位置，但不超出显示器
+    let x = position.x.clamp(monitor.x, (monitor.x + monitor.width - width).max(monitor.x));
+    let y = position.y.clamp(monitor.y, (monitor.y + monitor.height - height).max(monitor.y));
+
+    Some(match edge {
+        DockEdge::None => return None,
+        DockEdge::Top => PhysicalPosition::new(x, monitor.y),
+        DockEdge::Bottom => PhysicalPosition::new(x, monitor.y + monitor.height - height),
+        DockEdge::Left => PhysicalPosition::new(monitor.x, y),
+        DockEdge::Right => PhysicalPosition::new(monitor.x + monitor.width - width, y),
+    })
+}
+
+/// 贴边隐藏时主窗口的位置（只露出 DOCK_PEEK）
+fn hidden_position(
+    shown: PhysicalPosition<i32>,
+    size: tauri::PhysicalSize<u32>,
+    monitor: MonitorRect,
+    edge: DockEdge,
+) -> PhysicalPosition<i32> {
+    let peek = monitor.px(DOCK_PEEK);
+    match edge {
+        DockEdge::None => shown,
+        DockEdge::Top => PhysicalPosition::new(shown.x, monitor.y - size.height as i32 + peek),
+        DockEdge::Bottom => PhysicalPosition::new(shown.x, monitor.y + monitor.height - peek),
+        DockEdge::Left => PhysicalPosition::new(monitor.x - size.width as i32 + peek, shown.y),
+        DockEdge::Right => PhysicalPosition::new(monitor.x + monitor.width - peek, shown.y),
+    }
+}
+
+/// 失去焦点后将停靠的主窗口滑出屏幕，并开始监听鼠标位置
+fn hide_docked_window(app: &AppHandle<Wry>) {
+    let settings = dock_settings();
+    if settings.edge == DockEdge::None || is_dock_hidden() {
+        return;
+    }
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let Some(monitor) = monitors::main_monitor(app) else {
+        return;
+    };
+    let monitor = MonitorRect::of(&monitor);
+    let (Some(shown), Ok(size)) = (window_docked_position(&window, monitor, settings.edge), window.outer_size()) else {
+        return;
+    };
+
+    DOCK.lock().unwrap().hidden_on = Some(monitor);
+    hide_content_window(app);
+
+    let target = hidden_position(shown, size, monitor, settings.edge);
+    let generation = begin_dock_slide();
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        // 滑出途中被唤出时不再监听
+        if slide_window(&window, shown, target, generation).await {
+            start_dock_polling(&app_handle);
+        }
+    });
+}
+
+/// 将贴边隐藏的主窗口滑回屏幕并聚焦
+pub fn reveal_docked_window(app: &AppHandle<Wry>) {
+    let Some(monitor) = DOCK.lock().unwrap().hidden_on.take() else {
+        return;
+    };
+    if let Some(task) = DOCK_POLL_TASK.lock().unwrap().take() {
+        task.abort();
+    }
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+
+    // 在读取位置之前停止可能还在进行的滑出，从当前位置滑回
+    let generation = begin_dock_slide();
+    let edge = dock_settings().edge;
+    let from = window.outer_position().ok();
+    let target = window_docked_position(&window, monitor, edge);
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let finished = match (from, target) {
+            (Some(from), Some(target)) => slide_window(&window, from, target, generation).await,
+            _ => end_dock_slide(generation),
+        };
+        // 滑回途中又被隐藏时保持隐藏
+        if !finished {
+            return;
+        }
+        let _ = window.show();
+        let _ = window.set_focus();
+        let _ = present_active_content_window(&app_handle, false);
+    });
+}
+
+/// 开始新的停靠动画，正在进行的动画会在下一步停止
+fn begin_dock_slide() -> u64 {
+    DOCK_SLIDING.store(true, Ordering::SeqCst);
+    DOCK_SLIDE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// 停止正在进行的停靠动画
+fn cancel_dock_slide() {
+    DOCK_SLIDE_GENERATION.fetch_add(1, Ordering::SeqCst);
+    DOCK_SLIDING.store(false, Ordering::SeqCst);
+}
+
+/// 结束 generation 对应的停靠动画，已被新的动画取代或取消时返回 false
+fn end_dock_slide(generation: u64) -> bool {
+    if DOCK_SLIDE_GENERATION.load(Ordering::SeqCst) != generation {
+        return false;
+    }
+    DOCK_SLIDING.store(false, Ordering::SeqCst);
+    true
+}
+
+/// 逐步移动窗口，每一步前检查动画是否已被取代，完整执行时返回 true
+async fn slide_window(
+    window: &WebviewWindow<Wry>,
+    from: PhysicalPosition<i32>,
+    to: PhysicalPosition<i32>,
+    generation: u64,
+) -> bool {
+    for step in 1..=DOCK_SLIDE_STEPS {
+        if DOCK_SLIDE_GENERATION.load(Ordering::SeqCst) != generation {
+            return false;
+        }
+        let x = from.x + (to.x - from.x) * step / DOCK_SLIDE_STEPS;
+        let y = from.y + (to.y - from.y) * step / DOCK_SLIDE_STEPS;
+        let _ = window.set_position(Position::Physical(PhysicalPosition::new(x, y)));
+        tokio::time::sleep(DOCK_SLIDE_STEP_DELAY).await;
+    }
+    // 等待最后一次移动事件送达后再恢复
+    tokio::time::sleep(DOCK_SLIDE_STEP_DELAY * 4).await;
+    end_dock_slide(generation)
+}
+
+/// 隐藏期间轮询鼠标位置，在触发区域停留足够时间后唤出
+fn start_dock_polling(app: &AppHandle<Wry>) {
+    let app_handle = app.clone();
+    let mut task_guard = DOCK_POLL_TASK.lock().unwrap();
+    if let Some(task) = task_guard.take() {
+        task.abort();
+    }
+
+    *task_guard = Some(tauri::async_runtime::spawn(async move {
+        let mut entered_at: Option<Instant> = None;
+        loop {
+            tokio::time::sleep(DOCK_POLL_INTERVAL).await;
+
+            let (settings, Some(monitor)) = ({
+                let dock = DOCK.lock().unwrap();
+                (dock.settings, dock.hidden_on)
+            }) else {
+                return;
+            };
+
+            let in_trigger = match (
+                app_handle.cursor_position(),
+                app_handle.get_webview_window("main"),
+            ) {
+                (Ok(cursor), Some(window)) => {
+                    match (window.outer_position(), window.outer_size()) {
+                        (Ok(position), Ok(size)) => {
+                            is_in_reveal_area(position, size, monitor, &settings, cursor)
+                        }
+                        _ => false,
+                    }
                 }
+                _ => false,
+            };
+
+            if !in_trigger {
+                entered_at = None;
+                continue;
+            }
+            let since = *entered_at.get_or_insert_with(Instant::now);
+            if since.elapsed() >= Duration::from_millis(settings.reveal_delay_ms) {
+                // reveal 会中止当前任务，放到新任务中执行
+                let app = app_handle.clone();
+                tauri::async_runtime::spawn(async move { reveal_docked_window(&app) });
+                return;
             }
-        });
-        *task_guard = Some(task);
+        }
+    }));
+}
+
+/// 鼠标是否位于停靠边缘（主窗口露出部分所在的一段）或指定的角落
+fn is_in_reveal_area(
+    position: PhysicalPosition<i32>,
+    size: tauri::PhysicalSize<u32>,
+    monitor: MonitorRect,
+    settings: &DockSettings,
+    cursor: PhysicalPosition<f64>,
+) -> bool {
+    let (cx, cy) = (cursor.x.round() as i32, cursor.y.round() as i32);
+    let inside_monitor = cx >= monitor.x
+        && cx < monitor.x + monitor.width
+        && cy >= monitor.y
+        && cy < monitor.y + monitor.height;
+    if !inside_monitor {
+        return false;
+    }
+
+    if let Some(corner) = settings.reveal_corner {
+        let size = monitor.px(DOCK_CORNER_SIZE);
+        let near_left = cx < monitor.x + size;
+        let near_right = cx >= monitor.x + monitor.width - size;
+        let near_top = cy < monitor.y + size;
+        let near_bottom = cy >= monitor.y + monitor.height - size;
+        let in_corner = match corner {
+            DockCorner::TopLeft => near_top && near_left,
+            DockCorner::TopRight => near_top && near_right,
+            DockCorner::BottomLeft => near_bottom && near_left,
+            DockCorner::BottomRight => near_bottom && near_right,
+        };
+        if in_corner {
+            return true;
+        }
+    }
+
+    let peek = monitor.px(DOCK_PEEK).max(1);
+    let along_x = cx >= position.x && cx < position.x + size.width as i32;
+    let along_y = cy >= position.y && cy < position.y + size.height as i32;
+    match settings.edge {
+        DockEdge::None => false,
+        DockEdge::Top => along_x && cy < monitor.y + peek,
+        DockEdge::Bottom => along_x && cy >= monitor.y + monitor.height - peek,
+        DockEdge::Left => along_y && cx < monitor.x + peek,
+        DockEdge::Right => along_y && cx >= monitor.x + monitor.width - peek,
     }
 }
 
@@ -598,3 +1464,159 @@ fn set_window_on_all_workspaces(window: &WebviewWindow<Wry>) {
 fn set_window_on_all_workspaces(window: &WebviewWindow<Wry>) {
     let _ = window.set_skip_taskbar(true);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tauri::PhysicalSize;
+
+    const MONITOR: MonitorRect = MonitorRect {
+        x: 0,
+        y: 0,
+        width: 1920,
+        height: 1080,
+        scale: 1.0,
+    };
+    const SIZE: PhysicalSize<u32> = PhysicalSize {
+        width: 800,
+        height: 600,
+    };
+
+    fn at(x: i32, y: i32) -> PhysicalPosition<i32> {
+        PhysicalPosition::new(x, y)
+    }
+
+    fn dock(edge: DockEdge, reveal_corner: Option<DockCorner>) -> DockSettings {
+        DockSettings {
+            edge,
+            reveal_corner,
+            ..DEFAULT_DOCK_SETTINGS
+        }
+    }
+
+    #[test]
+    fn docked_position_snaps_to_the_edge_inside_the_monitor() {
+        assert_eq!(
+            docked_position(at(-50, 300), SIZE, MONITOR, DockEdge::Left),
+            Some(at(0, 300))
+        );
+        assert_eq!(
+            docked_position(at(-50, 300), SIZE, MONITOR, DockEdge::Right),
+            Some(at(1120, 300))
+        );
+        assert_eq!(
+            docked_position(at(1500, 300), SIZE, MONITOR, DockEdge::Top),
+            Some(at(1120, 0))
+        );
+        assert_eq!(
+            docked_position(at(100, 900), SIZE, MONITOR, DockEdge::Bottom),
+            Some(at(100, 480))
+        );
+        assert_eq!(
+            docked_position(at(100, 900), SIZE, MONITOR, DockEdge::None),
+            None
+        );
+
+        // 比显示器还宽的窗口贴着左上角
+        let wide = PhysicalSize::new(2000, 600);
+        assert_eq!(
+            docked_position(at(300, 300), wide, MONITOR, DockEdge::Top),
+            Some(at(0, 0))
+        );
+    }
+
+    #[test]
+    fn hidden_position_leaves_only_the_peek_on_screen() {
+        assert_eq!(
+            hidden_position(at(100, 0), SIZE, MONITOR, DockEdge::Top),
+            at(100, -596)
+        );
+        assert_eq!(
+            hidden_position(at(100, 480), SIZE, MONITOR, DockEdge::Bottom),
+            at(100, 1076)
+        );
+        assert_eq!(
+            hidden_position(at(0, 200), SIZE, MONITOR, DockEdge::Left),
+            at(-796, 200)
+        );
+        assert_eq!(
+            hidden_position(at(1120, 200), SIZE, MONITOR, DockEdge::Right),
+            at(1916, 200)
+        );
+        assert_eq!(
+            hidden_position(at(100, 200), SIZE, MONITOR, DockEdge::None),
+            at(100, 200)
+        );
+
+        // 露出部分按缩放换算为物理像素
+        let scaled = MonitorRect {
+            scale: 2.0,
+            ..MONITOR
+        };
+        assert_eq!(
+            hidden_position(at(0, 200), SIZE, scaled, DockEdge::Left),
+            at(-792, 200)
+        );
+    }
+
+    #[test]
+    fn reveal_area_is_the_peek_along_the_window_or_the_corner() {
+        let hidden = at(100, -596);
+        let top = dock(DockEdge::Top, None);
+        let cursor = |x: f64, y: f64| PhysicalPosition::new(x, y);
+
+        assert!(is_in_reveal_area(
+            hidden,
+            SIZE,
+            MONITOR,
+            &top,
+            cursor(500.0, 2.0)
+        ));
+        // 露出部分之外的同一边缘
+        assert!(!is_in_reveal_area(
+            hidden,
+            SIZE,
+            MONITOR,
+            &top,
+            cursor(50.0, 2.0)
+        ));
+        assert!(!is_in_reveal_area(
+            hidden,
+            SIZE,
+            MONITOR,
+            &top,
+            cursor(500.0, 10.0)
+        ));
+        // 显示器之外
+        assert!(!is_in_reveal_area(
+            hidden,
+            SIZE,
+            MONITOR,
+            &top,
+            cursor(500.0, -1.0)
+        ));
+
+        let with_corner = dock(DockEdge::Top, Some(DockCorner::BottomRight));
+        assert!(is_in_reveal_area(
+            hidden,
+            SIZE,
+            MONITOR,
+            &with_corner,
+            cursor(1915.0, 1075.0)
+        ));
+        assert!(!is_in_reveal_area(
+            hidden,
+            SIZE,
+            MONITOR,
+            &with_corner,
+            cursor(1915.0, 500.0)
+        ));
+        assert!(!is_in_reveal_area(
+            hidden,
+            SIZE,
+            MONITOR,
+            &top,
+            cursor(1915.0, 1075.0)
+        ));
+    }
+}
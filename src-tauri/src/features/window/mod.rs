@@ -3,9 +3,27 @@
 // 架构：
 // - api/: API 接口层（handlers, notifications）
 // - manager: 窗口管理器（底层实现、事件回调）
+// - appearance: 内容窗口外观（主题同步、强制暗色）
+// - focus: 焦点/拖动/自动隐藏状态机
+// - geometry: 窗口位置/尺寸的保存与恢复
+// - layout: 内容窗口相对主窗口的布局
+// - monitors: 多显示器（所在显示器、显示器变化）
+// - pin: 内容窗口固定状态（保存在设置中）
+// - script_modules: 注入脚本模块的启用开关（保存在设置中）
+// - shortcuts: 全局快捷键（绑定保存在设置中）
+// - split: 分屏（两个标签并排显示在内容区）
 
 pub mod api;
+pub mod appearance;
 pub mod feature;
+pub mod focus;
+pub mod geometry;
+pub mod layout;
 pub mod manager;
+pub mod monitors;
+pub mod pin;
+pub mod script_modules;
+pub mod shortcuts;
+pub mod split;
 
 pub use feature::WindowFeature;
@@ -0,0 +1,218 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use anyhow::Result;
+use tauri::{AppHandle, Manager, Monitor, PhysicalPosition, Position, Wry};
+
+use crate::core::AppState;
+use crate::features::settings::core::service::SettingService;
+use crate::features::window::manager;
+
+/// 唤出时是否移动到鼠标所在的显示器
+const SUMMON_ON_CURSOR_KEY: &str = "window.summon_on_cursor_monitor";
+/// 检查显示器接入/移除的间隔（Tauri 没有显示器变化事件）
+const MONITOR_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+static SUMMON_ON_CURSOR_MONITOR: AtomicBool = AtomicBool::new(false);
+
+/// 显示器的标识（名称和区域），用于检测变化
+type MonitorLayout = Vec<(Option<String>, i32, i32, u32, u32)>;
+
+/// 唤出时是否移动到鼠标所在的显示器
+pub fn summon_on_cursor_monitor() -> bool {
+    SUMMON_ON_CURSOR_MONITOR.load(Ordering::SeqCst)
+}
+
+/// 读取设置
+pub async fn load(app_state: &AppState) -> Result<()> {
+    let enabled = SettingService::get_or_default(app_state.db(), SUMMON_ON_CURSOR_KEY, "false")
+        .await?
+        == "true";
+    SUMMON_ON_CURSOR_MONITOR.store(enabled, Ordering::SeqCst);
+    Ok(())
+}
+
+/// 修改唤出时是否跟随鼠标所在的显示器
+pub async fn set_summon_on_cursor_monitor(app_state: &AppState, enabled: bool) -> Result<()> {
+    SettingService::set(
+        app_state.db(),
+        SUMMON_ON_CURSOR_KEY,
+        if enabled { "true" } else { "false" },
+    )
+    .await?;
+    SUMMON_ON_CURSOR_MONITOR.store(enabled, Ordering::SeqCst);
+    Ok(())
+}
+
+/// 主窗口所在的显示器：包含窗口中心的显示器，否则为重叠面积最大的，都没有时为 None
+pub fn main_monitor(app: &AppHandle<Wry>) -> Option<Monitor> {
+    let window = app.get_webview_window("main")?;
+    let position = window.outer_position().ok()?;
+    let size = window.outer_size().ok()?;
+    let monitors = app.available_monitors().ok()?;
+
+    let center = PhysicalPosition::new(
+        position.x + size.width as i32 / 2,
+        position.y + size.height as i32 / 2,
+    );
+    if let Some(monitor) = monitors.iter().find(|m| contains(m, center)) {
+        return Some(monitor.clone());
+    }
+
+    monitors
+        .into_iter()
+        .map(|monitor| {
+            let origin = monitor.position();
+            let extent = monitor.size();
+            let overlap_x = (position.x + size.width as i32).min(origin.x + extent.width as i32)
+                - position.x.max(origin.x);
+            let overlap_y = (position.y + size.height as i32).min(origin.y + extent.height as i32)
+                - position.y.max(origin.y);
+            (overlap_x.max(0) as i64 * overlap_y.max(0) as i64, monitor)
+        })
+        .filter(|(area, _)| *area > 0)
+        .max_by_key(|(area, _)| *area)
+        .map(|(_, monitor)| monitor)
+}
+
+/// 鼠标所在的显示器
+pub fn cursor_monitor(app: &AppHandle<Wry>) -> Option<Monitor> {
+    let cursor = app.cursor_position().ok()?;
+    let cursor = PhysicalPosition::new(cursor.x.round() as i32, cursor.y.round() as i32);
+    app.available_monitors()
+        .ok()?
+        .into_iter()
+        .find(|m| contains(m, cursor))
+}
+
+/// 唤出前将主窗口移动到鼠标所在的显示器（保持在显示器中的相对位置）
+pub fn move_main_to_cursor_monitor(app: &AppHandle<Wry>) {
+    if !summon_on_cursor_monitor() {
+        return;
+    }
+    let (Some(window), Some(target)) = (app.get_webview_window("main"), cursor_monitor(app)) else {
+        return;
+    };
+    let Some(current) = main_monitor(app) else {
+        move_main_to(app, &target);
+        return;
+    };
+    if same_monitor(&current, &target) {
+        return;
+    }
+
+    let (Ok(position), Ok(size)) = (window.outer_position(), window.outer_size()) else {
+        return;
+    };
+    // 按相对位置换算到目标显示器
+    let ratio = |offset: i32, extent: u32, window: u32| {
+        let free = extent.saturating_sub(window).max(1) as f64;
+        (offset as f64 / free).clamp(0.0, 1.0)
+    };
+    let rx = ratio(
+        position.x - current.position().x,
+        current.size().width,
+        size.width,
+    );
+    let ry = ratio(
+        position.y - current.position().y,
+        current.size().height,
+        size.height,
+    );
+    let free_width = target.size().width.saturating_sub(size.width) as f64;
+    let free_height = target.size().height.saturating_sub(size.height) as f64;
+
+    let _ = window.set_position(Position::Physical(PhysicalPosition::new(
+        target.position().x + (free_width * rx).round() as i32,
+        target.position().y + (free_height * ry).round() as i32,
+    )));
+}
+
+/// 定期检查显示器变化，显示器移除后把窗口移回可见的显示器
+pub fn watch(app: &AppHandle<Wry>) {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut last = monitor_layout(&app_handle);
+        loop {
+            tokio::time::sleep(MONITOR_CHECK_INTERVAL).await;
+
+            let current = monitor_layout(&app_handle);
+            if current == last || current.is_empty() {
+                continue;
+            }
+            println!(
+                "[WindowFeature] Monitors changed ({} connected)",
+                current.len()
+            );
+            last = current;
+            ensure_on_screen(&app_handle);
+        }
+    });
+}
+
+/// 主窗口不在任何显示器上时移到主显示器，然后重新定位内容窗口
+fn ensure_on_screen(app: &AppHandle<Wry>) {
+    // 贴边隐藏的窗口位于屏幕外，先按原显示器是否仍存在处理
+    manager::revalidate_dock(app);
+
+    if main_monitor(app).is_none() {
+        let target = app
+            .primary_monitor()
+            .ok()
+            .flatten()
+            .or_else(|| app.available_monitors().ok()?.into_iter().next());
+        if let Some(target) = target {
+            move_main_to(app, &target);
+        }
+        let dock = manager::dock_settings();
+        if dock.edge != manager::DockEdge::None {
+            manager::apply_dock_settings(app, dock);
+        }
+    }
+
+    manager::sync_content_window_position(app);
+}
+
+/// 将主窗口放到显示器工作区的顶部居中
+fn move_main_to(app: &AppHandle<Wry>, monitor: &Monitor) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let Ok(size) = window.outer_size() else {
+        return;
+    };
+
+    let work_area = monitor.work_area();
+    let x = work_area.position.x + (work_area.size.width as i32 - size.width as i32).max(0) / 2;
+    let y = work_area.position.y;
+    let _ = window.set_position(Position::Physical(PhysicalPosition::new(x, y)));
+}
+
+fn monitor_layout(app: &AppHandle<Wry>) -> MonitorLayout {
+    app.available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .map(|m| {
+            (
+                m.name().cloned(),
+                m.position().x,
+                m.position().y,
+                m.size().width,
+                m.size().height,
+            )
+        })
+        .collect()
+}
+
+fn contains(monitor: &Monitor, point: PhysicalPosition<i32>) -> bool {
+    let origin = monitor.position();
+    let extent = monitor.size();
+    point.x >= origin.x
+        && point.y >= origin.y
+        && point.x < origin.x + extent.width as i32
+        && point.y < origin.y + extent.height as i32
+}
+
+fn same_monitor(a: &Monitor, b: &Monitor) -> bool {
+    a.position() == b.position() && a.size() == b.size()
+}
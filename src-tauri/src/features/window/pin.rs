@@ -0,0 +1,51 @@
+use anyhow::Result;
+use tauri::{AppHandle, Manager};
+
+use crate::core::AppState;
+use crate::features::settings::core::service::SettingService;
+use crate::features::window::manager;
+use crate::infrastructure::ipc::emit_to_app;
+
+/// 内容窗口是否固定（不自动隐藏）
+const PINNED_KEY: &str = "window.content_pinned";
+/// 固定状态变化时发给前端的事件
+pub const PIN_CHANGED_EVENT: &str = "content-pin-changed";
+
+/// 读取固定状态（启动时和导入数据后调用）
+pub async fn load(app_state: &AppState) -> Result<()> {
+    let pinned =
+        SettingService::get_or_default(app_state.db(), PINNED_KEY, "false").await? == "true";
+    if pinned != manager::is_content_window_pinned() {
+        manager::set_content_window_pinned(&app_state.app_handle(), pinned);
+    }
+    Ok(())
+}
+
+/// 修改固定状态：保存设置、更新托盘勾选并通知前端
+pub async fn set_pinned(app_state: &AppState, pinned: bool) -> Result<()> {
+    SettingService::set(
+        app_state.db(),
+        PINNED_KEY,
+        if pinned { "true" } else { "false" },
+    )
+    .await?;
+
+    let app = app_state.app_handle();
+    manager::set_content_window_pinned(&app, pinned);
+    let _ = app_state.tray_manager().update_tray_menu(&app);
+    let _ = emit_to_app(&app, PIN_CHANGED_EVENT, pinned);
+    Ok(())
+}
+
+/// 切换固定状态（托盘和快捷键使用）
+pub fn toggle(app: &AppHandle) {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Some(state) = app_handle.try_state::<AppState>() {
+            let pinned = !manager::is_content_window_pinned();
+            if let Err(e) = set_pinned(&state, pinned).await {
+                println!("[WindowFeature] Failed to toggle content pin: {}", e);
+            }
+        }
+    });
+}
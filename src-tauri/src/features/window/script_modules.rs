@@ -0,0 +1,29 @@
+use anyhow::Result;
+
+use crate::core::AppState;
+use crate::features::settings::core::service::SettingService;
+
+/// 停用的注入脚本模块（JSON 字符串数组）
+const DISABLED_MODULES_KEY: &str = "window.disabled_script_modules";
+
+/// 从设置中恢复停用的模块
+pub async fn load(app_state: &AppState) -> Result<()> {
+    let disabled: Vec<String> = SettingService::get(app_state.db(), DISABLED_MODULES_KEY)
+        .await?
+        .and_then(|value| serde_json::from_str(&value).ok())
+        .unwrap_or_default();
+    app_state.injection_registry().set_disabled(disabled);
+    Ok(())
+}
+
+/// 启用或停用模块并保存，新建或重新加载的页面生效
+pub async fn set_enabled(app_state: &AppState, name: &str, enabled: bool) -> Result<()> {
+    let disabled = app_state.injection_registry().set_enabled(name, enabled)?;
+    SettingService::set(
+        app_state.db(),
+        DISABLED_MODULES_KEY,
+        &serde_json::to_string(&disabled)?,
+    )
+    .await?;
+    Ok(())
+}
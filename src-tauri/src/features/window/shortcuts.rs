@@ -0,0 +1,285 @@
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, Wry};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState};
+
+use crate::core::AppState;
+use crate::features::settings::core::service::SettingService;
+use crate::features::tab::api::commands as tab_commands;
+use crate::features::tab::core::service::TabService;
+use crate::features::window::{manager, pin};
+
+/// 快捷键设置的 key 前缀，完整 key 为 `shortcut.<action>`，值为空表示禁用
+const SETTING_PREFIX: &str = "shortcut.";
+
+/// 可绑定全局快捷键的操作
+struct ShortcutAction {
+    id: &'static str,
+    label: &'static str,
+    default_accelerator: &'static str,
+}
+
+const fn action(
+    id: &'static str,
+    label: &'static str,
+    default_accelerator: &'static str,
+) -> ShortcutAction {
+    ShortcutAction {
+        id,
+        label,
+        default_accelerator,
+    }
+}
+
+const ACTIONS: [ShortcutAction; 14] = [
+    action("toggle_main_window", "显示/隐藏", "CmdOrCtrl+Shift+Space"),
+    action("toggle_content_pinned", "固定/取消固定内容窗口", "CmdOrCtrl+Alt+P"),
+    action("tabs_activate_next", "下一个标签", "CmdOrCtrl+Alt+]"),
+    action("tabs_activate_previous", "上一个标签", "CmdOrCtrl+Alt+["),
+    action("tabs_close_active", "关闭当前标签", "CmdOrCtrl+Alt+W"),
+    action("tabs_activate_1", "切换到标签 1", "CmdOrCtrl+Alt+1"),
+    action("tabs_activate_2", "切换到标签 2", "CmdOrCtrl+Alt+2"),
+    action("tabs_activate_3", "切换到标签 3", "CmdOrCtrl+Alt+3"),
+    action("tabs_activate_4", "切换到标签 4", "CmdOrCtrl+Alt+4"),
+    action("tabs_activate_5", "切换到标签 5", "CmdOrCtrl+Alt+5"),
+    action("tabs_activate_6", "切换到标签 6", "CmdOrCtrl+Alt+6"),
+    action("tabs_activate_7", "切换到标签 7", "CmdOrCtrl+Alt+7"),
+    action("tabs_activate_8", "切换到标签 8", "CmdOrCtrl+Alt+8"),
+    action("tabs_activate_9", "切换到最后一个标签", "CmdOrCtrl+Alt+9"),
+];
+
+/// 当前生效的绑定
+struct ActiveBinding {
+    action: &'static ShortcutAction,
+    accelerator: Option<String>,
+    shortcut: Option<Shortcut>,
+    error: Option<String>,
+}
+
+static BINDINGS: Mutex<Vec<ActiveBinding>> = Mutex::new(Vec::new());
+
+/// 快捷键绑定 (用于 API 响应)
+#[derive(Debug, Clone, Serialize)]
+pub struct ShortcutBinding {
+    pub action: String,
+    pub label: String,
+    /// 为空表示未绑定
+    pub accelerator: Option<String>,
+    pub default_accelerator: String,
+    /// 是否已成功注册到系统
+    pub registered: bool,
+    /// 解析失败、与其他操作冲突或被其他程序占用时的原因
+    pub error: Option<String>,
+}
+
+/// 列出所有操作的当前绑定
+pub fn bindings() -> Vec<ShortcutBinding> {
+    let active = BINDINGS.lock().unwrap();
+
+    ACTIONS
+        .iter()
+        .map(|action| {
+            let binding = active.iter().find(|b| b.action.id == action.id);
+            ShortcutBinding {
+                action: action.id.to_string(),
+                label: action.label.to_string(),
+                accelerator: binding.and_then(|b| b.accelerator.clone()),
+                default_accelerator: action.default_accelerator.to_string(),
+                registered: binding.is_some_and(|b| b.shortcut.is_some() && b.error.is_none()),
+                error: binding.and_then(|b| b.error.clone()),
+            }
+        })
+        .collect()
+}
+
+/// 从设置中读取绑定并重新注册所有快捷键
+pub async fn reload(app_state: &AppState) -> Result<()> {
+    let mut configured = Vec::with_capacity(ACTIONS.len());
+    for action in &ACTIONS {
+        let value = SettingService::get(app_state.db(), &setting_key(action.id)).await?;
+        let accelerator = match value {
+            Some(value) => Some(value.trim().to_string()).filter(|v| !v.is_empty()),
+            None => Some(action.default_accelerator.to_string()),
+        };
+        configured.push((action, accelerator));
+    }
+
+    register_all(&app_state.app_handle(), configured);
+    Ok(())
+}
+
+/// 修改一个操作的绑定，accelerator 为空时禁用
+///
+/// 与其他操作冲突或无法解析时返回错误，不修改设置
+pub async fn set_binding(
+    app_state: &AppState,
+    action_id: &str,
+    accelerator: Option<&str>,
+) -> Result<()> {
+    let action = find_action(action_id)?;
+    let accelerator = accelerator.map(str::trim).filter(|v| !v.is_empty());
+
+    if let Some(accelerator) = accelerator {
+        let shortcut = parse(accelerator)?;
+        let conflict = BINDINGS
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|b| b.action.id != action.id && b.shortcut == Some(shortcut))
+            .map(|b| b.action.label);
+        if let Some(other) = conflict {
+            return Err(anyhow!("{} 已绑定到「{}」", accelerator, other));
+        }
+    }
+
+    SettingService::set(
+        app_state.db(),
+        &setting_key(action.id),
+        accelerator.unwrap_or(""),
+    )
+    .await?;
+    reload(app_state).await
+}
+
+/// 恢复默认绑定，未指定操作时恢复全部
+pub async fn reset_bindings(app_state: &AppState, action_id: Option<&str>) -> Result<()> {
+    let actions: Vec<&ShortcutAction> = match action_id {
+        Some(id) => vec![find_action(id)?],
+        None => ACTIONS.iter().collect(),
+    };
+
+    for action in actions {
+        SettingService::set(
+            app_state.db(),
+            &setting_key(action.id),
+            action.default_accelerator,
+        )
+        .await?;
+    }
+    reload(app_state).await
+}
+
+/// 全局快捷键插件的回调
+pub fn handle_shortcut(app: &AppHandle<Wry>, shortcut: &Shortcut, event: ShortcutEvent) {
+    if event.state != ShortcutState::Pressed {
+        return;
+    }
+
+    let action = BINDINGS
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|b| b.shortcut.as_ref() == Some(shortcut))
+        .map(|b| b.action.id);
+    let Some(action) = action else {
+        return;
+    };
+
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = run_action(&app_handle, action).await {
+            println!("[Shortcuts] Failed to run {}: {}", action, e);
+        }
+    });
+}
+
+async fn run_action(app: &AppHandle<Wry>, action: &str) -> Result<(), String> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Ok(());
+    };
+
+    match action {
+        "toggle_main_window" => manager::toggle_main_window(app),
+        "toggle_content_pinned" => pin::set_pinned(&state, !manager::is_content_window_pinned())
+            .await
+            .map_err(|e| e.to_string()),
+        "tabs_activate_next" => tab_commands::tabs_activate_next(state).await.map(|_| ()),
+        "tabs_activate_previous" => tab_commands::tabs_activate_previous(state)
+            .await
+            .map(|_| ()),
+        "tabs_close_active" => tab_commands::tabs_close_active(state).await.map(|_| ()),
+        _ => match action.strip_prefix("tabs_activate_") {
+            Some(index) => activate_tab_at(state, index).await,
+            None => Ok(()),
+        },
+    }
+}
+
+/// 切换到当前分组的第 N 个标签，9 始终对应最后一个
+async fn activate_tab_at(state: tauri::State<'_, AppState>, index: &str) -> Result<(), String> {
+    let index: usize = index
+        .parse()
+        .map_err(|_| format!("invalid tab index {}", index))?;
+    let tabs = TabService::list(state.db())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let target = if index == 9 {
+        tabs.last()
+    } else {
+        tabs.get(index.saturating_sub(1))
+    };
+    if let Some(tab) = target {
+        let id = tab.id.clone();
+        tab_commands::tabs_activate(state, id).await?;
+    }
+    Ok(())
+}
+
+fn register_all(app: &AppHandle<Wry>, configured: Vec<(&'static ShortcutAction, Option<String>)>) {
+    let global_shortcut = app.global_shortcut();
+    if let Err(e) = global_shortcut.unregister_all() {
+        println!("[Shortcuts] Failed to unregister shortcuts: {}", e);
+    }
+
+    let mut active: Vec<ActiveBinding> = Vec::with_capacity(configured.len());
+    for (action, accelerator) in configured {
+        let mut binding = ActiveBinding {
+            action,
+            accelerator: accelerator.clone(),
+            shortcut: None,
+            error: None,
+        };
+
+        if let Some(accelerator) = accelerator {
+            match parse(&accelerator) {
+                Ok(shortcut) => {
+                    binding.shortcut = Some(shortcut);
+                    // 与前面的操作重复时只保留先注册的
+                    if let Some(other) = active.iter().find(|b| b.shortcut == Some(shortcut)) {
+                        binding.error = Some(format!("与「{}」冲突", other.action.label));
+                    } else if let Err(e) = global_shortcut.register(shortcut) {
+                        binding.error = Some(format!("注册失败（可能已被其他程序占用）: {}", e));
+                    }
+                }
+                Err(e) => binding.error = Some(e.to_string()),
+            }
+        }
+
+        if let Some(error) = &binding.error {
+            println!("[Shortcuts] {} not registered: {}", action.id, error);
+        }
+        active.push(binding);
+    }
+
+    *BINDINGS.lock().unwrap() = active;
+}
+
+fn parse(accelerator: &str) -> Result<Shortcut> {
+    accelerator
+        .parse::<Shortcut>()
+        .map_err(|e| anyhow!("无法解析快捷键 {}: {}", accelerator, e))
+}
+
+fn find_action(id: &str) -> Result<&'static ShortcutAction> {
+    ACTIONS
+        .iter()
+        .find(|action| action.id == id)
+        .ok_or_else(|| anyhow!("unknown shortcut action: {}", id))
+}
+
+fn setting_key(action_id: &str) -> String {
+    format!("{}{}", SETTING_PREFIX, action_id)
+}
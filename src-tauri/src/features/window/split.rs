@@ -0,0 +1,300 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::{AppHandle, Wry};
+
+use crate::features::tab::core::models::{clamp_ratio, SplitOrientation};
+use crate::features::window::layout::Rect;
+use crate::infrastructure::debounce::DebouncedWrites;
+
+/// 两个窗格之间的间隙（逻辑像素，按显示器缩放）
+pub const DIVIDER: f64 = 6.0;
+/// 拖动分隔停止后多久保存比例
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+static ACTIVE_SPLIT: Mutex<Option<SplitView>> = Mutex::new(None);
+/// 拖动后尚未保存比例的分屏组合
+static PENDING_RATIOS: DebouncedWrites<String, SplitView> = DebouncedWrites::new(SAVE_DELAY);
+static RATIO_LISTENER: Mutex<Option<fn(&AppHandle<Wry>, &SplitView)>> = Mutex::new(None);
+
+/// 分屏中的一个窗格
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPane {
+    pub tab_id: String,
+    pub url: String,
+}
+
+/// 当前在内容区展示的分屏组合
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitView {
+    pub id: String,
+    pub primary: SplitPane,
+    pub secondary: SplitPane,
+    pub orientation: SplitOrientation,
+    pub ratio: f64,
+}
+
+impl SplitView {
+    /// 与 tab 组合在一起的另一个窗格
+    pub fn partner(&self, tab_id: &str) -> Option<&SplitPane> {
+        if self.primary.tab_id == tab_id {
+            Some(&self.secondary)
+        } else if self.secondary.tab_id == tab_id {
+            Some(&self.primary)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, tab_id: &str) -> bool {
+        self.partner(tab_id).is_some()
+    }
+}
+
+/// 当前展示的分屏组合
+pub fn active_split() -> Option<SplitView> {
+    ACTIVE_SPLIT.lock().unwrap().clone()
+}
+
+/// 设置当前展示的分屏组合，None 表示只显示单个标签
+pub fn set_active_split(split: Option<SplitView>) {
+    *ACTIVE_SPLIT.lock().unwrap() = split.map(|mut split| {
+        split.ratio = clamp_ratio(split.ratio);
+        split
+    });
+}
+
+/// tab 是否属于当前展示的分屏组合
+pub fn is_split_pane(tab_id: &str) -> bool {
+    ACTIVE_SPLIT
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|split| split.contains(tab_id))
+}
+
+/// 当前组合中与 tab 并排显示的另一个窗格
+pub fn partner(tab_id: &str) -> Option<SplitPane> {
+    ACTIVE_SPLIT
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|split| split.partner(tab_id).cloned())
+}
+
+/// 记录窗格的最新 URL（webview 被回收后按此 URL 重建）
+pub fn update_pane_url(tab_id: &str, url: &str) {
+    if let Some(split) = ACTIVE_SPLIT.lock().unwrap().as_mut() {
+        for pane in [&mut split.primary, &mut split.secondary] {
+            if pane.tab_id == tab_id {
+                pane.url = url.to_string();
+            }
+        }
+    }
+}
+
+/// 修改当前组合的方向和比例（仅当其为 id 对应的组合时）
+pub fn update_active_split(id: &str, orientation: SplitOrientation, ratio: f64) -> bool {
+    let mut guard = ACTIVE_SPLIT.lock().unwrap();
+    match guard.as_mut() {
+        Some(split) if split.id == id => {
+            split.orientation = orientation;
+            split.ratio = clamp_ratio(ratio);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// 将内容区按方向和比例划分为 primary / secondary 两个窗格（物理坐标）
+pub fn pane_rects(
+    area: Rect,
+    orientation: SplitOrientation,
+    ratio: f64,
+    divider: i32,
+) -> (Rect, Rect) {
+    let ratio = clamp_ratio(ratio);
+    match orientation {
+        SplitOrientation::Horizontal => {
+            let available = (area.width - divider).max(0);
+            let first = (available as f64 * ratio).round() as i32;
+            (
+                Rect {
+                    width: first,
+                    ..area
+                },
+                Rect {
+                    x: area.x + first + divider,
+                    width: available - first,
+                    ..area
+                },
+            )
+        }
+        SplitOrientation::Vertical => {
+            let available = (area.height - divider).max(0);
+            let first = (available as f64 * ratio).round() as i32;
+            (
+                Rect {
+                    height: first,
+                    ..area
+                },
+                Rect {
+                    y: area.y + first + divider,
+                    height: available - first,
+                    ..area
+                },
+            )
+        }
+    }
+}
+
+/// 用户拖动窗格边缘后，根据窗格在分屏方向上的新尺寸计算比例
+///
+/// 尺寸与当前布局一致（程序调整引起的事件）时返回 None
+pub fn ratio_from_pane(
+    split: &SplitView,
+    tab_id: &str,
+    area: Rect,
+    pane_extent: i32,
+    divider: i32,
+) -> Option<f64> {
+    let available = match split.orientation {
+        SplitOrientation::Horizontal => area.width - divider,
+        SplitOrientation::Vertical => area.height - divider,
+    };
+    if available <= 0 {
+        return None;
+    }
+
+    let extent = pane_extent as f64 / available as f64;
+    let ratio = if split.primary.tab_id == tab_id {
+        extent
+    } else if split.secondary.tab_id == tab_id {
+        1.0 - extent
+    } else {
+        return None;
+    };
+    let ratio = clamp_ratio(ratio);
+    ((ratio - split.ratio).abs() >= 0.005).then_some(ratio)
+}
+
+/// 注册拖动分隔后保存比例的回调（由 tab feature 负责写入数据库并通知前端）
+pub fn on_ratio_changed(listener: fn(&AppHandle<Wry>, &SplitView)) {
+    *RATIO_LISTENER.lock().unwrap() = Some(listener);
+}
+
+/// 拖动分隔停止后，将拖动过的组合交给回调保存
+pub fn schedule_ratio_save(app: &AppHandle<Wry>) {
+    let Some(split) = active_split() else {
+        return;
+    };
+    PENDING_RATIOS.set(split.id.clone(), split);
+
+    let app_handle = app.clone();
+    PENDING_RATIOS.schedule(async move {
+        let Some(listener) = *RATIO_LISTENER.lock().unwrap() else {
+            return;
+        };
+        PENDING_RATIOS
+            .flush(|_, split| {
+                listener(&app_handle, &split);
+                async { Ok(()) }
+            })
+            .await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: Rect = Rect {
+        x: 100,
+        y: 50,
+        width: 1006,
+        height: 806,
+    };
+    const GAP: i32 = 6;
+
+    fn split(orientation: SplitOrientation, ratio: f64) -> SplitView {
+        let pane = |tab_id: &str| SplitPane {
+            tab_id: tab_id.to_string(),
+            url: format!("https://{}.example.com/", tab_id),
+        };
+        SplitView {
+            id: "split".to_string(),
+            primary: pane("left"),
+            secondary: pane("right"),
+            orientation,
+            ratio,
+        }
+    }
+
+    #[test]
+    fn panes_share_the_area_around_the_divider() {
+        let (primary, secondary) = pane_rects(AREA, SplitOrientation::Horizontal, 0.3, GAP);
+        assert_eq!(primary, Rect { width: 300, ..AREA });
+        assert_eq!(
+            secondary,
+            Rect {
+                x: 406,
+                width: 700,
+                ..AREA
+            }
+        );
+
+        let (primary, secondary) = pane_rects(AREA, SplitOrientation::Vertical, 0.5, GAP);
+        assert_eq!(
+            primary,
+            Rect {
+                height: 400,
+                ..AREA
+            }
+        );
+        assert_eq!(
+            secondary,
+            Rect {
+                y: 456,
+                height: 400,
+                ..AREA
+            }
+        );
+    }
+
+    #[test]
+    fn pane_ratio_is_clamped() {
+        let (primary, secondary) = pane_rects(AREA, SplitOrientation::Horizontal, 0.99, GAP);
+        assert_eq!((primary.width, secondary.width), (850, 150));
+
+        let (primary, secondary) = pane_rects(AREA, SplitOrientation::Horizontal, f64::NAN, GAP);
+        assert_eq!((primary.width, secondary.width), (500, 500));
+
+        // 比分隔还窄的区域不产生负尺寸
+        let narrow = Rect { width: 3, ..AREA };
+        let (primary, secondary) = pane_rects(narrow, SplitOrientation::Horizontal, 0.5, GAP);
+        assert_eq!((primary.width, secondary.width), (0, 0));
+    }
+
+    #[test]
+    fn ratio_follows_the_resized_pane() {
+        let view = split(SplitOrientation::Horizontal, 0.5);
+        assert_eq!(ratio_from_pane(&view, "left", AREA, 300, GAP), Some(0.3));
+        assert_eq!(ratio_from_pane(&view, "right", AREA, 300, GAP), Some(0.7));
+        assert_eq!(ratio_from_pane(&view, "left", AREA, 50, GAP), Some(0.15));
+
+        let view = split(SplitOrientation::Vertical, 0.5);
+        assert_eq!(ratio_from_pane(&view, "left", AREA, 200, GAP), Some(0.25));
+    }
+
+    #[test]
+    fn unchanged_or_unknown_panes_give_no_ratio() {
+        let view = split(SplitOrientation::Horizontal, 0.5);
+        // 程序按当前比例布局引起的尺寸变化
+        assert_eq!(ratio_from_pane(&view, "left", AREA, 500, GAP), None);
+        assert_eq!(ratio_from_pane(&view, "left", AREA, 502, GAP), None);
+        assert_eq!(ratio_from_pane(&view, "other", AREA, 300, GAP), None);
+
+        let collapsed = Rect { width: GAP, ..AREA };
+        assert_eq!(ratio_from_pane(&view, "left", collapsed, 0, GAP), None);
+    }
+}
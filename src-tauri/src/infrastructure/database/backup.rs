@@ -0,0 +1,261 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
+use serde::Serialize;
+
+use super::connection::{app_data_dir, DB_FILENAME};
+
+/// 备份所在目录（相对应用数据目录）
+const BACKUPS_DIR: &str = "backups";
+/// 保留的备份数量
+const MAX_BACKUPS: usize = 10;
+/// 待恢复的数据库文件（下次启动时替换数据库）
+const STAGED_RESTORE_SUFFIX: &str = ".restore";
+/// 定时备份的间隔
+pub const DAILY_BACKUP_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// 备份文件信息
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupInfo {
+    pub file_name: String,
+    /// 触发原因：pre-migration / daily / pre-restore
+    pub reason: String,
+    pub size_bytes: u64,
+    pub created_at: String,
+}
+
+/// 备份目录，不存在时创建
+pub fn backups_dir() -> Result<PathBuf> {
+    let dir = app_data_dir()?.join(BACKUPS_DIR);
+    fs::create_dir_all(&dir).context("failed to create backups directory")?;
+    Ok(dir)
+}
+
+/// 使用 `VACUUM INTO` 生成一致的快照，并删除超出数量的旧备份
+pub async fn create_backup(db: &DatabaseConnection, reason: &str) -> Result<BackupInfo> {
+    create_backup_in(db, &backups_dir()?, reason).await
+}
+
+async fn create_backup_in(db: &DatabaseConnection, dir: &Path, reason: &str) -> Result<BackupInfo> {
+    let file_name = format!(
+        "pet_focus-{}-{}.sqlite",
+        Utc::now().format("%Y%m%d-%H%M%S"),
+        reason
+    );
+    let path = dir.join(&file_name);
+    if path.exists() {
+        fs::remove_file(&path)?;
+    }
+
+    let target = path.to_string_lossy().replace('\'', "''");
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        format!("VACUUM INTO '{}'", target),
+    ))
+    .await
+    .with_context(|| format!("failed to back up database to {}", path.display()))?;
+
+    rotate(dir)?;
+    backup_info(&path)
+}
+
+/// 按时间倒序列出备份
+pub fn list_backups() -> Result<Vec<BackupInfo>> {
+    list_backups_in(&backups_dir()?)
+}
+
+fn list_backups_in(dir: &Path) -> Result<Vec<BackupInfo>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "sqlite"))
+        .collect();
+
+    // 文件名带时间戳，按名称倒序即为时间倒序
+    paths.sort();
+    paths.reverse();
+
+    paths.iter().map(|path| backup_info(path)).collect()
+}
+
+/// 距离最近一次备份是否已超过 interval（没有备份时为 true）
+pub fn is_backup_due(interval: Duration) -> Result<bool> {
+    let dir = backups_dir()?;
+    let latest = list_backups()?
+        .into_iter()
+        .next()
+        .and_then(|info| fs::metadata(dir.join(info.file_name)).ok()?.modified().ok());
+
+    Ok(match latest {
+        Some(modified) => SystemTime::now()
+            .duration_since(modified)
+            .map(|elapsed| elapsed >= interval)
+            .unwrap_or(false),
+        None => true,
+    })
+}
+
+/// 暂存要恢复的备份，并为当前数据库做一次 pre-restore 备份
+///
+/// 必须先暂存：新备份触发的轮换可能删除正要恢复的最旧备份
+pub async fn prepare_restore(db: &DatabaseConnection, file_name: &str) -> Result<()> {
+    prepare_restore_in(db, &backups_dir()?, &staged_restore_path()?, file_name).await
+}
+
+async fn prepare_restore_in(
+    db: &DatabaseConnection,
+    dir: &Path,
+    staged: &Path,
+    file_name: &str,
+) -> Result<()> {
+    stage_restore(dir, staged, file_name)?;
+    if let Err(e) = create_backup_in(db, dir, "pre-restore").await {
+        // 没有恢复前的备份就不恢复
+        let _ = fs::remove_file(staged);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// 将备份暂存为待恢复文件，下次启动打开数据库前替换
+///
+/// 运行中的连接池仍持有数据库文件，无法直接覆盖
+fn stage_restore(dir: &Path, staged: &Path, file_name: &str) -> Result<()> {
+    // 只接受备份目录中的文件名，避免恢复任意路径
+    if file_name.contains(['/', '\\']) || file_name.contains("..") {
+        return Err(anyhow!("invalid backup name: {}", file_name));
+    }
+
+    let source = dir.join(file_name);
+    if !source.is_file() {
+        return Err(anyhow!("backup not found: {}", file_name));
+    }
+
+    fs::copy(&source, staged).context("failed to stage backup for restore")?;
+    Ok(())
+}
+
+/// 如果存在待恢复文件，用它替换数据库
+pub(crate) fn apply_staged_restore(db_path: &Path) -> Result<()> {
+    let staged = staged_restore_path()?;
+    if !staged.is_file() {
+        return Ok(());
+    }
+
+    println!("[Database] Restoring database from staged backup");
+    for suffix in ["-wal", "-shm", "-journal"] {
+        let sidecar = PathBuf::from(format!("{}{}", db_path.display(), suffix));
+        if sidecar.exists() {
+            fs::remove_file(&sidecar)?;
+        }
+    }
+    fs::rename(&staged, db_path).context("failed to restore database from backup")?;
+
+    Ok(())
+}
+
+fn staged_restore_path() -> Result<PathBuf> {
+    Ok(app_data_dir()?.join(format!("{}{}", DB_FILENAME, STAGED_RESTORE_SUFFIX)))
+}
+
+fn rotate(dir: &Path) -> Result<()> {
+    for expired in list_backups_in(dir)?.into_iter().skip(MAX_BACKUPS) {
+        fs::remove_file(dir.join(&expired.file_name))?;
+    }
+    Ok(())
+}
+
+fn backup_info(path: &Path) -> Result<BackupInfo> {
+    let metadata = fs::metadata(path)?;
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    // pet_focus-<date>-<time>-<reason>.sqlite
+    let reason = file_name
+        .trim_end_matches(".sqlite")
+        .splitn(4, '-')
+        .nth(3)
+        .unwrap_or_default()
+        .to_string();
+    let created_at = metadata
+        .modified()
+        .map(|time| DateTime::<Utc>::from(time).to_rfc3339())
+        .unwrap_or_default();
+
+    Ok(BackupInfo {
+        file_name,
+        reason,
+        size_bytes: metadata.len(),
+        created_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::Database;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("nob-backups-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// 内存数据库不支持 `VACUUM INTO`，用目录中的文件数据库
+    async fn connect(dir: &Path) -> DatabaseConnection {
+        let url = format!("sqlite://{}?mode=rwc", dir.join("source.db").display());
+        Database::connect(url).await.unwrap()
+    }
+
+    fn fill(dir: &Path) {
+        for day in 1..=MAX_BACKUPS {
+            fs::write(
+                dir.join(format!("pet_focus-202501{:02}-000000-daily.sqlite", day)),
+                format!("backup {}", day),
+            )
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn restoring_the_oldest_backup_of_a_full_set() {
+        let dir = temp_dir();
+        fill(&dir);
+        let oldest = "pet_focus-20250101-000000-daily.sqlite";
+        let staged = dir.join(format!("{}{}", DB_FILENAME, STAGED_RESTORE_SUFFIX));
+        let db = connect(&dir).await;
+
+        prepare_restore_in(&db, &dir, &staged, oldest)
+            .await
+            .unwrap();
+
+        // 暂存的是最旧备份的内容，之后它才被 pre-restore 备份轮换掉
+        assert_eq!(fs::read_to_string(&staged).unwrap(), "backup 1");
+        let backups = list_backups_in(&dir).unwrap();
+        assert_eq!(backups.len(), MAX_BACKUPS);
+        assert_eq!(backups[0].reason, "pre-restore");
+        assert!(!dir.join(oldest).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn restore_rejects_paths_and_missing_backups() {
+        let dir = temp_dir();
+        fill(&dir);
+        let staged = dir.join(format!("{}{}", DB_FILENAME, STAGED_RESTORE_SUFFIX));
+        let db = connect(&dir).await;
+
+        for name in ["../pet_focus.sqlite", "sub/a.sqlite", "missing.sqlite"] {
+            assert!(prepare_restore_in(&db, &dir, &staged, name).await.is_err());
+        }
+        assert!(!staged.exists());
+        assert_eq!(list_backups_in(&dir).unwrap().len(), MAX_BACKUPS);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 use std::fs;
+use std::path::PathBuf;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
@@ -7,24 +8,35 @@ use sea_orm::DatabaseConnection;
 use sqlx::sqlite::SqliteConnectOptions;
 use tauri::AppHandle;
 
-const DB_FILENAME: &str = "pet_focus.sqlite";
+use super::backup;
+
+pub(crate) const DB_FILENAME: &str = "pet_focus.sqlite";
 const QUALIFIER: &str = "site";
 const ORGANIZATION: &str = "yiges";
 const APPLICATION: &str = "nob";
 
+/// 应用数据目录（数据库、导出文件等所在目录），不存在时创建
+pub fn app_data_dir() -> Result<PathBuf> {
+    let project_dirs = ProjectDirs::from(QUALIFIER, ORGANIZATION, APPLICATION)
+        .context("failed to resolve application data directory")?;
+    let app_dir = project_dirs.data_dir().to_path_buf();
+
+    fs::create_dir_all(&app_dir).context("failed to create application data directory")?;
+
+    Ok(app_dir)
+}
+
 /// 初始化数据库连接
 ///
 /// 只负责创建连接和启用外键，不执行任何 Migration
 /// Migration 由各个 Feature 通过 DatabaseRegistry 统一管理
 pub async fn init_db(_app_handle: &AppHandle) -> Result<DatabaseConnection> {
-    let project_dirs = ProjectDirs::from(QUALIFIER, ORGANIZATION, APPLICATION)
-        .context("failed to resolve application data directory")?;
-    let app_dir = project_dirs.data_dir();
-
-    fs::create_dir_all(app_dir).context("failed to create application data directory")?;
-
+    let app_dir = app_data_dir()?;
     let db_path = app_dir.join(DB_FILENAME);
 
+    // 上次运行中选择了恢复备份，在打开连接前替换数据库文件
+    backup::apply_staged_restore(&db_path)?;
+
     // 使用 SqliteConnectOptions 配置 SQLite 连接，启用外键约束
     let sqlite_opt = SqliteConnectOptions::new()
         .filename(&db_path)
@@ -0,0 +1,24 @@
+use sea_orm::entity::prelude::*;
+
+/// 已执行的 Migration 记录
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "schema_migrations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub name: String,
+    pub feature: String,
+    /// 执行时 Migration 声明的修订号
+    pub revision: i32,
+    pub applied_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unreachable!("schema_migrations has no relations")
+    }
+}
@@ -1,5 +1,8 @@
+pub mod backup;
 mod connection;
+mod migration_entity;
 mod registry;
+pub mod schema;
 
-pub use connection::init_db;
-pub use registry::DatabaseRegistry;
+pub use connection::{app_data_dir, init_db};
+pub use registry::{DatabaseRegistry, RegisteredMigration};
@@ -1,22 +1,45 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ConnectionTrait, DatabaseConnection, EntityTrait, Schema, Set,
+    TransactionTrait,
+};
 use sea_orm_migration::prelude::*;
-use std::future::Future;
-use std::pin::Pin;
-
-/// Migration 函数类型 - 使用 for<'a> 来处理生命周期
-type MigrationFn = Box<
-    dyn for<'a> Fn(
-            &'a SchemaManager<'a>,
-        ) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send + 'a>>
-        + Send
-        + Sync,
->;
+
+use super::migration_entity::{self, Entity as AppliedMigrationEntity};
+
+/// 一个已注册的 Migration 及其修订号
+pub struct RegisteredMigration {
+    migration: Box<dyn MigrationTrait>,
+    revision: i32,
+}
+
+impl RegisteredMigration {
+    /// revision 为 Migration 声明的修订号，执行时记录在 `schema_migrations` 中
+    ///
+    /// 已发布的 Migration 改变了执行结果时递增修订号，
+    /// 已执行过的数据库可以检测出与当前代码不一致
+    pub fn new<M>(migration: M, revision: i32) -> Self
+    where
+        M: MigrationTrait + 'static,
+    {
+        Self {
+            migration: Box::new(migration),
+            revision,
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.migration.name()
+    }
+}
 
 /// 数据库注册表
 ///
-/// 用于收集所有 Features 注册的 Migrations，并统一执行
+/// 收集各 Feature 注册的 Migrations，按注册顺序执行，
+/// 并在 `schema_migrations` 表中记录已执行的名称和修订号，每个 Migration 只执行一次
 pub struct DatabaseRegistry {
-    migrations: Vec<(&'static str, MigrationFn)>,
+    migrations: Vec<(&'static str, RegisteredMigration)>,
 }
 
 impl DatabaseRegistry {
@@ -26,32 +49,137 @@ impl DatabaseRegistry {
         }
     }
 
-    /// 注册一个 Migration
-    pub fn register_migration<F>(&mut self, name: &'static str, migration_fn: F)
-    where
-        F: for<'a> Fn(
-                &'a SchemaManager<'a>,
-            ) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send + 'a>>
-            + Send
-            + Sync
-            + 'static,
-    {
-        self.migrations.push((name, Box::new(migration_fn)));
+    /// 注册一个 Feature 的 Migrations（按列表顺序执行）
+    pub fn register_migrations(
+        &mut self,
+        feature: &'static str,
+        migrations: Vec<RegisteredMigration>,
+    ) {
+        for migration in migrations {
+            self.migrations.push((feature, migration));
+        }
+    }
+
+    /// 执行所有尚未执行的 Migrations
+    pub async fn run_migrations(&self, db: &DatabaseConnection) -> Result<()> {
+        self.check_unique_names()?;
+        Self::ensure_bookkeeping_table(db).await?;
+
+        let applied = AppliedMigrationEntity::find().all(db).await?;
+
+        for (feature, registered) in &self.migrations {
+            let name = registered.name();
+
+            if let Some(record) = applied.iter().find(|m| m.name == name) {
+                if record.revision != registered.revision {
+                    // 已执行的 Migration 不应再修改，新的变更需要新增 Migration
+                    let message = format!(
+                        "migration '{}' has changed since it was applied (revision {} -> {})",
+                        name, record.revision, registered.revision
+                    );
+                    if cfg!(debug_assertions) {
+                        return Err(anyhow!(message));
+                    }
+                    println!("[Database] Warning: {}", message);
+                }
+                continue;
+            }
+
+            println!("Running migration: {} ({})", name, feature);
+
+            let txn = db.begin().await?;
+            let manager = SchemaManager::new(&txn);
+            registered
+                .migration
+                .up(&manager)
+                .await
+                .map_err(|e| anyhow!("Migration '{}' failed: {}", name, e))?;
+
+            migration_entity::ActiveModel {
+                name: Set(name.to_owned()),
+                feature: Set((*feature).to_owned()),
+                revision: Set(registered.revision),
+                applied_at: Set(Utc::now()),
+            }
+            .insert(&txn)
+            .await?;
+            txn.commit().await?;
+        }
+
+        Ok(())
     }
 
-    /// 执行所有已注册的 Migrations
-    pub async fn run_migrations(&self, db: &sea_orm::DatabaseConnection) -> Result<()> {
-        let manager = SchemaManager::new(db);
+    /// 是否存在尚未执行的 Migrations
+    pub async fn has_pending(&self, db: &DatabaseConnection) -> Result<bool> {
+        Self::ensure_bookkeeping_table(db).await?;
+
+        let applied = AppliedMigrationEntity::find().all(db).await?;
+        Ok(self
+            .migrations
+            .iter()
+            .any(|(_, registered)| !applied.iter().any(|m| m.name == registered.name())))
+    }
 
-        for (name, migration_fn) in &self.migrations {
-            println!("Running migration: {}", name);
-            migration_fn(&manager)
+    /// 按执行的逆序回滚最近的 steps 个 Migrations（仅开发构建）
+    #[cfg(debug_assertions)]
+    pub async fn rollback(&self, db: &DatabaseConnection, steps: usize) -> Result<()> {
+        Self::ensure_bookkeeping_table(db).await?;
+
+        let applied = AppliedMigrationEntity::find().all(db).await?;
+
+        let to_rollback = self
+            .migrations
+            .iter()
+            .rev()
+            .filter(|(_, registered)| applied.iter().any(|m| m.name == registered.name()))
+            .take(steps);
+
+        for (feature, registered) in to_rollback {
+            let name = registered.name();
+            println!("Rolling back migration: {} ({})", name, feature);
+
+            let txn = db.begin().await?;
+            let manager = SchemaManager::new(&txn);
+            registered
+                .migration
+                .down(&manager)
                 .await
-                .map_err(|e| anyhow::anyhow!("Migration '{}' failed: {}", name, e))?;
+                .map_err(|e| anyhow!("Rollback of '{}' failed: {}", name, e))?;
+
+            AppliedMigrationEntity::delete_by_id(name.to_owned())
+                .exec(&txn)
+                .await?;
+            txn.commit().await?;
         }
 
         Ok(())
     }
+
+    fn check_unique_names(&self) -> Result<()> {
+        for (index, (_, registered)) in self.migrations.iter().enumerate() {
+            if self.migrations[..index]
+                .iter()
+                .any(|(_, other)| other.name() == registered.name())
+            {
+                return Err(anyhow!(
+                    "migration '{}' is registered more than once",
+                    registered.name()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn ensure_bookkeeping_table(db: &DatabaseConnection) -> Result<()> {
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_table = schema.create_table_from_entity(AppliedMigrationEntity);
+        create_table.if_not_exists();
+        db.execute(backend.build(&create_table)).await?;
+
+        Ok(())
+    }
 }
 
 impl Default for DatabaseRegistry {
@@ -59,3 +187,129 @@ impl Default for DatabaseRegistry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::Database;
+
+    /// 创建一张表；重复执行会失败，用来检查是否只执行一次
+    struct CreateTable(&'static str);
+
+    impl MigrationName for CreateTable {
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for CreateTable {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(Alias::new(self.0))
+                        .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                        .to_owned(),
+                )
+                .await
+        }
+
+        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .drop_table(Table::drop().table(Alias::new(self.0)).to_owned())
+                .await
+        }
+    }
+
+    fn registry(migrations: &[(&'static str, i32)]) -> DatabaseRegistry {
+        let mut registry = DatabaseRegistry::new();
+        registry.register_migrations(
+            "test",
+            migrations
+                .iter()
+                .map(|(name, revision)| RegisteredMigration::new(CreateTable(name), *revision))
+                .collect(),
+        );
+        registry
+    }
+
+    async fn applied(db: &DatabaseConnection) -> Vec<(String, i32)> {
+        AppliedMigrationEntity::find()
+            .all(db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| (m.name, m.revision))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn migrations_run_once_in_order() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let registry = registry(&[("m1_notes", 1), ("m2_tags", 1)]);
+
+        assert!(registry.has_pending(&db).await.unwrap());
+        registry.run_migrations(&db).await.unwrap();
+        assert!(!registry.has_pending(&db).await.unwrap());
+        // 第二次执行时跳过已执行的（重复建表会失败）
+        registry.run_migrations(&db).await.unwrap();
+
+        assert_eq!(
+            applied(&db).await,
+            vec![("m1_notes".to_string(), 1), ("m2_tags".to_string(), 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn only_new_migrations_run() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        registry(&[("m1_notes", 1)])
+            .run_migrations(&db)
+            .await
+            .unwrap();
+
+        let registry = registry(&[("m1_notes", 1), ("m2_tags", 1)]);
+        assert!(registry.has_pending(&db).await.unwrap());
+        registry.run_migrations(&db).await.unwrap();
+        assert_eq!(applied(&db).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn changed_revision_is_reported() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        registry(&[("m1_notes", 1)])
+            .run_migrations(&db)
+            .await
+            .unwrap();
+
+        let error = registry(&[("m1_notes", 2)])
+            .run_migrations(&db)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("revision 1 -> 2"), "{}", error);
+        assert_eq!(applied(&db).await, vec![("m1_notes".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn duplicate_names_are_rejected() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let duplicated = registry(&[("m1_notes", 1), ("m1_notes", 1)]);
+        assert!(duplicated.run_migrations(&db).await.is_err());
+        // 重名时一个都不执行
+        assert!(registry(&[("m1_notes", 1)]).has_pending(&db).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn rollback_reverts_the_latest_migrations() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let registry = registry(&[("m1_notes", 1), ("m2_tags", 1)]);
+        registry.run_migrations(&db).await.unwrap();
+
+        registry.rollback(&db, 1).await.unwrap();
+        assert_eq!(applied(&db).await, vec![("m1_notes".to_string(), 1)]);
+        // 回滚后可以重新执行
+        registry.run_migrations(&db).await.unwrap();
+        assert_eq!(applied(&db).await.len(), 2);
+    }
+}
@@ -0,0 +1,47 @@
+use sea_orm::{ConnectionTrait, DbErr, Statement};
+
+/// 检查表中是否存在指定列
+///
+/// sea-schema 未启用 sqlite 特性，`SchemaManager::has_column` 无法使用，
+/// 这里直接通过 `PRAGMA table_info` 查询
+pub async fn has_column<C>(conn: &C, table: &str, column: &str) -> Result<bool, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let backend = conn.get_database_backend();
+    let rows = conn
+        .query_all(Statement::from_string(
+            backend,
+            format!("PRAGMA table_info(\"{}\")", table),
+        ))
+        .await?;
+
+    for row in rows {
+        let name: String = row.try_get("", "name")?;
+        if name == column {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// 数据库中是否已有表（用于区分全新的数据库）
+pub async fn has_tables<C>(conn: &C) -> Result<bool, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let backend = conn.get_database_backend();
+    let row = conn
+        .query_one(Statement::from_string(
+            backend,
+            "SELECT COUNT(*) AS count FROM sqlite_master WHERE type = 'table'".to_string(),
+        ))
+        .await?;
+
+    let count: i64 = match row {
+        Some(row) => row.try_get("", "count")?,
+        None => 0,
+    };
+    Ok(count > 0)
+}
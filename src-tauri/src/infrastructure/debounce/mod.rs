@@ -0,0 +1,3 @@
+mod writes;
+
+pub use writes::DebouncedWrites;
@@ -0,0 +1,165 @@
+use std::borrow::Borrow;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::Result;
+use tauri::async_runtime::JoinHandle;
+
+/// 防抖写入队列：按 key 保留最新的值，停止更新 `delay` 后统一写入
+///
+/// 写入成功的才移出队列，失败的留到下次写入时重试；写入期间又更新过的值也留在队列中
+pub struct DebouncedWrites<K, V> {
+    delay: Duration,
+    pending: Mutex<Vec<(K, V)>>,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl<K, V> DebouncedWrites<K, V>
+where
+    K: Clone + PartialEq,
+    V: Clone + PartialEq,
+{
+    pub const fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending: Mutex::new(Vec::new()),
+            task: Mutex::new(None),
+        }
+    }
+
+    /// 记录 key 的最新值，替换尚未写入的旧值
+    pub fn set(&self, key: K, value: V) {
+        let mut pending = self.pending.lock().unwrap();
+        pending.retain(|(queued, _)| *queued != key);
+        pending.push((key, value));
+    }
+
+    /// key 尚未写入的值
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.pending
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(queued, _)| queued.borrow() == key)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// 丢弃 key 尚未写入的值
+    pub fn discard<Q>(&self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.pending
+            .lock()
+            .unwrap()
+            .retain(|(queued, _)| queued.borrow() != key);
+    }
+
+    /// 重新计时：取消上一次尚未执行的 `write`，`delay` 后执行这一次的
+    ///
+    /// `write` 通常调用 [`Self::flush`]，也可以一并写入队列之外的状态
+    pub fn schedule<F>(&self, write: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let delay = self.delay;
+        let mut task = self.task.lock().unwrap();
+        if let Some(previous) = task.take() {
+            previous.abort();
+        }
+        *task = Some(tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(delay).await;
+            write.await;
+        }));
+    }
+
+    /// 逐个写入队列中的值，返回写入失败的 key 和错误（由调用方记录日志）
+    pub async fn flush<W, Fut>(&self, mut write: W) -> Vec<(K, anyhow::Error)>
+    where
+        W: FnMut(K, V) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let pending = self.pending.lock().unwrap().clone();
+        let mut failed = Vec::new();
+        for (key, value) in pending {
+            match write(key.clone(), value.clone()).await {
+                Ok(()) => self
+                    .pending
+                    .lock()
+                    .unwrap()
+                    .retain(|(queued, latest)| !(*queued == key && *latest == value)),
+                Err(e) => failed.push((key, e)),
+            }
+        }
+        failed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn writes() -> DebouncedWrites<String, u32> {
+        DebouncedWrites::new(Duration::from_millis(500))
+    }
+
+    #[test]
+    fn keeps_the_latest_value_per_key() {
+        let writes = writes();
+        writes.set("a".to_string(), 1);
+        writes.set("b".to_string(), 2);
+        writes.set("a".to_string(), 3);
+        assert_eq!(writes.get("a"), Some(3));
+        assert_eq!(writes.get("b"), Some(2));
+
+        writes.discard("a");
+        assert_eq!(writes.get("a"), None);
+        assert_eq!(writes.get("b"), Some(2));
+    }
+
+    #[tokio::test]
+    async fn failed_writes_stay_queued() {
+        let writes = writes();
+        writes.set("ok".to_string(), 1);
+        writes.set("fails".to_string(), 2);
+
+        let failed = writes
+            .flush(|key, _| async move {
+                if key == "fails" {
+                    Err(anyhow!("disk full"))
+                } else {
+                    Ok(())
+                }
+            })
+            .await;
+
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, "fails");
+        assert_eq!(writes.get("ok"), None);
+        assert_eq!(writes.get("fails"), Some(2));
+    }
+
+    #[tokio::test]
+    async fn values_updated_during_a_write_stay_queued() {
+        let writes = writes();
+        writes.set("a".to_string(), 1);
+
+        let failed = writes
+            .flush(|key, _| {
+                // 写入期间又有新的值
+                writes.set(key, 2);
+                async { Ok(()) }
+            })
+            .await;
+
+        assert!(failed.is_empty());
+        assert_eq!(writes.get("a"), Some(2));
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use tauri::{AppHandle, Wry};
+
+/// 动态脚本生成回调（可生成多段，每段单独注入）
+pub type ScriptProvider = Arc<dyn Fn(&AppHandle<Wry>) -> Vec<String> + Send + Sync>;
+
+/// 脚本内容
+enum ScriptSource {
+    Static(&'static str),
+    /// 构建内容窗口时按当前状态生成
+    Dynamic(ScriptProvider),
+}
+
+/// 注入到内容窗口的脚本模块
+///
+/// - name: 唯一标识（建议以 Feature 名称为前缀，如 `tab.title_reporter`）
+/// - description: 显示文本
+/// - order: 注入顺序，小的先执行
+/// - reapply_on_load: 页面加载完成后按当前状态重新执行（窗口创建后状态可能已变化）
+/// - required: 其他模块依赖的模块，不能停用
+pub struct ScriptModule {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub order: i32,
+    pub reapply_on_load: bool,
+    pub required: bool,
+    source: ScriptSource,
+}
+
+impl ScriptModule {
+    /// 创建静态脚本模块
+    pub fn new(
+        name: &'static str,
+        description: &'static str,
+        order: i32,
+        source: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            description,
+            order,
+            reapply_on_load: false,
+            required: false,
+            source: ScriptSource::Static(source),
+        }
+    }
+
+    /// 创建动态脚本模块
+    pub fn dynamic<F>(
+        name: &'static str,
+        description: &'static str,
+        order: i32,
+        provider: F,
+    ) -> Self
+    where
+        F: Fn(&AppHandle<Wry>) -> Vec<String> + Send + Sync + 'static,
+    {
+        Self {
+            name,
+            description,
+            order,
+            reapply_on_load: false,
+            required: false,
+            source: ScriptSource::Dynamic(Arc::new(provider)),
+        }
+    }
+
+    /// 页面加载完成后重新执行
+    pub fn reapply_on_load(mut self) -> Self {
+        self.reapply_on_load = true;
+        self
+    }
+
+    /// 标记为不能停用
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    fn scripts(&self, app: &AppHandle<Wry>) -> Vec<String> {
+        match &self.source {
+            ScriptSource::Static(source) => vec![(*source).to_string()],
+            ScriptSource::Dynamic(provider) => provider(app),
+        }
+    }
+}
+
+/// 脚本模块信息（用于 API 响应）
+#[derive(Debug, Clone, Serialize)]
+pub struct ScriptModuleInfo {
+    pub name: String,
+    pub feature: String,
+    pub description: String,
+    pub order: i32,
+    pub required: bool,
+    pub enabled: bool,
+}
+
+/// 注入脚本注册表
+///
+/// 收集各 Feature 注册的脚本模块，构建内容窗口时按 order 组装为初始化脚本，
+/// 停用的模块在之后新建或重新加载的页面中不再注入
+pub struct InjectionRegistry {
+    modules: Vec<(&'static str, ScriptModule)>,
+    disabled: Mutex<HashSet<String>>,
+}
+
+impl InjectionRegistry {
+    pub fn new() -> Self {
+        Self {
+            modules: Vec::new(),
+            disabled: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 注册一个 Feature 的脚本模块
+    pub fn register_scripts(&mut self, feature: &'static str, modules: Vec<ScriptModule>) {
+        for module in modules {
+            if self.modules.iter().any(|(_, m)| m.name == module.name) {
+                println!(
+                    "[Injection] Script module '{}' is registered more than once",
+                    module.name
+                );
+                continue;
+            }
+            self.modules.push((feature, module));
+        }
+        self.modules.sort_by_key(|(_, module)| module.order);
+    }
+
+    /// 所有模块（按注入顺序）
+    pub fn modules(&self) -> Vec<ScriptModuleInfo> {
+        let disabled = self.disabled.lock().unwrap();
+        self.modules
+            .iter()
+            .map(|(feature, module)| ScriptModuleInfo {
+                name: module.name.to_string(),
+                feature: (*feature).to_string(),
+                description: module.description.to_string(),
+                order: module.order,
+                required: module.required,
+                enabled: module.required || !disabled.contains(module.name),
+            })
+            .collect()
+    }
+
+    /// 设置停用的模块（启动时从设置中恢复）
+    pub fn set_disabled(&self, names: Vec<String>) {
+        *self.disabled.lock().unwrap() = names.into_iter().collect();
+    }
+
+    /// 启用或停用模块，返回停用的模块列表
+    pub fn set_enabled(&self, name: &str, enabled: bool) -> Result<Vec<String>> {
+        let Some((_, module)) = self.modules.iter().find(|(_, module)| module.name == name) else {
+            return Err(anyhow!("unknown script module: {}", name));
+        };
+        if module.required && !enabled {
+            return Err(anyhow!("script module {} cannot be disabled", name));
+        }
+        let mut disabled = self.disabled.lock().unwrap();
+        if enabled {
+            disabled.remove(name);
+        } else {
+            disabled.insert(name.to_string());
+        }
+        let mut names: Vec<String> = disabled.iter().cloned().collect();
+        names.sort();
+        Ok(names)
+    }
+
+    /// 构建内容窗口时的初始化脚本
+    pub fn initialization_scripts(&self, app: &AppHandle<Wry>) -> Vec<String> {
+        self.collect(app, |_| true)
+    }
+
+    /// 页面加载完成后需要重新执行的脚本
+    pub fn reload_scripts(&self, app: &AppHandle<Wry>) -> Vec<String> {
+        self.collect(app, |module| module.reapply_on_load)
+    }
+
+    fn collect<P>(&self, app: &AppHandle<Wry>, predicate: P) -> Vec<String>
+    where
+        P: Fn(&ScriptModule) -> bool,
+    {
+        self.active_modules(predicate)
+            .into_iter()
+            .flat_map(|module| module.scripts(app))
+            .collect()
+    }
+
+    /// 需要注入的模块（按注入顺序），必需的模块不受停用设置影响
+    fn active_modules<P>(&self, predicate: P) -> Vec<&ScriptModule>
+    where
+        P: Fn(&ScriptModule) -> bool,
+    {
+        let disabled = self.disabled.lock().unwrap();
+        self.modules
+            .iter()
+            .map(|(_, module)| module)
+            .filter(|module| module.required || !disabled.contains(module.name))
+            .filter(|module| predicate(module))
+            .collect()
+    }
+}
+
+impl Default for InjectionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(name: &'static str, order: i32) -> ScriptModule {
+        ScriptModule::new(name, name, order, name)
+    }
+
+    fn registry() -> InjectionRegistry {
+        let mut registry = InjectionRegistry::new();
+        registry.register_scripts(
+            "tab",
+            vec![
+                module("tab.late", 40),
+                module("tab.reporter", 20).required(),
+            ],
+        );
+        registry.register_scripts(
+            "window",
+            vec![
+                module("window.bridge", 0).required(),
+                module("window.theme", 30).reapply_on_load(),
+            ],
+        );
+        registry
+    }
+
+    fn names(modules: Vec<&ScriptModule>) -> Vec<&'static str> {
+        modules.into_iter().map(|module| module.name).collect()
+    }
+
+    #[test]
+    fn modules_are_injected_by_order_across_features() {
+        let registry = registry();
+        assert_eq!(
+            names(registry.active_modules(|_| true)),
+            vec!["window.bridge", "tab.reporter", "window.theme", "tab.late"]
+        );
+        assert_eq!(
+            names(registry.active_modules(|module| module.reapply_on_load)),
+            vec!["window.theme"]
+        );
+
+        let features: Vec<String> = registry
+            .modules()
+            .into_iter()
+            .map(|info| info.feature)
+            .collect();
+        assert_eq!(features, vec!["window", "tab", "window", "tab"]);
+    }
+
+    #[test]
+    fn disabled_modules_are_skipped() {
+        let registry = registry();
+        assert_eq!(
+            registry.set_enabled("tab.late", false).unwrap(),
+            vec!["tab.late"]
+        );
+        assert_eq!(
+            names(registry.active_modules(|_| true)),
+            vec!["window.bridge", "tab.reporter", "window.theme"]
+        );
+        assert!(!registry.modules()[3].enabled);
+
+        assert!(registry.set_enabled("tab.late", true).unwrap().is_empty());
+        assert_eq!(registry.active_modules(|_| true).len(), 4);
+        assert!(registry.set_enabled("tab.unknown", false).is_err());
+    }
+
+    #[test]
+    fn required_modules_cannot_be_disabled() {
+        let registry = registry();
+        assert!(registry.set_enabled("tab.reporter", false).is_err());
+
+        // 设置中残留的停用记录也不影响必需的模块
+        registry.set_disabled(vec![
+            "window.bridge".to_string(),
+            "window.theme".to_string(),
+        ]);
+        assert_eq!(
+            names(registry.active_modules(|_| true)),
+            vec!["window.bridge", "tab.reporter", "tab.late"]
+        );
+        assert!(registry.modules()[0].enabled);
+    }
+
+    #[test]
+    fn duplicate_names_are_rejected() {
+        let mut registry = registry();
+        registry.register_scripts("other", vec![module("tab.late", -10)]);
+
+        let modules = registry.modules();
+        assert_eq!(modules.len(), 4);
+        let late = modules.iter().find(|info| info.name == "tab.late").unwrap();
+        assert_eq!((late.feature.as_str(), late.order), ("tab", 40));
+    }
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+use tauri::{Emitter, EventTarget, Runtime};
+
+/// 应用界面所在的 webview
+pub const APP_WEBVIEW: &str = "main";
+
+/// 只向应用界面发送事件
+///
+/// 标签、分组等事件带有各个标签的 URL 和标题，不能发给内容窗口中的远程页面；
+/// 远程页面也没有 `core:event:allow-listen` 权限（见 capabilities/content.json），
+/// 因为 JS 端默认以 Any 为目标监听，会收到 `emit_to` 发给其他窗口的事件
+pub fn emit_to_app<R, E, S>(emitter: &E, event: &str, payload: S) -> tauri::Result<()>
+where
+    R: Runtime,
+    E: Emitter<R>,
+    S: Serialize + Clone,
+{
+    emitter.emit_to(EventTarget::webview_window(APP_WEBVIEW), event, payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    #[test]
+    fn content_windows_cannot_listen_to_app_events() {
+        let capability: Value =
+            serde_json::from_str(include_str!("../../../capabilities/content.json")).unwrap();
+        let permissions = capability["permissions"].as_array().unwrap();
+        assert!(permissions.iter().all(|permission| {
+            let identifier = permission
+                .as_str()
+                .or_else(|| permission["identifier"].as_str())
+                .unwrap_or_default();
+            !identifier.starts_with("core:event:") && identifier != "core:default"
+        }));
+    }
+}
@@ -0,0 +1,457 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use url::Url;
+
+use super::events::APP_WEBVIEW;
+use super::rate_limit::{RateLimit, RateLimiter};
+use super::validate::{self, Validator};
+
+/// 应用自身的窗口（加载本地前端），其余窗口都按远程页面处理
+const APP_WEBVIEWS: &[&str] = &[APP_WEBVIEW];
+
+/// 命令的调用方
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Caller {
+    /// 应用自身的前端
+    App,
+    /// 内容窗口或分离窗口中的远程页面
+    Content,
+}
+
+impl Caller {
+    pub fn from_label(label: &str) -> Self {
+        if APP_WEBVIEWS.contains(&label) {
+            Self::App
+        } else {
+            Self::Content
+        }
+    }
+}
+
+/// 远程页面可以调用的命令
+struct ContentCommand {
+    name: &'static str,
+    validate: Validator,
+    limit: RateLimit,
+    /// 回复类命令：只接受应用请求过的窗口在有效期内回复一次（见 [`IpcGuard::expect_reply`]）
+    reply: bool,
+}
+
+/// 应用发出请求后等待回复的时间
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 应用向内容窗口发出、尚未收到回复的请求
+struct PendingReply {
+    label: String,
+    command: &'static str,
+    expires_at: Instant,
+}
+
+const fn per_10s(max: usize) -> RateLimit {
+    RateLimit::new(max, Duration::from_secs(10))
+}
+
+/// 远程页面只能调用上报类命令和打开链接，参数逐个校验，按窗口限制调用频率
+const CONTENT_COMMANDS: &[ContentCommand] = &[
+    ContentCommand {
+        name: "tabs_report_navigation",
+        validate: validate::page_url,
+        limit: per_10s(60),
+        reply: false,
+    },
+    ContentCommand {
+        name: "tabs_report_title",
+        validate: validate::title,
+        limit: per_10s(60),
+        reply: false,
+    },
+    ContentCommand {
+        name: "tabs_report_favicon",
+        validate: validate::favicons,
+        limit: per_10s(20),
+        reply: false,
+    },
+    ContentCommand {
+        name: "tabs_respond_url",
+        validate: validate::page_url,
+        limit: per_10s(10),
+        reply: true,
+    },
+    ContentCommand {
+        name: "tabs_open_link",
+        validate: validate::web_url,
+        limit: per_10s(5),
+        reply: false,
+    },
+    ContentCommand {
+        name: "tabs_open_external",
+        validate: validate::external_url,
+        limit: per_10s(5),
+        reply: false,
+    },
+];
+
+/// 应用命令的调用检查（在命令处理器之前执行）
+///
+/// 插件命令由 capabilities 控制；应用命令没有 ACL 清单，所有窗口都能调用，
+/// 因此在这里按调用方限制
+pub struct IpcGuard {
+    limiter: RateLimiter,
+    pending_replies: Mutex<Vec<PendingReply>>,
+}
+
+impl IpcGuard {
+    pub fn new() -> Self {
+        Self {
+            limiter: RateLimiter::new(),
+            pending_replies: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 记录应用向 `label` 窗口发出的请求，该窗口可以在有效期内调用一次 `command` 回复
+    ///
+    /// 同一窗口重复请求时只保留最新的一个
+    pub fn expect_reply(&self, label: &str, command: &'static str, now: Instant) {
+        let mut pending = self.pending_replies.lock().unwrap();
+        pending.retain(|reply| !(reply.label == label && reply.command == command));
+        pending.push(PendingReply {
+            label: label.to_string(),
+            command,
+            expires_at: now + REPLY_TIMEOUT,
+        });
+    }
+
+    /// 取出 `label` 窗口尚未过期的请求，没有时返回 false
+    fn take_reply(&self, label: &str, command: &str, now: Instant) -> bool {
+        let mut pending = self.pending_replies.lock().unwrap();
+        pending.retain(|reply| reply.expires_at > now);
+        let Some(index) = pending
+            .iter()
+            .position(|reply| reply.label == label && reply.command == command)
+        else {
+            return false;
+        };
+        pending.remove(index);
+        true
+    }
+
+    /// 检查 `label` 窗口（当前页面为 `page`）调用 `command` 是否允许
+    pub fn check(
+        &self,
+        label: &str,
+        page: Option<&Url>,
+        command: &str,
+        args: &Value,
+        now: Instant,
+    ) -> Result<(), String> {
+        if Caller::from_label(label) == Caller::App {
+            return Ok(());
+        }
+        let Some(spec) = CONTENT_COMMANDS.iter().find(|spec| spec.name == command) else {
+            return Err(format!(
+                "command {} is not allowed from content pages",
+                command
+            ));
+        };
+        (spec.validate)(args, page).map_err(|e| format!("{}: {}", command, e))?;
+        if spec.reply && !self.take_reply(label, command, now) {
+            return Err(format!("{}: no pending request for this window", command));
+        }
+        if !self
+            .limiter
+            .try_acquire(&format!("{}:{}", label, command), spec.limit, now)
+        {
+            return Err(format!("{}: rate limit exceeded", command));
+        }
+        Ok(())
+    }
+}
+
+impl Default for IpcGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const CONTENT: &str = "content-0b4e7f";
+    const DETACHED: &str = "detached-0b4e7f";
+
+    fn url_args() -> Value {
+        json!({ "url": "https://example.com/" })
+    }
+
+    fn page() -> Url {
+        Url::parse("https://example.com/").unwrap()
+    }
+
+    fn icon_args(url: &str) -> Value {
+        json!({ "icons": [{ "url": url, "sizes": null }] })
+    }
+
+    #[test]
+    fn classifies_callers_by_label() {
+        assert_eq!(Caller::from_label("main"), Caller::App);
+        assert_eq!(Caller::from_label(CONTENT), Caller::Content);
+        assert_eq!(Caller::from_label(DETACHED), Caller::Content);
+        assert_eq!(Caller::from_label("anything-else"), Caller::Content);
+    }
+
+    #[test]
+    fn content_pages_cannot_call_privileged_commands() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        for label in [CONTENT, DETACHED] {
+            assert!(guard
+                .check(label, None, "tabs_close", &json!({ "id": "x" }), now)
+                .is_err());
+            assert!(guard
+                .check(
+                    label,
+                    None,
+                    "set_theme_preference",
+                    &json!({ "theme": "dark" }),
+                    now
+                )
+                .is_err());
+            assert!(guard
+                .check(label, None, "tabs_list", &json!({}), now)
+                .is_err());
+            assert!(guard
+                .check(label, None, "data_export", &json!({}), now)
+                .is_err());
+            assert!(guard
+                .check(label, None, "site_scripts_create", &json!({}), now)
+                .is_err());
+            assert!(guard
+                .check(label, None, "link_rules_update", &json!({}), now)
+                .is_err());
+            assert!(guard
+                .check(label, None, "tabs_create", &url_args(), now)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn main_window_can_call_any_command() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        assert!(guard
+            .check("main", None, "tabs_close", &json!({ "id": "x" }), now)
+            .is_ok());
+        assert!(guard
+            .check(
+                "main",
+                None,
+                "set_theme_preference",
+                &json!({ "theme": "dark" }),
+                now
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn content_pages_can_call_reporting_commands() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        assert!(guard
+            .check(
+                CONTENT,
+                Some(&page()),
+                "tabs_report_navigation",
+                &url_args(),
+                now
+            )
+            .is_ok());
+        assert!(guard
+            .check(
+                CONTENT,
+                None,
+                "tabs_report_title",
+                &json!({ "title": "Example" }),
+                now
+            )
+            .is_ok());
+        assert!(guard
+            .check(
+                CONTENT,
+                Some(&page()),
+                "tabs_report_favicon",
+                &json!({ "icons": [{ "url": "https://example.com/favicon.ico", "sizes": null }] }),
+                now
+            )
+            .is_ok());
+        guard.expect_reply(CONTENT, "tabs_respond_url", now);
+        assert!(guard
+            .check(CONTENT, Some(&page()), "tabs_respond_url", &url_args(), now)
+            .is_ok());
+        assert!(guard
+            .check(CONTENT, None, "tabs_open_link", &url_args(), now)
+            .is_ok());
+        assert!(guard
+            .check(
+                DETACHED,
+                None,
+                "tabs_open_external",
+                &json!({ "url": "mailto:a@example.com" }),
+                now
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn content_arguments_are_validated() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        let file = json!({ "url": "file:///etc/passwd" });
+        assert!(guard
+            .check(CONTENT, None, "tabs_report_navigation", &file, now)
+            .is_err());
+        assert!(guard
+            .check(CONTENT, None, "tabs_open_external", &file, now)
+            .is_err());
+        assert!(guard
+            .check(
+                CONTENT,
+                None,
+                "tabs_open_link",
+                &json!({ "url": "mailto:a@b.c" }),
+                now
+            )
+            .is_err());
+        assert!(guard
+            .check(
+                CONTENT,
+                None,
+                "tabs_report_title",
+                &json!({ "title": 42 }),
+                now
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn content_pages_cannot_report_another_origin() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        let evil = Url::parse("https://evil.com/").unwrap();
+        let bank = json!({ "url": "https://bank.example/login" });
+
+        assert!(guard
+            .check(CONTENT, Some(&evil), "tabs_report_navigation", &bank, now)
+            .is_err());
+        guard.expect_reply(CONTENT, "tabs_respond_url", now);
+        assert!(guard
+            .check(CONTENT, Some(&evil), "tabs_respond_url", &bank, now)
+            .is_err());
+        // 链接可以指向其他网站
+        assert!(guard
+            .check(CONTENT, Some(&evil), "tabs_open_link", &bank, now)
+            .is_ok());
+    }
+
+    #[test]
+    fn content_favicon_reports_keep_usable_icons() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        let report = |url: &str| {
+            guard.check(
+                CONTENT,
+                Some(&page()),
+                "tabs_report_favicon",
+                &icon_args(url),
+                now,
+            )
+        };
+
+        // 其他站点（CDN）的图标可以上报；本机和局域网地址在下载前被跳过
+        assert!(report("https://cdn.other.com/icon.png").is_ok());
+        assert!(report("http://127.0.0.1/favicon.ico").is_ok());
+        assert!(report("data:image/png;base64,AAAA").is_ok());
+        assert!(guard
+            .check(
+                CONTENT,
+                Some(&page()),
+                "tabs_report_favicon",
+                &json!({ "icons": [{ "url": 1 }] }),
+                now
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn unsolicited_url_response_is_rejected() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        let page = page();
+        let respond = |label: &str, now: Instant| {
+            guard.check(label, Some(&page), "tabs_respond_url", &url_args(), now)
+        };
+
+        // 应用没有请求时不接受回复
+        assert!(respond(CONTENT, now).is_err());
+
+        // 只有被请求的窗口能回复，且只能回复一次
+        guard.expect_reply(CONTENT, "tabs_respond_url", now);
+        assert!(respond(DETACHED, now).is_err());
+        assert!(respond(CONTENT, now).is_ok());
+        assert!(respond(CONTENT, now).is_err());
+
+        // 请求过期后不再接受
+        guard.expect_reply(CONTENT, "tabs_respond_url", now);
+        assert!(respond(CONTENT, now + REPLY_TIMEOUT).is_err());
+    }
+
+    #[test]
+    fn content_calls_are_rate_limited_per_window_and_command() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        for _ in 0..5 {
+            assert!(guard
+                .check(CONTENT, None, "tabs_open_link", &url_args(), now)
+                .is_ok());
+        }
+        assert!(guard
+            .check(CONTENT, None, "tabs_open_link", &url_args(), now)
+            .is_err());
+        // 其他窗口和其他命令不受影响
+        assert!(guard
+            .check(DETACHED, None, "tabs_open_link", &url_args(), now)
+            .is_ok());
+        assert!(guard
+            .check(
+                CONTENT,
+                Some(&page()),
+                "tabs_report_navigation",
+                &url_args(),
+                now
+            )
+            .is_ok());
+        // 时间窗口过后恢复
+        let later = now + Duration::from_secs(10);
+        assert!(guard
+            .check(CONTENT, None, "tabs_open_link", &url_args(), later)
+            .is_ok());
+    }
+
+    #[test]
+    fn invalid_calls_do_not_count_towards_the_limit() {
+        let guard = IpcGuard::new();
+        let now = Instant::now();
+        let bad = json!({ "url": "javascript:alert(1)" });
+        for _ in 0..10 {
+            assert!(guard
+                .check(CONTENT, None, "tabs_open_link", &bad, now)
+                .is_err());
+        }
+        assert!(guard
+            .check(CONTENT, None, "tabs_open_link", &url_args(), now)
+            .is_ok());
+    }
+}
@@ -0,0 +1,7 @@
+mod events;
+mod guard;
+mod rate_limit;
+mod validate;
+
+pub use events::{emit_to_app, APP_WEBVIEW};
+pub use guard::IpcGuard;
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 调用频率限制：`per` 时间内最多 `max` 次
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max: usize,
+    pub per: Duration,
+}
+
+impl RateLimit {
+    pub const fn new(max: usize, per: Duration) -> Self {
+        Self { max, per }
+    }
+}
+
+/// 滑动窗口限流器（按 key 分别计数）
+pub struct RateLimiter {
+    hits: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self {
+            hits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 记录一次调用，超出限制时返回 false（被拒绝的调用不计数）
+    pub fn try_acquire(&self, key: &str, limit: RateLimit, now: Instant) -> bool {
+        let mut hits = self.hits.lock().unwrap();
+        let window = hits.entry(key.to_string()).or_default();
+        while window
+            .front()
+            .is_some_and(|&hit| now.saturating_duration_since(hit) >= limit.per)
+        {
+            window.pop_front();
+        }
+        if window.len() >= limit.max {
+            return false;
+        }
+        window.push_back(now);
+        true
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: RateLimit = RateLimit::new(3, Duration::from_secs(10));
+
+    #[test]
+    fn rejects_calls_over_the_limit() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.try_acquire("a", LIMIT, now));
+        }
+        assert!(!limiter.try_acquire("a", LIMIT, now));
+        assert!(!limiter.try_acquire("a", LIMIT, now + Duration::from_secs(9)));
+    }
+
+    #[test]
+    fn allows_calls_again_after_the_window() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.try_acquire("a", LIMIT, now));
+        }
+        assert!(limiter.try_acquire("a", LIMIT, now + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn counts_keys_separately() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.try_acquire("a", LIMIT, now));
+        }
+        assert!(limiter.try_acquire("b", LIMIT, now));
+    }
+
+    #[test]
+    fn rejected_calls_do_not_extend_the_window() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.try_acquire("a", LIMIT, now));
+        }
+        for second in 1..10 {
+            assert!(!limiter.try_acquire("a", LIMIT, now + Duration::from_secs(second)));
+        }
+        assert!(limiter.try_acquire("a", LIMIT, now + Duration::from_secs(10)));
+    }
+}
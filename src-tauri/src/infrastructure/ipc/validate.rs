@@ -0,0 +1,234 @@
+use serde_json::{Map, Value};
+use url::Url;
+
+/// URL 最大长度
+const MAX_URL_LEN: usize = 4096;
+/// data: 图标的最大长度
+const MAX_DATA_URL_LEN: usize = 512 * 1024;
+/// 标题最大长度（字符数）
+const MAX_TITLE_LEN: usize = 2048;
+/// 一次上报的最多图标数
+const MAX_ICONS: usize = 64;
+/// 图标 sizes 属性的最大长度
+const MAX_SIZES_LEN: usize = 128;
+
+const WEB_SCHEMES: &[&str] = &["http", "https"];
+const EXTERNAL_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// 参数校验函数，`page` 为调用方窗口当前的页面地址
+pub type Validator = fn(&Value, Option<&Url>) -> Result<(), String>;
+
+/// `{ url }`：http/https 页面地址
+pub fn web_url(args: &Value, _page: Option<&Url>) -> Result<(), String> {
+    let args = object(args, &["url"])?;
+    url(string(args, "url", MAX_URL_LEN)?, WEB_SCHEMES).map(|_| ())
+}
+
+/// `{ url }`：页面上报的自身地址，必须与窗口当前页面同源
+///
+/// pushState 不能改变源，跨源的地址只可能是伪造的
+pub fn page_url(args: &Value, page: Option<&Url>) -> Result<(), String> {
+    let args = object(args, &["url"])?;
+    let reported = url(string(args, "url", MAX_URL_LEN)?, WEB_SCHEMES)?;
+    match page {
+        Some(page) if page.origin() == reported.origin() => Ok(()),
+        _ => Err("url must be on the same origin as the page".to_string()),
+    }
+}
+
+/// `{ url }`：用系统浏览器打开的地址（http/https/mailto/tel）
+pub fn external_url(args: &Value, _page: Option<&Url>) -> Result<(), String> {
+    let args = object(args, &["url"])?;
+    url(string(args, "url", MAX_URL_LEN)?, EXTERNAL_SCHEMES).map(|_| ())
+}
+
+/// `{ title }`
+pub fn title(args: &Value, _page: Option<&Url>) -> Result<(), String> {
+    let args = object(args, &["title"])?;
+    string(args, "title", MAX_TITLE_LEN).map(|_| ())
+}
+
+/// `{ icons: [{ url, sizes }] }`：只检查结构和长度
+///
+/// 不能下载的图标（本机或局域网地址、非图片的 data URL 等）由 `FaviconService::pick` 跳过，
+/// 不影响同一次上报中的其他图标
+pub fn favicons(args: &Value, _page: Option<&Url>) -> Result<(), String> {
+    let args = object(args, &["icons"])?;
+    let icons = args
+        .get("icons")
+        .and_then(Value::as_array)
+        .ok_or("argument 'icons' must be an array")?;
+    if icons.len() > MAX_ICONS {
+        return Err(format!("too many icons ({} > {})", icons.len(), MAX_ICONS));
+    }
+    for icon in icons {
+        let icon = object(icon, &["url", "sizes"])?;
+        let href = string(icon, "url", MAX_DATA_URL_LEN)?;
+        if !href.starts_with("data:") && href.len() > MAX_URL_LEN {
+            return Err(format!("argument 'url' is too long (> {})", MAX_URL_LEN));
+        }
+        match icon.get("sizes") {
+            None | Some(Value::Null) => {}
+            Some(_) => {
+                string(icon, "sizes", MAX_SIZES_LEN)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 参数必须是对象且只包含 `keys` 中的字段
+fn object<'a>(args: &'a Value, keys: &[&str]) -> Result<&'a Map<String, Value>, String> {
+    let args = args.as_object().ok_or("arguments must be an object")?;
+    if let Some(key) = args.keys().find(|key| !keys.contains(&key.as_str())) {
+        return Err(format!("unexpected argument '{}'", key));
+    }
+    Ok(args)
+}
+
+fn string<'a>(args: &'a Map<String, Value>, key: &str, max_len: usize) -> Result<&'a str, String> {
+    let value = args
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("argument '{}' must be a string", key))?;
+    if value.chars().count() > max_len {
+        return Err(format!("argument '{}' is too long (> {})", key, max_len));
+    }
+    Ok(value)
+}
+
+fn url(value: &str, schemes: &[&str]) -> Result<Url, String> {
+    let parsed = Url::parse(value).map_err(|e| format!("invalid url: {}", e))?;
+    if !schemes.contains(&parsed.scheme()) {
+        return Err(format!("url scheme '{}' is not allowed", parsed.scheme()));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn page() -> Url {
+        Url::parse("https://www.example.com/app").unwrap()
+    }
+
+    fn icon(url: &str) -> Value {
+        json!({ "icons": [{ "url": url, "sizes": null }] })
+    }
+
+    #[test]
+    fn web_url_accepts_http_pages() {
+        assert!(web_url(&json!({ "url": "https://example.com/a?b#c" }), None).is_ok());
+        assert!(web_url(&json!({ "url": "http://localhost:3000/" }), None).is_ok());
+    }
+
+    #[test]
+    fn web_url_rejects_other_schemes_and_garbage() {
+        for url in [
+            "file:///etc/passwd",
+            "javascript:alert(1)",
+            "tauri://localhost",
+            "",
+            "not a url",
+        ] {
+            assert!(web_url(&json!({ "url": url }), None).is_err(), "{}", url);
+        }
+        assert!(web_url(&json!({ "url": 1 }), None).is_err());
+        assert!(web_url(&json!({}), None).is_err());
+        assert!(web_url(&json!("https://example.com"), None).is_err());
+    }
+
+    #[test]
+    fn rejects_unexpected_arguments() {
+        let args = json!({ "url": "https://example.com", "id": "tab-1" });
+        assert!(web_url(&args, None).is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_values() {
+        let long = format!("https://example.com/{}", "a".repeat(MAX_URL_LEN));
+        assert!(web_url(&json!({ "url": long }), None).is_err());
+        assert!(title(&json!({ "title": "t".repeat(MAX_TITLE_LEN) }), None).is_ok());
+        assert!(title(&json!({ "title": "t".repeat(MAX_TITLE_LEN + 1) }), None).is_err());
+    }
+
+    #[test]
+    fn page_url_must_be_same_origin_as_the_page() {
+        let page = page();
+        let report = |url: &str, page: Option<&Url>| page_url(&json!({ "url": url }), page);
+
+        assert!(report("https://www.example.com/other?q=1#top", Some(&page)).is_ok());
+        for url in [
+            "https://bank.example/login",
+            "https://example.com/app",
+            "http://www.example.com/app",
+            "https://www.example.com:8443/app",
+        ] {
+            assert!(report(url, Some(&page)).is_err(), "{}", url);
+        }
+        // 不知道页面地址时无法确认
+        assert!(report("https://www.example.com/app", None).is_err());
+        assert!(report("javascript:alert(1)", Some(&page)).is_err());
+    }
+
+    #[test]
+    fn external_url_allows_mail_and_phone_links() {
+        assert!(external_url(&json!({ "url": "mailto:a@example.com" }), None).is_ok());
+        assert!(external_url(&json!({ "url": "tel:+123" }), None).is_ok());
+        let app = json!({ "url": "file:///Applications/Calculator.app" });
+        assert!(external_url(&app, None).is_err());
+        assert!(external_url(&json!({ "url": "smb://server/share" }), None).is_err());
+    }
+
+    #[test]
+    fn favicons_accepts_web_and_image_data_urls() {
+        let args = json!({
+            "icons": [
+                { "url": "https://example.com/favicon.ico", "sizes": null },
+                { "url": "data:image/png;base64,AAAA", "sizes": "16x16 32x32" },
+                { "url": "https://github.githubassets.com/favicons/favicon.svg" },
+            ]
+        });
+        assert!(favicons(&args, Some(&page())).is_ok());
+    }
+
+    #[test]
+    fn favicons_leaves_unusable_icons_to_the_picker() {
+        // 不能下载的图标在选择时跳过，不拒绝整次上报
+        let args = json!({
+            "icons": [
+                { "url": "http://127.0.0.1/favicon.ico" },
+                { "url": "data:text/html,<script>" },
+                { "url": "https://example.com/favicon.ico" },
+            ]
+        });
+        assert!(favicons(&args, Some(&page())).is_ok());
+    }
+
+    #[test]
+    fn favicons_rejects_malformed_icons() {
+        let page = page();
+        assert!(favicons(
+            &json!({ "icons": "https://example.com/a.ico" }),
+            Some(&page)
+        )
+        .is_err());
+        assert!(favicons(&json!({ "icons": [{ "url": 1 }] }), Some(&page)).is_err());
+        let extra = json!({ "icons": [{ "url": "https://example.com/a.ico", "rel": "icon" }] });
+        assert!(favicons(&extra, Some(&page)).is_err());
+        let sizes = json!({ "icons": [{ "url": "https://example.com/a.ico", "sizes": 16 }] });
+        assert!(favicons(&sizes, Some(&page)).is_err());
+        let long = format!("https://example.com/{}", "a".repeat(MAX_URL_LEN));
+        assert!(favicons(&icon(&long), Some(&page)).is_err());
+    }
+
+    #[test]
+    fn favicons_rejects_too_many_icons() {
+        let icons: Vec<_> = (0..=MAX_ICONS)
+            .map(|i| json!({ "url": format!("https://example.com/{}.png", i) }))
+            .collect();
+        assert!(favicons(&json!({ "icons": icons }), Some(&page())).is_err());
+    }
+}
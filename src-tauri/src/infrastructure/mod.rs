@@ -1,2 +1,6 @@
 pub mod database;
+pub mod debounce;
+pub mod injection;
+pub mod ipc;
 pub mod tray;
+pub mod web;
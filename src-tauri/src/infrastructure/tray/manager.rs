@@ -1,9 +1,10 @@
 use tauri::{
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    AppHandle, Wry,
+    AppHandle, Manager, Wry,
 };
 
 use super::registry::TrayRegistry;
+use crate::core::AppState;
 use crate::features::window::manager as window;
 
 /// 托盘管理器
@@ -57,6 +58,11 @@ impl TrayManager {
                 // 从 handlers map 中查找并调用对应的 handler
                 if let Some(handler) = handlers.get(event.id.as_ref()) {
                     handler(&app);
+                } else if let Some(state) = app.try_state::<AppState>() {
+                    // 动态子菜单项的 handler 在每次构建菜单时记录在 registry 中
+                    if let Some(handler) = state.tray_manager().dynamic_handler(event.id.as_ref()) {
+                        handler(app);
+                    }
                 }
             })
             .build(app)?;
@@ -75,6 +81,11 @@ impl TrayManager {
         Ok(())
     }
 
+    /// 查找动态子菜单项的 handler
+    pub fn dynamic_handler(&self, id: &str) -> Option<super::registry::TrayHandler> {
+        self.registry.dynamic_handler(id)
+    }
+
     /// 设置托盘 Tooltip 文本
     pub fn set_tooltip(&self, app: &AppHandle<Wry>, text: &str) -> tauri::Result<()> {
         if let Some(tray) = app.tray_by_id("main") {
@@ -4,4 +4,4 @@ mod registry;
 
 pub use items::*;
 pub use manager::TrayManager;
-pub use registry::{TrayMenuItem, TrayRegistry};
+pub use registry::{TrayMenuItem, TrayRegistry, TraySubmenu};
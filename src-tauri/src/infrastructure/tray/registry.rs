@@ -1,10 +1,20 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 use tauri::{
-    menu::{Menu, MenuItem, PredefinedMenuItem},
+    menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu},
     AppHandle, Wry,
 };
 
+/// 托盘菜单项点击回调
+pub type TrayHandler = Arc<dyn Fn(&AppHandle) + Send + Sync>;
+
+/// 动态子菜单项生成回调
+pub type TrayItemsProvider = Arc<dyn Fn(&AppHandle) -> Vec<TrayMenuItem> + Send + Sync>;
+
+/// 菜单项状态判断回调（可见、可用、勾选）
+pub type TrayPredicate = Arc<dyn Fn(&AppHandle) -> bool + Send + Sync>;
+
 /// 托盘菜单项定义
 ///
 /// 每个 Feature 可以提供多个托盘菜单项，包含：
@@ -12,12 +22,14 @@ use tauri::{
 /// - label: 显示文本
 /// - handler: 点击时的回调函数
 /// - is_visible: 每次构建菜单时判断是否显示
+/// - is_checked: 设置后显示为可勾选的菜单项
 pub struct TrayMenuItem {
     pub id: String,
     pub label: String,
-    pub handler: Arc<dyn Fn(&AppHandle) + Send + Sync>,
+    pub handler: TrayHandler,
     pub is_visible: Arc<dyn Fn(&AppHandle) -> bool + Send + Sync>,
     pub is_enabled: Arc<dyn Fn(&AppHandle) -> bool + Send + Sync>,
+    pub is_checked: Option<TrayPredicate>,
 }
 
 impl TrayMenuItem {
@@ -38,6 +50,7 @@ impl TrayMenuItem {
             handler: Arc::new(handler),
             is_visible: Arc::new(is_visible),
             is_enabled: Arc::new(|_| true),
+            is_checked: None,
         }
     }
 
@@ -50,6 +63,37 @@ impl TrayMenuItem {
         self
     }
 
+    /// 设置勾选状态检查函数（菜单项显示为可勾选）
+    pub fn with_checked<C>(mut self, is_checked: C) -> Self
+    where
+        C: Fn(&AppHandle) -> bool + Send + Sync + 'static,
+    {
+        self.is_checked = Some(Arc::new(is_checked));
+        self
+    }
+
+    /// 构建对应的菜单项
+    fn build(&self, app: &AppHandle<Wry>) -> tauri::Result<Box<dyn tauri::menu::IsMenuItem<Wry>>> {
+        let enabled = (self.is_enabled)(app);
+        Ok(match &self.is_checked {
+            Some(is_checked) => Box::new(CheckMenuItem::with_id(
+                app,
+                &self.id,
+                &self.label,
+                enabled,
+                is_checked(app),
+                None::<&str>,
+            )?),
+            None => Box::new(MenuItem::with_id(
+                app,
+                &self.id,
+                &self.label,
+                enabled,
+                None::<&str>,
+            )?),
+        })
+    }
+
     /// 创建总是可见的托盘菜单项
     pub fn always_visible<F>(id: impl Into<String>, label: impl Into<String>, handler: F) -> Self
     where
@@ -59,12 +103,46 @@ impl TrayMenuItem {
     }
 }
 
+/// 动态子菜单定义
+///
+/// 子菜单项在每次构建菜单时通过 items 回调生成（例如最近关闭的标签页），
+/// 其点击回调由 TrayRegistry 在构建时记录，点击时再分发
+pub struct TraySubmenu {
+    pub id: String,
+    pub label: String,
+    pub items: TrayItemsProvider,
+    /// 子菜单为空时显示的占位文本
+    pub empty_label: String,
+}
+
+impl TraySubmenu {
+    /// 创建动态子菜单
+    pub fn new<I>(
+        id: impl Into<String>,
+        label: impl Into<String>,
+        empty_label: impl Into<String>,
+        items: I,
+    ) -> Self
+    where
+        I: Fn(&AppHandle) -> Vec<TrayMenuItem> + Send + Sync + 'static,
+    {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            items: Arc::new(items),
+            empty_label: empty_label.into(),
+        }
+    }
+}
+
 /// 托盘菜单布局项
 ///
 /// 用于在 registry 中手动布局菜单结构
 pub enum TrayMenuLayout {
     /// 菜单项
     Item(TrayMenuItem),
+    /// 动态子菜单
+    Submenu(TraySubmenu),
     /// 分隔符
     Separator,
 }
@@ -76,11 +154,16 @@ pub enum TrayMenuLayout {
 /// 类似于 `lib.rs` 中手动注册 commands，这里也需要手动引入各个 Feature 的托盘项并布局。
 pub struct TrayRegistry {
     layout: Vec<TrayMenuLayout>,
+    /// 动态子菜单项的回调（每次构建菜单时重建）
+    dynamic_handlers: Mutex<HashMap<String, TrayHandler>>,
 }
 
 impl TrayRegistry {
     pub fn new() -> Self {
-        Self { layout: Vec::new() }
+        Self {
+            layout: Vec::new(),
+            dynamic_handlers: Mutex::new(HashMap::new()),
+        }
     }
 
     /// 添加菜单项到布局
@@ -88,6 +171,11 @@ impl TrayRegistry {
         self.layout.push(TrayMenuLayout::Item(item));
     }
 
+    /// 添加动态子菜单到布局
+    pub fn add_submenu(&mut self, submenu: TraySubmenu) {
+        self.layout.push(TrayMenuLayout::Submenu(submenu));
+    }
+
     /// 添加分隔符到布局
     pub fn add_separator(&mut self) {
         self.layout.push(TrayMenuLayout::Separator);
@@ -96,18 +184,39 @@ impl TrayRegistry {
     /// 根据布局构建托盘菜单
     pub fn build_menu(&self, app: &AppHandle<Wry>) -> tauri::Result<Menu<Wry>> {
         let mut menu_items: Vec<Box<dyn tauri::menu::IsMenuItem<Wry>>> = Vec::new();
+        let mut dynamic_handlers = HashMap::new();
 
         for layout_item in &self.layout {
             match layout_item {
                 TrayMenuLayout::Item(item) => {
                     // 检查可见性
                     if (item.is_visible)(app) {
-                        let enabled = (item.is_enabled)(app);
-                        let menu_item =
-                            MenuItem::with_id(app, &item.id, &item.label, enabled, None::<&str>)?;
-                        menu_items.push(Box::new(menu_item));
+                        menu_items.push(item.build(app)?);
                     }
                 }
+                TrayMenuLayout::Submenu(submenu) => {
+                    let items = (submenu.items)(app);
+                    let submenu_item = Submenu::with_id(app, &submenu.id, &submenu.label, true)?;
+
+                    if items.is_empty() {
+                        let placeholder = MenuItem::with_id(
+                            app,
+                            format!("{}:empty", submenu.id),
+                            &submenu.empty_label,
+                            false,
+                            None::<&str>,
+                        )?;
+                        submenu_item.append(&placeholder)?;
+                    }
+
+                    for item in items.into_iter().filter(|item| (item.is_visible)(app)) {
+                        let menu_item = item.build(app)?;
+                        submenu_item.append(menu_item.as_ref())?;
+                        dynamic_handlers.insert(item.id, item.handler);
+                    }
+
+                    menu_items.push(Box::new(submenu_item));
+                }
                 TrayMenuLayout::Separator => {
                     let separator = PredefinedMenuItem::separator(app)?;
                     menu_items.push(Box::new(separator));
@@ -121,9 +230,16 @@ impl TrayRegistry {
             .map(|item| &**item as &dyn tauri::menu::IsMenuItem<Wry>)
             .collect();
 
+        *self.dynamic_handlers.lock().unwrap() = dynamic_handlers;
+
         Menu::with_items(app, &menu_item_refs)
     }
 
+    /// 查找动态子菜单项的回调
+    pub fn dynamic_handler(&self, id: &str) -> Option<TrayHandler> {
+        self.dynamic_handlers.lock().unwrap().get(id).cloned()
+    }
+
     /// 获取所有布局项
     pub fn layout(&self) -> &[TrayMenuLayout] {
         &self.layout
@@ -0,0 +1,123 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use url::{Host, Url};
+
+/// 指向本机的主机名（`localhost` 及其子域名）
+pub fn is_local_name(name: &str) -> bool {
+    let name = name.trim_end_matches('.').to_ascii_lowercase();
+    name == "localhost" || name.ends_with(".localhost")
+}
+
+/// 是否为公网地址：排除回环、私有、链路本地、组播和保留地址
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => is_public_ipv6(ip),
+    }
+}
+
+/// 主机是否可以从应用后台访问：拒绝 localhost 和非公网的 IP 地址
+///
+/// 域名只检查名字本身，解析出的地址需要在连接前另行检查
+pub fn is_public_host(host: &Host<&str>) -> bool {
+    match host {
+        Host::Domain(domain) => !domain.is_empty() && !is_local_name(domain),
+        Host::Ipv4(ip) => is_public_ipv4(*ip),
+        Host::Ipv6(ip) => is_public_ipv6(*ip),
+    }
+}
+
+/// URL 是否为指向公网主机的 http/https 地址
+pub fn is_public_url(url: &Url) -> bool {
+    matches!(url.scheme(), "http" | "https") && url.host().is_some_and(|host| is_public_host(&host))
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8、100.64.0.0/10（运营商 NAT）、198.18.0.0/15（基准测试）、240.0.0.0/4（保留）
+        || a == 0
+        || (a == 100 && (64..128).contains(&b))
+        || (a == 198 && (b == 18 || b == 19))
+        || a >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    // IPv4 映射地址（::ffff:a.b.c.d）和 NAT64 地址（64:ff9b::a.b.c.d）按内嵌的 IPv4 地址判断
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_public_ipv4(v4);
+    }
+    let segments = ip.segments();
+    if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        let [.., a, b, c, d] = ip.octets();
+        return is_public_ipv4(Ipv4Addr::new(a, b, c, d));
+    }
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // 唯一本地地址 fc00::/7、链路本地 fe80::/10、站点本地 fec0::/10、文档 2001:db8::/32
+        || (segments[0] & 0xfe00) == 0xfc00
+        || (segments[0] & 0xffc0) == 0xfe80
+        || (segments[0] & 0xffc0) == 0xfec0
+        || (segments[0] == 0x2001 && segments[1] == 0x0db8)
+        // 已废弃的 IPv4 兼容地址 ::a.b.c.d
+        || segments[..6] == [0; 6])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn public(url: &str) -> bool {
+        is_public_url(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn accepts_public_hosts() {
+        assert!(public("https://example.com/favicon.ico"));
+        assert!(public("http://93.184.216.34/"));
+        assert!(public("http://[2606:2800:220:1:248:1893:25c8:1946]/"));
+        assert!(public("https://100.63.0.1/"));
+    }
+
+    #[test]
+    fn rejects_local_names() {
+        assert!(!public("http://localhost:8080/"));
+        assert!(!public("http://LOCALHOST./"));
+        assert!(!public("http://app.localhost/"));
+        assert!(!public("file:///etc/passwd"));
+    }
+
+    #[test]
+    fn rejects_loopback_private_and_link_local_addresses() {
+        for url in [
+            "http://127.0.0.1/",
+            "http://127.1/",
+            "http://0x7f000001/",
+            "http://2130706433/",
+            "http://0.0.0.0/",
+            "http://10.0.0.1/",
+            "http://172.16.5.4/",
+            "http://192.168.0.1/",
+            "http://169.254.169.254/latest/meta-data/",
+            "http://100.64.0.1/",
+            "http://255.255.255.255/",
+            "http://[::1]/",
+            "http://[::]/",
+            "http://[fe80::1]/",
+            "http://[fd00::1]/",
+            "http://[::ffff:127.0.0.1]/",
+            "http://[::ffff:192.168.0.1]/",
+            "http://[64:ff9b::10.0.0.1]/",
+            "http://[::127.0.0.1]/",
+        ] {
+            assert!(!public(url), "{}", url);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::redirect::Policy;
+use url::Url;
+
+use super::address::{is_local_name, is_public_ip, is_public_url};
+
+/// 最多跟随的重定向次数
+const MAX_REDIRECTS: usize = 5;
+
+/// 下载到的响应内容
+pub struct FetchedBody {
+    /// 响应声明的 MIME 类型（不含参数）
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// 下载公网上的资源，响应体超过 `max_bytes` 时中止
+///
+/// 只连接公网地址：域名解析后过滤回环、私有和链路本地地址，每一跳重定向都重新检查，
+/// 避免远程页面借应用访问本机或局域网服务
+pub async fn fetch_public(url: &str, timeout: Duration, max_bytes: usize) -> Result<FetchedBody> {
+    let parsed = Url::parse(url)?;
+    if !is_public_url(&parsed) {
+        return Err(anyhow!("refusing to fetch non-public url: {}", url));
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        // 经过代理时由代理解析域名，会绕过地址检查
+        .no_proxy()
+        .dns_resolver(Arc::new(PublicResolver))
+        .redirect(Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if !is_public_url(attempt.url()) {
+                let target = attempt.url().to_string();
+                attempt.error(format!("redirect to non-public url: {}", target))
+            } else {
+                attempt.follow()
+            }
+        }))
+        .build()?;
+
+    let mut response = client.get(parsed).send().await?.error_for_status()?;
+    if response
+        .content_length()
+        .is_some_and(|len| len > max_bytes as u64)
+    {
+        return Err(anyhow!("response too large: {}", url));
+    }
+
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.split(';').next().unwrap_or(v).trim().to_string());
+
+    // 没有 Content-Length 或声明不实时，边读边检查大小
+    let mut data = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if data.len() + chunk.len() > max_bytes {
+            return Err(anyhow!("response too large: {}", url));
+        }
+        data.extend_from_slice(&chunk);
+    }
+
+    Ok(FetchedBody { content_type, data })
+}
+
+/// 只返回公网地址的 DNS 解析器
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            if is_local_name(&host) {
+                return Err(format!("{} is a local host", host).into());
+            }
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_ip(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to a public address", host).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
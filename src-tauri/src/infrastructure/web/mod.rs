@@ -0,0 +1,9 @@
+mod address;
+mod fetch;
+mod pattern;
+mod site;
+
+pub use address::is_public_url;
+pub use fetch::fetch_public;
+pub use pattern::{pattern_to_regex, split_port};
+pub use site::{site, SECOND_LEVEL_LABELS};
@@ -0,0 +1,174 @@
+use anyhow::{anyhow, Result};
+
+/// 将 URL 匹配规则转换为正则表达式源码（站点脚本和链接规则共用，在注入脚本中用 `new RegExp` 匹配）
+///
+/// 支持的写法：
+/// - `<all_urls>`：所有 http/https 页面
+/// - 匹配模式 `scheme://host/path`：scheme 可为 `*`（http 和 https），
+///   host 可为 `*` 或 `*.example.com`（含子域名），可带端口（不带时匹配任意端口），
+///   path 中 `*` 匹配任意字符
+/// - `/regex/`：userscript @include 的正则写法
+/// - 其他：按通配符 `*` 匹配完整 URL（userscript @include 的 glob 写法）
+pub fn pattern_to_regex(pattern: &str) -> Result<String> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return Err(anyhow!("empty match pattern"));
+    }
+    if pattern == "<all_urls>" {
+        return Ok("^https?://".to_string());
+    }
+    if pattern.len() > 2 && pattern.starts_with('/') && pattern.ends_with('/') {
+        return Ok(pattern[1..pattern.len() - 1].to_string());
+    }
+
+    let Some((scheme, rest)) = pattern.split_once("://") else {
+        return Ok(format!("^{}$", glob(pattern)));
+    };
+    let scheme = match scheme {
+        "*" => "https?".to_string(),
+        "http" | "https" => scheme.to_string(),
+        _ => return Ok(format!("^{}$", glob(pattern))),
+    };
+
+    let (host, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => return Err(anyhow!("match pattern '{}' has no path", pattern)),
+    };
+    let (host, port) = match split_port(host) {
+        Some((host, Some(port))) => (host, format!(":{}", port)),
+        Some((host, None)) => (host, "(:\\d+)?".to_string()),
+        None => return Err(anyhow!("invalid port in match pattern '{}'", pattern)),
+    };
+    let host = if host == "*" {
+        "[^/:]+".to_string()
+    } else if let Some(domain) = host.strip_prefix("*.") {
+        format!("([^/:]+\\.)?{}", escape(domain))
+    } else if host.is_empty() || host.contains('*') {
+        return Err(anyhow!("invalid host in match pattern '{}'", pattern));
+    } else {
+        escape(host)
+    };
+
+    Ok(format!("^{}://{}{}{}$", scheme, host, port, glob(path)))
+}
+
+/// 拆分 `host:port`，端口必须是数字；格式不对时返回 None
+pub fn split_port(authority: &str) -> Option<(&str, Option<&str>)> {
+    match authority.split_once(':') {
+        Some((host, port)) if !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()) => {
+            Some((host, Some(port)))
+        }
+        Some(_) => None,
+        None => Some((authority, None)),
+    }
+}
+
+/// 通配符 `*` 转为 `.*`，其余字符按字面匹配
+fn glob(value: &str) -> String {
+    value.split('*').map(escape).collect::<Vec<_>>().join(".*")
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if "\\^$.|?*+()[]{}/".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+
+    fn matches(pattern: &str, url: &str) -> bool {
+        Regex::new(&pattern_to_regex(pattern).unwrap())
+            .unwrap()
+            .is_match(url)
+    }
+
+    #[test]
+    fn all_urls_matches_http_and_https() {
+        assert!(matches("<all_urls>", "https://example.com/"));
+        assert!(matches("<all_urls>", "http://example.com/a"));
+        assert!(!matches("<all_urls>", "file:///etc/hosts"));
+    }
+
+    #[test]
+    fn match_pattern_scheme() {
+        assert!(matches("*://example.com/*", "http://example.com/"));
+        assert!(matches("*://example.com/*", "https://example.com/a"));
+        assert!(matches("https://example.com/*", "https://example.com/a"));
+        assert!(!matches("https://example.com/*", "http://example.com/a"));
+    }
+
+    #[test]
+    fn match_pattern_host() {
+        assert!(matches("https://*/*", "https://anything.test/x"));
+        assert!(matches("https://*.example.com/*", "https://example.com/"));
+        assert!(matches("https://*.example.com/*", "https://a.b.example.com/"));
+        assert!(!matches("https://*.example.com/*", "https://badexample.com/"));
+        assert!(!matches("https://*.example.com/*", "https://example.com.evil.test/"));
+        // 点号按字面匹配
+        assert!(!matches("https://example.com/*", "https://exampleXcom/"));
+    }
+
+    #[test]
+    fn match_pattern_port_and_path() {
+        assert!(matches("https://example.com/*", "https://example.com:8443/a"));
+        assert!(matches("https://example.com:8443/*", "https://example.com:8443/a"));
+        assert!(!matches("https://example.com:8443/*", "https://example.com/a"));
+        assert!(!matches("https://example.com:8443/*", "https://example.com:9443/a"));
+        assert!(matches("https://example.com/app/*", "https://example.com/app/x?y=1"));
+        assert!(!matches("https://example.com/app/*", "https://example.com/other"));
+        assert!(!matches("https://example.com/", "https://example.com/a"));
+    }
+
+    #[test]
+    fn regex_pattern_is_passed_through() {
+        assert_eq!(
+            pattern_to_regex("/^https://app\\.example\\.com/").unwrap(),
+            "^https://app\\.example\\.com"
+        );
+        assert!(matches("/example\\.(com|org)/", "https://www.example.org/"));
+    }
+
+    #[test]
+    fn glob_pattern_matches_whole_url() {
+        assert_eq!(
+            pattern_to_regex("http*://example.com/*").unwrap(),
+            "^http.*:\\/\\/example\\.com\\/.*$"
+        );
+        assert!(matches("*example.com*", "https://www.example.com/a"));
+        assert!(!matches("example.com", "https://example.com"));
+    }
+
+    #[test]
+    fn invalid_patterns_are_rejected() {
+        assert!(pattern_to_regex("").is_err());
+        assert!(pattern_to_regex("   ").is_err());
+        assert!(pattern_to_regex("https://example.com").is_err());
+        assert!(pattern_to_regex("https://exa*mple.com/*").is_err());
+        assert!(pattern_to_regex("https:///path").is_err());
+        assert!(pattern_to_regex("https://example.com:/*").is_err());
+        assert!(pattern_to_regex("https://example.com:port/*").is_err());
+    }
+
+    #[test]
+    fn generated_regexes_compile() {
+        for pattern in [
+            "<all_urls>",
+            "*://*/*",
+            "https://*.example.co.uk/a(b)[c]{d}+?$^|.*",
+            "ftp://example.com/*",
+            "example.com/*",
+        ] {
+            let source = pattern_to_regex(pattern).unwrap();
+            assert!(Regex::new(&source).is_ok(), "{} -> {}", pattern, source);
+        }
+    }
+}
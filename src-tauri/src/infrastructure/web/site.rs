@@ -0,0 +1,47 @@
+use url::{Host, Url};
+
+/// 二级域名后缀（如 example.co.uk），用于近似计算注册域名
+pub const SECOND_LEVEL_LABELS: &[&str] = &["co", "com", "net", "org", "gov", "edu", "ac"];
+
+/// 近似的注册域名：取最后两级，`co.uk` 这类二级后缀取最后三级；IP 地址原样返回
+pub fn site(url: &Url) -> Option<String> {
+    match url.host()? {
+        Host::Domain(domain) => {
+            let labels: Vec<&str> = domain.split('.').collect();
+            let n = labels.len();
+            let keep = if n >= 3
+                && SECOND_LEVEL_LABELS.contains(&labels[n - 2])
+                && labels[n - 1].len() == 2
+            {
+                3
+            } else {
+                2
+            };
+            Some(labels[n.saturating_sub(keep)..].join("."))
+        }
+        host => Some(host.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site_of(value: &str) -> Option<String> {
+        site(&Url::parse(value).unwrap())
+    }
+
+    #[test]
+    fn approximates_registrable_domain() {
+        assert_eq!(site_of("https://docs.x.com/"), Some("x.com".to_string()));
+        assert_eq!(site_of("https://x.com/"), Some("x.com".to_string()));
+        assert_eq!(site_of("https://a.b.example.co.uk/"), Some("example.co.uk".to_string()));
+        assert_eq!(site_of("https://example.co.uk/"), Some("example.co.uk".to_string()));
+        // 顶级域名不是两个字母时不视为二级后缀
+        assert_eq!(site_of("https://a.co.example/"), Some("co.example".to_string()));
+        assert_eq!(site_of("http://localhost:8080/"), Some("localhost".to_string()));
+        assert_eq!(site_of("http://192.168.1.10/"), Some("192.168.1.10".to_string()));
+        assert_eq!(site_of("http://[::1]/"), Some("[::1]".to_string()));
+        assert_eq!(site_of("mailto:someone@example.com"), None);
+    }
+}
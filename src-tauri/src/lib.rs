@@ -7,8 +7,14 @@ mod infrastructure;
 pub use core::AppState;
 
 use core::Feature;
-use features::{settings::SettingsFeature, tab::TabFeature, window::WindowFeature};
-use infrastructure::database::{init_db, DatabaseRegistry};
+use features::{
+    backup::BackupFeature, data_transfer::DataTransferFeature, link_rule::LinkRuleFeature,
+    settings::SettingsFeature, site_script::SiteScriptFeature, tab::TabFeature,
+    window::WindowFeature,
+};
+use infrastructure::database::{backup, init_db, schema, DatabaseRegistry};
+use infrastructure::injection::InjectionRegistry;
+use infrastructure::ipc::IpcGuard;
 use std::sync::Arc;
 use tauri::Manager;
 
@@ -18,6 +24,10 @@ fn init_features() -> Vec<Arc<dyn Feature>> {
         SettingsFeature::new(),
         Arc::new(TabFeature::new()),
         Arc::new(WindowFeature::new()),
+        Arc::new(DataTransferFeature::new()),
+        Arc::new(BackupFeature::new()),
+        Arc::new(SiteScriptFeature::new()),
+        Arc::new(LinkRuleFeature::new()),
     ]
 }
 
@@ -25,7 +35,20 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(features::window::shortcuts::handle_shortcut)
+                .build(),
+        )
+        // 应用命令的调用检查（见 core::registry::commands）
+        .manage(IpcGuard::new())
+        // 缓存的网站图标
+        .register_asynchronous_uri_scheme_protocol(
+            features::tab::api::protocol::FAVICON_SCHEME,
+            features::tab::api::protocol::handle_favicon_request,
+        )
         .setup(|app| {
             let handle = app.handle();
 
@@ -42,6 +65,27 @@ pub fn run() {
                 feature.register_database(&mut db_registry);
             }
 
+            // 开发构建：设置 NOB_MIGRATE_DOWN=<n> 启动时先回滚最近的 n 个 Migrations，
+            // 随后按当前代码重新执行（修改尚未发布的 Migration 时使用）
+            #[cfg(debug_assertions)]
+            if let Some(steps) = std::env::var("NOB_MIGRATE_DOWN")
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+            {
+                tauri::async_runtime::block_on(db_registry.rollback(&db, steps))
+                    .map_err(|e| format!("Failed to roll back migrations: {}", e))?;
+            }
+
+            // 执行 Migration 前先备份已有数据，迁移出错时可以恢复
+            tauri::async_runtime::block_on(async {
+                let has_data = schema::has_tables(&db).await.unwrap_or(false);
+                if has_data && db_registry.has_pending(&db).await.unwrap_or(true) {
+                    if let Err(e) = backup::create_backup(&db, "pre-migration").await {
+                        println!("[Database] Pre-migration backup failed: {}", e);
+                    }
+                }
+            });
+
             tauri::async_runtime::block_on(db_registry.run_migrations(&db))
                 .map_err(|e| format!("Failed to run migrations: {}", e))?;
 
@@ -52,6 +96,13 @@ pub fn run() {
             let tray_registry = core::registry::tray::build_tray_registry();
             state.set_tray_registry(tray_registry);
 
+            // 收集各 Feature 注册的注入脚本
+            let mut injection_registry = InjectionRegistry::new();
+            for feature in &features {
+                feature.register_scripts(&mut injection_registry);
+            }
+            state.set_injection_registry(injection_registry);
+
             // 初始化所有 Features
             for feature in &features {
                 tauri::async_runtime::block_on(feature.initialize(&state)).map_err(|e| {
@@ -81,13 +132,25 @@ pub fn run() {
             tauri::WindowEvent::Moved(_) => {
                 if window.label() == "main" {
                     features::window::manager::on_main_window_moved(window.app_handle());
+                } else {
+                    features::window::manager::on_content_window_moved(window.app_handle(), window.label());
                 }
             }
             tauri::WindowEvent::Resized(_) => {
                 if window.label() == "main" {
                     features::window::manager::on_main_window_resized(window.app_handle());
+                } else {
+                    features::window::manager::on_content_window_resized(window.app_handle(), window.label());
                 }
             }
+            // 内容窗口销毁后从 webview 池中移除，分离窗口被关闭时放回内容栏
+            tauri::WindowEvent::Destroyed => {
+                features::window::manager::on_content_window_destroyed(window.app_handle(), window.label());
+            }
+            // 跟随系统外观时，同步内容页面的强制暗色样式
+            tauri::WindowEvent::ThemeChanged(_) if window.label() == "main" => {
+                features::window::appearance::on_system_theme_changed(window.app_handle());
+            }
             // 监听焦点事件，处理自动显示/隐藏
             tauri::WindowEvent::Focused(focused) => {
                 features::window::manager::handle_focus_change(window.app_handle(), window.label(), *focused);
@@ -0,0 +1,28 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ask } from "@tauri-apps/plugin-dialog";
+
+import type { BackupInfo } from "@/features/backup/types";
+import { withModalOpen } from "@/features/window/api/focus.api";
+
+export async function dbListBackups(): Promise<BackupInfo[]> {
+  return invoke<BackupInfo[]>("db_list_backups");
+}
+
+/** 恢复后应用会自动重启 */
+export async function dbRestoreBackup(fileName: string): Promise<void> {
+  await invoke("db_restore_backup", { fileName });
+}
+
+/** 确认后恢复备份，返回是否已开始恢复 */
+export async function dbRestoreBackupWithConfirm(fileName: string): Promise<boolean> {
+  const confirmed = await withModalOpen(() =>
+    ask(`恢复备份 ${fileName} 会覆盖当前数据并重启应用，是否继续？`, {
+      title: "恢复备份",
+      kind: "warning",
+    }),
+  );
+  if (confirmed) {
+    await dbRestoreBackup(fileName);
+  }
+  return confirmed;
+}
@@ -0,0 +1,7 @@
+export type BackupInfo = {
+  file_name: string;
+  /** pre-migration / daily / pre-restore */
+  reason: string;
+  size_bytes: number;
+  created_at: string;
+};
@@ -0,0 +1,31 @@
+import { invoke } from "@tauri-apps/api/core";
+import { open, save } from "@tauri-apps/plugin-dialog";
+
+import type { DataExportResult, DataImportResult, ImportMode } from "@/features/data-transfer/types";
+import { withModalOpen } from "@/features/window/api/focus.api";
+
+const BUNDLE_FILTERS = [{ name: "NoB 数据", extensions: ["json"] }];
+
+export async function dataExport(path?: string): Promise<DataExportResult> {
+  return invoke<DataExportResult>("data_export", { path: path ?? null });
+}
+
+export async function dataImport(path: string, mode?: ImportMode): Promise<DataImportResult> {
+  return invoke<DataImportResult>("data_import", { path, mode: mode ?? null });
+}
+
+/** 选择导出位置并导出，取消选择时返回 null */
+export async function dataExportWithDialog(): Promise<DataExportResult | null> {
+  const path = await withModalOpen(() =>
+    save({ defaultPath: "nob-data.json", filters: BUNDLE_FILTERS }),
+  );
+  return path ? dataExport(path) : null;
+}
+
+/** 选择数据文件并导入，取消选择时返回 null */
+export async function dataImportWithDialog(mode?: ImportMode): Promise<DataImportResult | null> {
+  const path = await withModalOpen(() =>
+    open({ multiple: false, directory: false, filters: BUNDLE_FILTERS }),
+  );
+  return path ? dataImport(path, mode) : null;
+}
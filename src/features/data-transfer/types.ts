@@ -0,0 +1,15 @@
+export type ImportMode = "merge" | "replace";
+
+export type DataExportResult = {
+  path: string;
+  groups: number;
+  tabs: number;
+  settings: number;
+};
+
+export type DataImportResult = {
+  groups_created: number;
+  tabs_created: number;
+  tabs_skipped: number;
+  settings_written: number;
+};
@@ -0,0 +1,24 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type {
+  CreateLinkRulePayload,
+  LinkRule,
+  UpdateLinkRulePayload,
+} from "@/features/link-rule/types";
+
+/** 所有规则（按匹配顺序） */
+export async function linkRulesList(): Promise<LinkRule[]> {
+  return invoke<LinkRule[]>("link_rules_list");
+}
+
+export async function linkRulesCreate(payload: CreateLinkRulePayload): Promise<LinkRule> {
+  return invoke<LinkRule>("link_rules_create", { payload });
+}
+
+export async function linkRulesUpdate(payload: UpdateLinkRulePayload): Promise<LinkRule | null> {
+  return invoke<LinkRule | null>("link_rules_update", { payload });
+}
+
+export async function linkRulesDelete(id: string): Promise<LinkRule | null> {
+  return invoke<LinkRule | null>("link_rules_delete", { id });
+}
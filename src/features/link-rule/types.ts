@@ -0,0 +1,49 @@
+/** 链接的处理方式：当前标签、新标签、系统浏览器、阻止 */
+export type LinkAction = "stay" | "new_tab" | "external" | "block";
+
+/** 目标链接与来源页面的关系 */
+export type LinkRelation = "any" | "same_origin" | "same_site" | "cross_site";
+
+/** 规则作用范围：页面中的链接（注入脚本）、导航（包括重定向和 iframe）或两者 */
+export type RuleScope = "all" | "links" | "navigation";
+
+export type LinkRule = {
+  id: string;
+  name: string;
+  /** 来源页面匹配规则（匹配模式、`<all_urls>` 或通配符），为空时匹配任意页面 */
+  source_patterns: string[];
+  /** 目标链接匹配规则，为空时匹配任意链接 */
+  target_patterns: string[];
+  relation: LinkRelation;
+  scope: RuleScope;
+  action: LinkAction;
+  /** 小的优先匹配 */
+  priority: number;
+  enabled: boolean;
+  builtin: boolean;
+  created_at: string;
+  updated_at: string;
+};
+
+export type CreateLinkRulePayload = {
+  name: string;
+  source_patterns?: string[];
+  target_patterns?: string[];
+  relation?: LinkRelation;
+  scope?: RuleScope;
+  action: LinkAction;
+  priority?: number;
+  enabled?: boolean;
+};
+
+export type UpdateLinkRulePayload = {
+  id: string;
+  name?: string;
+  source_patterns?: string[];
+  target_patterns?: string[];
+  relation?: LinkRelation;
+  scope?: RuleScope;
+  action?: LinkAction;
+  priority?: number;
+  enabled?: boolean;
+};
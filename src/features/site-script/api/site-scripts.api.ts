@@ -0,0 +1,30 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type {
+  CreateSiteScriptPayload,
+  SiteScript,
+  UpdateSiteScriptPayload,
+} from "@/features/site-script/types";
+
+export async function siteScriptsList(): Promise<SiteScript[]> {
+  return invoke<SiteScript[]>("site_scripts_list");
+}
+
+export async function siteScriptsCreate(payload: CreateSiteScriptPayload): Promise<SiteScript> {
+  return invoke<SiteScript>("site_scripts_create", { payload });
+}
+
+export async function siteScriptsUpdate(
+  payload: UpdateSiteScriptPayload,
+): Promise<SiteScript | null> {
+  return invoke<SiteScript | null>("site_scripts_update", { payload });
+}
+
+export async function siteScriptsDelete(id: string): Promise<SiteScript | null> {
+  return invoke<SiteScript | null>("site_scripts_delete", { id });
+}
+
+/** 导入带 `==UserScript==` 或 `==UserStyle==` 头部的源码（读取 @name、@match、@include、@run-at） */
+export async function siteScriptsImport(source: string): Promise<SiteScript> {
+  return invoke<SiteScript>("site_scripts_import", { source });
+}
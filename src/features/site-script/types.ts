@@ -0,0 +1,35 @@
+export type ScriptKind = "css" | "js";
+
+export type RunAt = "document_start" | "document_end" | "document_idle";
+
+export type SiteScript = {
+  id: string;
+  name: string;
+  kind: ScriptKind;
+  /** URL 匹配规则（匹配模式、`<all_urls>`、`/regex/` 或通配符），任一匹配即注入 */
+  matches: string[];
+  code: string;
+  enabled: boolean;
+  run_at: RunAt;
+  created_at: string;
+  updated_at: string;
+};
+
+export type CreateSiteScriptPayload = {
+  name: string;
+  kind: ScriptKind;
+  matches: string[];
+  code: string;
+  enabled?: boolean;
+  run_at?: RunAt;
+};
+
+export type UpdateSiteScriptPayload = {
+  id: string;
+  name?: string;
+  kind?: ScriptKind;
+  matches?: string[];
+  code?: string;
+  enabled?: boolean;
+  run_at?: RunAt;
+};
@@ -1,11 +1,25 @@
 import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
 
 import type {
+  ClosedTab,
+  CreateTabGroupInput,
   CreateTabInput,
+  CreateTabSplitInput,
+  ImportTabsInput,
+  ImportTabsResult,
+  ReorderTabGroupsInput,
   ReorderTabsInput,
+  SuspensionPolicy,
   Tab,
+  TabGroup,
+  TabHistoryEntry,
+  TabSplit,
+  UpdateTabGroupInput,
   UpdateTabInput,
+  UpdateTabSplitInput,
 } from "@/features/tab/types";
+import { withModalOpen } from "@/features/window/api/focus.api";
 
 export async function tabsList(): Promise<Tab[]> {
   return invoke<Tab[]>("tabs_list");
@@ -27,6 +41,40 @@ export async function tabsClose(id: string): Promise<Tab | null> {
   return invoke<Tab | null>("tabs_close", { id });
 }
 
+export async function tabsReopenClosed(id?: string): Promise<Tab | null> {
+  return invoke<Tab | null>("tabs_reopen_closed", { id: id ?? null });
+}
+
+export async function tabsListClosed(limit?: number): Promise<ClosedTab[]> {
+  return invoke<ClosedTab[]>("tabs_list_closed", { limit: limit ?? null });
+}
+
+export async function tabsImport(payload: ImportTabsInput): Promise<ImportTabsResult> {
+  return invoke<ImportTabsResult>("tabs_import", { payload });
+}
+
+/** 选择书签文件并导入，取消选择时返回 null */
+export async function tabsImportWithDialog(
+  options?: Omit<ImportTabsInput, "path">,
+): Promise<ImportTabsResult | null> {
+  const path = await withModalOpen(() =>
+    open({
+      multiple: false,
+      directory: false,
+      filters: [{ name: "书签", extensions: ["html", "htm", "json", "sqlite"] }],
+    }),
+  );
+  return path ? tabsImport({ ...options, path }) : null;
+}
+
+export async function tabsGetSuspensionPolicy(): Promise<SuspensionPolicy> {
+  return invoke<SuspensionPolicy>("tabs_get_suspension_policy");
+}
+
+export async function tabsSetSuspensionPolicy(policy: SuspensionPolicy): Promise<SuspensionPolicy> {
+  return invoke<SuspensionPolicy>("tabs_set_suspension_policy", { policy });
+}
+
 export async function tabsReorder(payload: ReorderTabsInput): Promise<void> {
   return invoke<void>("tabs_reorder", { payload });
 }
@@ -47,6 +95,30 @@ export async function tabsReload(id: string): Promise<void> {
   return invoke<void>("tabs_reload", { id });
 }
 
+export async function tabsResetContentSize(id: string): Promise<Tab | null> {
+  return invoke<Tab | null>("tabs_reset_content_size", { id });
+}
+
+export async function tabsDetach(id: string): Promise<Tab | null> {
+  return invoke<Tab | null>("tabs_detach", { id });
+}
+
+export async function tabsAttach(id: string): Promise<Tab | null> {
+  return invoke<Tab | null>("tabs_attach", { id });
+}
+
+export async function tabsGoBack(): Promise<Tab | null> {
+  return invoke<Tab | null>("tabs_go_back");
+}
+
+export async function tabsGoForward(): Promise<Tab | null> {
+  return invoke<Tab | null>("tabs_go_forward");
+}
+
+export async function tabsHistory(id?: string): Promise<TabHistoryEntry[]> {
+  return invoke<TabHistoryEntry[]>("tabs_history", { id: id ?? null });
+}
+
 export async function tabsGetCurrentUrl(): Promise<string> {
   return invoke<string>("tabs_get_current_url");
 }
@@ -54,3 +126,43 @@ export async function tabsGetCurrentUrl(): Promise<string> {
 export async function tabsRequestUrl(): Promise<void> {
   return invoke<void>("tabs_request_url");
 }
+
+export async function tabGroupsList(): Promise<TabGroup[]> {
+  return invoke<TabGroup[]>("tab_groups_list");
+}
+
+export async function tabGroupsCreate(payload: CreateTabGroupInput): Promise<TabGroup> {
+  return invoke<TabGroup>("tab_groups_create", { payload });
+}
+
+export async function tabGroupsUpdate(payload: UpdateTabGroupInput): Promise<TabGroup | null> {
+  return invoke<TabGroup | null>("tab_groups_update", { payload });
+}
+
+export async function tabGroupsActivate(id: string): Promise<TabGroup | null> {
+  return invoke<TabGroup | null>("tab_groups_activate", { id });
+}
+
+export async function tabGroupsDelete(id: string): Promise<TabGroup | null> {
+  return invoke<TabGroup | null>("tab_groups_delete", { id });
+}
+
+export async function tabGroupsReorder(payload: ReorderTabGroupsInput): Promise<void> {
+  return invoke<void>("tab_groups_reorder", { payload });
+}
+
+export async function tabSplitsList(): Promise<TabSplit[]> {
+  return invoke<TabSplit[]>("tab_splits_list");
+}
+
+export async function tabSplitsCreate(payload: CreateTabSplitInput): Promise<TabSplit> {
+  return invoke<TabSplit>("tab_splits_create", { payload });
+}
+
+export async function tabSplitsUpdate(payload: UpdateTabSplitInput): Promise<TabSplit | null> {
+  return invoke<TabSplit | null>("tab_splits_update", { payload });
+}
+
+export async function tabSplitsDelete(id: string): Promise<TabSplit | null> {
+  return invoke<TabSplit | null>("tab_splits_delete", { id });
+}
@@ -1,3 +1,4 @@
+import { convertFileSrc } from "@tauri-apps/api/core";
 import { Globe } from "lucide-react";
 
 import { Button } from "@/components/ui/button";
@@ -11,7 +12,8 @@ interface TabListProps {
 
 export function TabList({ tabs, onActivate }: TabListProps) {
   const getFavicon = (tab: Tab) => {
-    if (tab.favicon_url) return tab.favicon_url;
+    // 后端缓存的图标通过 favicon:// 协议读取，离线时同样可用
+    if (tab.favicon_url) return convertFileSrc(tab.favicon_url, "favicon");
     try {
       const url = new URL(tab.url);
       return `https://www.google.com/s2/favicons?domain=${url.hostname}&sz=32`;
@@ -1,18 +1,35 @@
 import { GripVertical, Pin } from "lucide-react";
-import { useState } from "react";
-import { invoke } from "@tauri-apps/api/core";
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
 
 import { Button } from "@/components/ui/button";
 import { ButtonGroup } from "@/components/ui/button-group";
 import { Label } from "@/components/ui/label";
+import {
+  CONTENT_PIN_CHANGED_EVENT,
+  windowGetContentPinned,
+  windowSetContentPinned,
+} from "@/features/window/api/pin.api";
 
 export function WindowControls() {
   const [isPinned, setIsPinned] = useState(false);
 
+  useEffect(() => {
+    windowGetContentPinned().then(setIsPinned).catch(console.error);
+
+    const unlisten = listen<boolean>(CONTENT_PIN_CHANGED_EVENT, (event) => {
+      setIsPinned(event.payload);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   const togglePin = async () => {
     const newState = !isPinned;
     setIsPinned(newState);
-    await invoke("set_content_window_pinned", { pinned: newState });
+    await windowSetContentPinned(newState);
   };
 
   return (
@@ -1,5 +1,6 @@
 export type Tab = {
   id: string;
+  group_id: string;
   title: string;
   url: string;
   initial_url: string;
@@ -7,9 +8,56 @@ export type Tab = {
   is_pinned: boolean;
   is_active: boolean;
   sort_order: number;
+  /** 内容窗口尺寸，null 表示使用默认尺寸 */
+  content_width: number | null;
+  content_height: number | null;
   last_opened_at: string;
   created_at: string;
   updated_at: string;
+  suspended: boolean;
+  detached: boolean;
+};
+
+export type SuspensionPolicy = {
+  idle_minutes: number;
+  max_live_webviews: number;
+};
+
+export type ImportFormat = "netscape_html" | "chrome_json" | "firefox_places";
+
+export type ImportTabsInput = {
+  path: string;
+  format?: ImportFormat;
+  folders_as_groups?: boolean;
+};
+
+export type ImportTabsResult = {
+  created: number;
+  skipped: number;
+  failed: number;
+  groups_created: number;
+  errors: string[];
+};
+
+export type TabHistoryEntry = {
+  id: string;
+  tab_id: string;
+  position: number;
+  url: string;
+  title?: string | null;
+  is_current: boolean;
+  created_at: string;
+};
+
+export type ClosedTab = {
+  id: string;
+  group_id: string;
+  title: string;
+  url: string;
+  favicon_url?: string | null;
+  is_pinned: boolean;
+  sort_order: number;
+  closed_at: string;
 };
 
 export type CreateTabInput = {
@@ -31,3 +79,51 @@ export type UpdateTabInput = {
 export type ReorderTabsInput = {
   ordered_ids: string[];
 };
+
+export type TabGroup = {
+  id: string;
+  name: string;
+  is_active: boolean;
+  sort_order: number;
+  created_at: string;
+  updated_at: string;
+};
+
+export type CreateTabGroupInput = {
+  name: string;
+  activate?: boolean;
+};
+
+export type UpdateTabGroupInput = {
+  id: string;
+  name?: string;
+};
+
+export type ReorderTabGroupsInput = {
+  ordered_ids: string[];
+};
+
+export type SplitOrientation = "horizontal" | "vertical";
+
+export type TabSplit = {
+  id: string;
+  primary_tab_id: string;
+  secondary_tab_id: string;
+  orientation: SplitOrientation;
+  ratio: number;
+  created_at: string;
+  updated_at: string;
+};
+
+export type CreateTabSplitInput = {
+  primary_tab_id: string;
+  secondary_tab_id: string;
+  orientation?: SplitOrientation;
+  ratio?: number;
+};
+
+export type UpdateTabSplitInput = {
+  id: string;
+  orientation?: SplitOrientation;
+  ratio?: number;
+};
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type { ForceDarkSettings } from "@/features/window/types";
+
+export async function windowGetForceDark(): Promise<ForceDarkSettings> {
+  return invoke<ForceDarkSettings>("window_get_force_dark");
+}
+
+export async function windowSetForceDark(settings: ForceDarkSettings): Promise<ForceDarkSettings> {
+  return invoke<ForceDarkSettings>("window_set_force_dark", { settings });
+}
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type { DockSettings } from "@/features/window/types";
+
+export async function windowGetDockSettings(): Promise<DockSettings> {
+  return invoke<DockSettings>("window_get_dock_settings");
+}
+
+export async function windowSetDockSettings(settings: DockSettings): Promise<DockSettings> {
+  return invoke<DockSettings>("window_set_dock_settings", { settings });
+}
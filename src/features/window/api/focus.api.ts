@@ -0,0 +1,26 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type { FocusTimings } from "@/features/window/types";
+
+export async function windowGetFocusTimings(): Promise<FocusTimings> {
+  return invoke<FocusTimings>("window_get_focus_timings");
+}
+
+export async function windowSetFocusTimings(timings: FocusTimings): Promise<FocusTimings> {
+  return invoke<FocusTimings>("window_set_focus_timings", { timings });
+}
+
+/** 打开原生对话框前后调用，期间窗口失去焦点不会自动隐藏 */
+export async function windowSetModalOpen(open: boolean): Promise<void> {
+  return invoke<void>("window_set_modal_open", { open });
+}
+
+/** 在原生对话框打开期间执行 task，结束（包括出错）后恢复自动隐藏 */
+export async function withModalOpen<T>(task: () => Promise<T>): Promise<T> {
+  await windowSetModalOpen(true);
+  try {
+    return await task();
+  } finally {
+    await windowSetModalOpen(false);
+  }
+}
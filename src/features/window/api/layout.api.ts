@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type { LayoutSettings } from "@/features/window/types";
+
+export async function windowGetContentLayout(): Promise<LayoutSettings> {
+  return invoke<LayoutSettings>("window_get_content_layout");
+}
+
+export async function windowSetContentLayout(settings: LayoutSettings): Promise<LayoutSettings> {
+  return invoke<LayoutSettings>("window_set_content_layout", { settings });
+}
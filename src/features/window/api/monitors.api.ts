@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export async function windowGetSummonOnCursorMonitor(): Promise<boolean> {
+  return invoke<boolean>("window_get_summon_on_cursor_monitor");
+}
+
+/** 开启后，唤出时主窗口会移动到鼠标所在的显示器 */
+export async function windowSetSummonOnCursorMonitor(enabled: boolean): Promise<boolean> {
+  return invoke<boolean>("window_set_summon_on_cursor_monitor", { enabled });
+}
@@ -0,0 +1,12 @@
+import { invoke } from "@tauri-apps/api/core";
+
+/** 内容窗口固定状态变化事件（托盘、快捷键或前端修改时都会发出） */
+export const CONTENT_PIN_CHANGED_EVENT = "content-pin-changed";
+
+export async function windowGetContentPinned(): Promise<boolean> {
+  return invoke<boolean>("window_get_content_pinned");
+}
+
+export async function windowSetContentPinned(pinned: boolean): Promise<void> {
+  return invoke<void>("set_content_window_pinned", { pinned });
+}
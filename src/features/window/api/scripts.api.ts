@@ -0,0 +1,15 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type { ScriptModuleInfo } from "@/features/window/types";
+
+export async function windowScriptModulesList(): Promise<ScriptModuleInfo[]> {
+  return invoke<ScriptModuleInfo[]>("window_script_modules_list");
+}
+
+/** 新建或重新加载的页面生效 */
+export async function windowScriptModulesSetEnabled(
+  name: string,
+  enabled: boolean,
+): Promise<ScriptModuleInfo[]> {
+  return invoke<ScriptModuleInfo[]>("window_script_modules_set_enabled", { name, enabled });
+}
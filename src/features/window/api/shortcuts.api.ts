@@ -0,0 +1,16 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type { ShortcutBinding } from "@/features/window/types";
+
+export async function shortcutsList(): Promise<ShortcutBinding[]> {
+  return invoke<ShortcutBinding[]>("shortcuts_list");
+}
+
+/** accelerator 为空时禁用该快捷键 */
+export async function shortcutsSet(action: string, accelerator: string | null): Promise<ShortcutBinding[]> {
+  return invoke<ShortcutBinding[]>("shortcuts_set", { action, accelerator });
+}
+
+export async function shortcutsReset(action?: string): Promise<ShortcutBinding[]> {
+  return invoke<ShortcutBinding[]>("shortcuts_reset", { action: action ?? null });
+}
@@ -0,0 +1,55 @@
+export type ShortcutBinding = {
+  action: string;
+  label: string;
+  /** null 表示未绑定 */
+  accelerator: string | null;
+  default_accelerator: string;
+  registered: boolean;
+  error: string | null;
+};
+
+export type DockEdge = "none" | "top" | "bottom" | "left" | "right";
+
+export type DockCorner = "top_left" | "top_right" | "bottom_left" | "bottom_right";
+
+export type DockSettings = {
+  edge: DockEdge;
+  /** 鼠标移到该角落也会唤出 */
+  reveal_corner: DockCorner | null;
+  /** 鼠标在触发区域停留多久后唤出（毫秒） */
+  reveal_delay_ms: number;
+};
+
+export type ContentLayout = "below" | "above" | "left" | "right" | "overlay" | "auto";
+
+export type LayoutSettings = {
+  mode: ContentLayout;
+  /** 与主窗口的间隙（逻辑像素） */
+  gap: number;
+};
+
+export type FocusTimings = {
+  /** 所有窗口失去焦点后多久隐藏内容窗口（毫秒） */
+  blur_delay_ms: number;
+  /** 主窗口停止移动多久后视为拖动结束（毫秒） */
+  drag_settle_ms: number;
+};
+
+export type ForceDarkSettings = {
+  /** 暗色外观下为页面注入反色样式 */
+  enabled: boolean;
+  /** 不注入的站点（域名，同时匹配子域名） */
+  excluded_hosts: string[];
+};
+
+export type ScriptModuleInfo = {
+  /** 唯一标识，如 `tab.title_reporter` */
+  name: string;
+  feature: string;
+  description: string;
+  /** 注入顺序，小的先执行 */
+  order: number;
+  /** 其他模块依赖的模块，不能停用 */
+  required: boolean;
+  enabled: boolean;
+};